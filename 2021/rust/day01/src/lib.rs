@@ -1,32 +1,47 @@
-pub fn part1(data: String) -> i32 {
-    data.lines()
-        .map(|row| row.parse::<i32>().unwrap())
-        .fold((0, None), |(acc, last), n| {
-            if n > last.unwrap_or(i32::MAX) {
-                (acc + 1, Some(n))
-            } else {
-                (acc, Some(n))
-            }
-        })
-        .0
-}
+use util::Solution;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<i32>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        data.lines()
+            .map(|row| row.parse::<i32>().unwrap())
+            .collect()
+    }
+
+    fn part1(data: Self::Input<'_>) -> Self::Output1 {
+        data.into_iter()
+            .fold((0, None), |(acc, last), n| {
+                if n > last.unwrap_or(i32::MAX) {
+                    (acc + 1, Some(n))
+                } else {
+                    (acc, Some(n))
+                }
+            })
+            .0
+    }
 
-pub fn part2(data: String) -> i32 {
-    let ns = data.lines().map(|row| row.parse::<i32>().unwrap());
-    let i1 = ns.clone();
-    let i2 = ns.clone().skip(1);
-    let i3 = ns.clone().skip(2);
+    fn part2(data: Self::Input<'_>) -> Self::Output2 {
+        let ns = data.into_iter();
+        let i1 = ns.clone();
+        let i2 = ns.clone().skip(1);
+        let i3 = ns.clone().skip(2);
 
-    i1.zip(i2)
-        .zip(i3)
-        .fold((0, None), |(acc, last), ((n1, n2), n3)| {
-            let n = n1 + n2 + n3;
+        i1.zip(i2)
+            .zip(i3)
+            .fold((0, None), |(acc, last), ((n1, n2), n3)| {
+                let n = n1 + n2 + n3;
 
-            if n > last.unwrap_or(i32::MAX) {
-                (acc + 1, Some(n))
-            } else {
-                (acc, Some(n))
-            }
-        })
-        .0
+                if n > last.unwrap_or(i32::MAX) {
+                    (acc + 1, Some(n))
+                } else {
+                    (acc, Some(n))
+                }
+            })
+            .0
+    }
 }
@@ -1,9 +1,17 @@
+use util::Solution;
+
 #[test]
-fn day01p01() {
-    assert_eq!(day01::part1(util::read_input("../..", 1)), 1759)
+fn input_1() {
+    assert_eq!(
+        day01::Day::part1(day01::Day::parse(&util::test_input("../..", 1))),
+        1759
+    )
 }
 
 #[test]
-fn day01p02() {
-    assert_eq!(day01::part2(util::read_input("../..", 1)), 1805)
+fn input_2() {
+    assert_eq!(
+        day01::Day::part2(day01::Day::parse(&util::test_input("../..", 1))),
+        1805
+    )
 }
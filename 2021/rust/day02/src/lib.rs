@@ -1,32 +1,52 @@
-pub fn part1(data: String) -> i32 {
-    let (x, y) = data
-        .lines()
-        .map(|line| line.split_whitespace().collect())
-        .fold((0, 0), |(x, y), line: Vec<&str>| match line.as_slice() {
-            ["forward", n] => (x + n.parse::<i32>().unwrap(), y),
-            ["down", n] => (x, y + n.parse::<i32>().unwrap()),
-            ["up", n] => (x, y - n.parse::<i32>().unwrap()),
-            _ => (x, y),
-        });
-
-    x * y
+use util::Solution;
+
+#[derive(Clone, Copy)]
+pub enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
 }
 
-pub fn part2(data: String) -> i32 {
-    let (x, y, _a) = data
-        .lines()
-        .map(|line| line.split_whitespace().collect())
-        .fold((0, 0, 0), |(x, y, a), line: Vec<&str>| {
-            match line.as_slice() {
-                ["down", n] => (x, y, a + n.parse::<i32>().unwrap()),
-                ["up", n] => (x, y, a - n.parse::<i32>().unwrap()),
-                ["forward", n] => {
-                    let n = n.parse::<i32>().unwrap();
-                    (x + n, y + (n * a), a)
-                }
-                _ => (x, y, a),
-            }
-        });
-
-    x * y
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Command>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        data.lines()
+            .map(|line| line.split_whitespace().collect())
+            .filter_map(|line: Vec<&str>| match line.as_slice() {
+                ["forward", n] => Some(Command::Forward(n.parse::<i32>().unwrap())),
+                ["down", n] => Some(Command::Down(n.parse::<i32>().unwrap())),
+                ["up", n] => Some(Command::Up(n.parse::<i32>().unwrap())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn part1(data: Self::Input<'_>) -> Self::Output1 {
+        let (x, y) = data
+            .into_iter()
+            .fold((0, 0), |(x, y), command| match command {
+                Command::Forward(n) => (x + n, y),
+                Command::Down(n) => (x, y + n),
+                Command::Up(n) => (x, y - n),
+            });
+
+        x * y
+    }
+
+    fn part2(data: Self::Input<'_>) -> Self::Output2 {
+        let (x, y, _a) = data
+            .into_iter()
+            .fold((0, 0, 0), |(x, y, a), command| match command {
+                Command::Down(n) => (x, y, a + n),
+                Command::Up(n) => (x, y, a - n),
+                Command::Forward(n) => (x + n, y + (n * a), a),
+            });
+
+        x * y
+    }
 }
@@ -1,5 +1,7 @@
+use util::Solution;
+
 #[test]
-fn day02p01_sample() {
+fn example_1() {
     let data = "\
     forward 5
     down 5
@@ -8,16 +10,19 @@ fn day02p01_sample() {
     down 8
     forward 2\
     ";
-    assert_eq!(day02::part1(data.to_string()), 150)
+    assert_eq!(day02::Day::part1(day02::Day::parse(data)), 150)
 }
 
 #[test]
-fn day02p01() {
-    assert_eq!(day02::part1(util::read_input("../..", 2)), 1654760)
+fn input_1() {
+    assert_eq!(
+        day02::Day::part1(day02::Day::parse(&util::test_input("../..", 2))),
+        1654760
+    )
 }
 
 #[test]
-fn day02p02_sample() {
+fn example_2() {
     let data = "\
     forward 5
     down 5
@@ -26,10 +31,13 @@ fn day02p02_sample() {
     down 8
     forward 2\
     ";
-    assert_eq!(day02::part2(data.to_string()), 900)
+    assert_eq!(day02::Day::part2(day02::Day::parse(data)), 900)
 }
 
 #[test]
-fn day02p02() {
-    assert_eq!(day02::part2(util::read_input("../..", 2)), 1956047400)
+fn input_2() {
+    assert_eq!(
+        day02::Day::part2(day02::Day::parse(&util::test_input("../..", 2))),
+        1956047400
+    )
 }
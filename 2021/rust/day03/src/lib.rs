@@ -1,4 +1,5 @@
 use std::collections::BTreeMap;
+use util::Solution;
 
 #[derive(Debug)]
 enum ByteCount {
@@ -36,90 +37,102 @@ fn counts<T: AsRef<str>>(data: impl Iterator<Item = T>) -> BTreeMap<usize, ByteC
     .collect()
 }
 
-pub fn part1(data: String) -> i32 {
-    let counts = counts(data.lines());
-
-    let gamma: String = counts
-        .iter()
-        .map(|(_, v)| {
-            let v = match *v {
-                ByteCount::MORE0 => 0,
-                ByteCount::MORE1 => 1,
-                _ => panic!("Unknown digit"),
-            };
-            char::from_digit(v, 10).unwrap()
-        })
-        .collect();
-
-    let epsilon: String = counts
-        .iter()
-        .map(|(_, v)| {
-            let v = match *v {
-                ByteCount::MORE0 => 1,
-                ByteCount::MORE1 => 0,
-                _ => panic!("Unknown digit"),
-            };
-            char::from_digit(v, 10).unwrap()
-        })
-        .collect();
-
-    let gamman: i32 = i32::from_str_radix(&gamma, 2).unwrap();
-    let epsilonn: i32 = i32::from_str_radix(&epsilon, 2).unwrap();
-    gamman * epsilonn
-}
+pub struct Day;
 
-pub fn part2(data: String) -> i32 {
-    let len = data.lines().next().unwrap().chars().count();
+impl Solution for Day {
+    type Input<'a> = Vec<&'a str>;
+    type Output1 = i32;
+    type Output2 = i32;
 
-    let mut oxygen: Vec<&str> = data.lines().map(|l| l.trim_start().trim_end()).collect();
-    let mut co2: Vec<&str> = oxygen.clone();
+    fn parse(data: &str) -> Self::Input<'_> {
+        data.lines().map(|l| l.trim_start().trim_end()).collect()
+    }
 
-    for i in 0..(len) {
-        if oxygen.len() > 1 {
-            let oxygen_counts = counts(oxygen.iter());
+    fn part1(data: Self::Input<'_>) -> Self::Output1 {
+        let counts = counts(data.iter());
 
-            let mut oxygen_keep: Vec<&str> = Vec::new();
+        let gamma: String = counts
+            .iter()
+            .map(|(_, v)| {
+                let v = match *v {
+                    ByteCount::MORE0 => 0,
+                    ByteCount::MORE1 => 1,
+                    _ => panic!("Unknown digit"),
+                };
+                char::from_digit(v, 10).unwrap()
+            })
+            .collect();
 
-            oxygen.iter().for_each(|row| {
-                match (oxygen_counts.get(&i).unwrap(), row.chars().nth(i).unwrap()) {
-                    (ByteCount::MORE1, '1') => {
-                        oxygen_keep.push(row);
-                    }
-                    (ByteCount::MORE0, '0') => {
-                        oxygen_keep.push(row);
-                    }
-                    (ByteCount::EQUAL, '1') => {
-                        oxygen_keep.push(row);
-                    }
-                    _ => (),
-                }
-            });
-            oxygen = oxygen_keep;
-        }
-        if co2.len() > 1 {
-            let co2_counts = counts(co2.iter());
+        let epsilon: String = counts
+            .iter()
+            .map(|(_, v)| {
+                let v = match *v {
+                    ByteCount::MORE0 => 1,
+                    ByteCount::MORE1 => 0,
+                    _ => panic!("Unknown digit"),
+                };
+                char::from_digit(v, 10).unwrap()
+            })
+            .collect();
+
+        let gamman: i32 = i32::from_str_radix(&gamma, 2).unwrap();
+        let epsilonn: i32 = i32::from_str_radix(&epsilon, 2).unwrap();
+        gamman * epsilonn
+    }
 
-            let mut co2_keep: Vec<&str> = Vec::new();
+    fn part2(data: Self::Input<'_>) -> Self::Output2 {
+        let len = data[0].chars().count();
 
-            co2.iter().for_each(|row| {
-                match (co2_counts.get(&i).unwrap(), row.chars().nth(i).unwrap()) {
-                    (ByteCount::MORE1, '0') => {
-                        co2_keep.push(row);
-                    }
-                    (ByteCount::MORE0, '1') => {
-                        co2_keep.push(row);
+        let mut oxygen: Vec<&str> = data;
+        let mut co2: Vec<&str> = oxygen.clone();
+
+        for i in 0..(len) {
+            if oxygen.len() > 1 {
+                let oxygen_counts = counts(oxygen.iter());
+
+                let mut oxygen_keep: Vec<&str> = Vec::new();
+
+                oxygen.iter().for_each(|row| {
+                    match (oxygen_counts.get(&i).unwrap(), row.chars().nth(i).unwrap()) {
+                        (ByteCount::MORE1, '1') => {
+                            oxygen_keep.push(row);
+                        }
+                        (ByteCount::MORE0, '0') => {
+                            oxygen_keep.push(row);
+                        }
+                        (ByteCount::EQUAL, '1') => {
+                            oxygen_keep.push(row);
+                        }
+                        _ => (),
                     }
-                    (ByteCount::EQUAL, '0') => {
-                        co2_keep.push(row);
+                });
+                oxygen = oxygen_keep;
+            }
+            if co2.len() > 1 {
+                let co2_counts = counts(co2.iter());
+
+                let mut co2_keep: Vec<&str> = Vec::new();
+
+                co2.iter().for_each(|row| {
+                    match (co2_counts.get(&i).unwrap(), row.chars().nth(i).unwrap()) {
+                        (ByteCount::MORE1, '0') => {
+                            co2_keep.push(row);
+                        }
+                        (ByteCount::MORE0, '1') => {
+                            co2_keep.push(row);
+                        }
+                        (ByteCount::EQUAL, '0') => {
+                            co2_keep.push(row);
+                        }
+                        _ => (),
                     }
-                    _ => (),
-                }
-            });
-            co2 = co2_keep;
+                });
+                co2 = co2_keep;
+            }
         }
-    }
 
-    let oxygenn: i32 = i32::from_str_radix(&oxygen.get(0).unwrap(), 2).unwrap();
-    let co2n: i32 = i32::from_str_radix(&co2.get(0).unwrap(), 2).unwrap();
-    oxygenn * co2n
+        let oxygenn: i32 = i32::from_str_radix(&oxygen.get(0).unwrap(), 2).unwrap();
+        let co2n: i32 = i32::from_str_radix(&co2.get(0).unwrap(), 2).unwrap();
+        oxygenn * co2n
+    }
 }
@@ -1,5 +1,7 @@
+use util::Solution;
+
 #[test]
-fn day03p01_sample() {
+fn example_1() {
     let data = "\
     00100
     11110
@@ -14,16 +16,19 @@ fn day03p01_sample() {
     00010
     01010\
     ";
-    assert_eq!(day03::part1(data.to_string()), 198)
+    assert_eq!(day03::Day::part1(day03::Day::parse(data)), 198)
 }
 
 #[test]
-fn day03p01() {
-    assert_eq!(day03::part1(util::read_input("../..", 3)), 1540244)
+fn input_1() {
+    assert_eq!(
+        day03::Day::part1(day03::Day::parse(&util::test_input("../..", 3))),
+        1540244
+    )
 }
 
 #[test]
-fn day03p02_sample() {
+fn example_2() {
     let data = "\
     00100
     11110
@@ -38,10 +43,13 @@ fn day03p02_sample() {
     00010
     01010\
     ";
-    assert_eq!(day03::part2(data.to_string()), 230)
+    assert_eq!(day03::Day::part2(day03::Day::parse(data)), 230)
 }
 
 #[test]
-fn day03p02() {
-    assert_eq!(day03::part2(util::read_input("../..", 3)), 4203981)
+fn input_2() {
+    assert_eq!(
+        day03::Day::part2(day03::Day::parse(&util::test_input("../..", 3))),
+        4203981
+    )
 }
@@ -1,5 +1,6 @@
 use parser::separated_digits;
 use std::collections::HashMap;
+use util::Solution;
 
 use nom::{
     character::complete::line_ending,
@@ -12,7 +13,7 @@ use nom::{
 };
 
 #[derive(Debug, PartialEq, Eq)]
-struct BingoCard {
+pub struct BingoCard {
     num_idx: HashMap<i32, (i32, i32)>,
     row_counts: HashMap<i32, i32>,
     column_counts: HashMap<i32, i32>,
@@ -90,44 +91,53 @@ fn header(s: &str) -> IResult<&str, Vec<i32>> {
     terminated(sep_num_list, count(line_ending, 2))(s)
 }
 
-pub fn part1(data: String) -> i32 {
-    let (_rest, (header, mut tabs)) = tuple((header, cards))(&data).unwrap();
+pub struct Day;
 
-    for i in header {
-        for card in tabs.iter_mut() {
-            if card.claim(i) {
-                return card.sum_remaining() * i;
+impl Solution for Day {
+    type Input<'a> = (Vec<i32>, Vec<BingoCard>);
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        let (_rest, (header, tabs)) = tuple((header, cards))(data).unwrap();
+        (header, tabs)
+    }
+
+    fn part1((header, mut tabs): Self::Input<'_>) -> Self::Output1 {
+        for i in header {
+            for card in tabs.iter_mut() {
+                if card.claim(i) {
+                    return card.sum_remaining() * i;
+                }
             }
         }
-    }
 
-    0
-}
+        0
+    }
 
-pub fn part2(data: String) -> i32 {
-    let (_rest, (header, mut tabs)) = tuple((header, cards))(&data).unwrap();
-
-    for i in header {
-        let mut j = 0;
-        let mut max = tabs.len();
-        let mut last_found: Option<BingoCard> = None;
-        while j < max {
-            match tabs[j].claim(i) {
-                true => {
-                    last_found = Some(tabs.remove(j));
-                    max -= 1;
-                }
-                false => {
-                    j += 1;
+    fn part2((header, mut tabs): Self::Input<'_>) -> Self::Output2 {
+        for i in header {
+            let mut j = 0;
+            let mut max = tabs.len();
+            let mut last_found: Option<BingoCard> = None;
+            while j < max {
+                match tabs[j].claim(i) {
+                    true => {
+                        last_found = Some(tabs.remove(j));
+                        max -= 1;
+                    }
+                    false => {
+                        j += 1;
+                    }
                 }
             }
+            if tabs.len() == 0 {
+                return last_found.unwrap().sum_remaining() * i;
+            }
         }
-        if tabs.len() == 0 {
-            return last_found.unwrap().sum_remaining() * i;
-        }
-    }
 
-    0
+        0
+    }
 }
 
 #[cfg(test)]
@@ -1,3 +1,5 @@
+use util::Solution;
+
 const DATA: &str = "\
 7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
 
@@ -22,21 +24,27 @@ const DATA: &str = "\
 ";
 
 #[test]
-fn day04p01_sample() {
-    assert_eq!(day04::part1(DATA.to_string()), 4512)
+fn example_1() {
+    assert_eq!(day04::Day::part1(day04::Day::parse(DATA)), 4512)
 }
 
 #[test]
-fn day04p01() {
-    assert_eq!(day04::part1(util::read_input("../..", 4)), 49860)
+fn input_1() {
+    assert_eq!(
+        day04::Day::part1(day04::Day::parse(&util::test_input("../..", 4))),
+        49860
+    )
 }
 
 #[test]
-fn day04p02_sample() {
-    assert_eq!(day04::part2(DATA.to_string()), 1924)
+fn example_2() {
+    assert_eq!(day04::Day::part2(day04::Day::parse(DATA)), 1924)
 }
 
 #[test]
-fn day04p02() {
-    assert_eq!(day04::part2(util::read_input("../..", 4)), 24628)
+fn input_2() {
+    assert_eq!(
+        day04::Day::part2(day04::Day::parse(&util::test_input("../..", 4))),
+        24628
+    )
 }
@@ -2,6 +2,7 @@ use core::fmt;
 use parser;
 use std::collections::hash_map::{Entry, Iter};
 use std::collections::HashMap;
+use util::Solution;
 
 use nom::{
     bytes::complete::tag,
@@ -12,7 +13,7 @@ use nom::{
 };
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
-struct Point {
+pub struct Point {
     x: i32,
     y: i32,
 }
@@ -30,7 +31,7 @@ impl fmt::Display for Point {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct Pipe {
+pub struct Pipe {
     start: Point,
     end: Point,
 }
@@ -124,15 +125,40 @@ fn pipes(s: &str) -> IResult<&str, Vec<Pipe>> {
     many0(terminated(pipe, line_ending))(s)
 }
 
-pub fn part1(data: String) -> usize {
-    let (_res, pipes) = pipes(&data).unwrap();
+pub struct Day;
 
-    let mut diagram: HashMap<Point, u32> = HashMap::new();
+impl Solution for Day {
+    type Input<'a> = Vec<Pipe>;
+    type Output1 = usize;
+    type Output2 = usize;
 
-    pipes
-        .iter()
-        .filter(|pipe| pipe.straignt())
-        .for_each(|pipe| {
+    fn parse(data: &str) -> Self::Input<'_> {
+        let (_res, pipes) = pipes(data).unwrap();
+        pipes
+    }
+
+    fn part1(pipes: Self::Input<'_>) -> Self::Output1 {
+        let mut diagram: HashMap<Point, u32> = HashMap::new();
+
+        pipes
+            .iter()
+            .filter(|pipe| pipe.straignt())
+            .for_each(|pipe| {
+                pipe.points().iter().for_each(|point| {
+                    diagram
+                        .entry(point.clone())
+                        .and_modify(|x| *x += 1)
+                        .or_insert(1);
+                })
+            });
+
+        diagram.iter().filter(|(_, n)| **n >= 2).count()
+    }
+
+    fn part2(pipes: Self::Input<'_>) -> Self::Output2 {
+        let mut diagram: Pipes = Pipes::new();
+
+        pipes.iter().for_each(|pipe| {
             pipe.points().iter().for_each(|point| {
                 diagram
                     .entry(point.clone())
@@ -141,7 +167,8 @@ pub fn part1(data: String) -> usize {
             })
         });
 
-    diagram.iter().filter(|(_, n)| **n >= 2).count()
+        diagram.iter().filter(|(_, n)| **n >= 2).count()
+    }
 }
 
 impl fmt::Display for Pipes {
@@ -165,23 +192,6 @@ impl fmt::Display for Pipes {
     }
 }
 
-pub fn part2(data: String) -> usize {
-    let (_res, pipes) = pipes(&data).unwrap();
-
-    let mut diagram: Pipes = Pipes::new();
-
-    pipes.iter().for_each(|pipe| {
-        pipe.points().iter().for_each(|point| {
-            diagram
-                .entry(point.clone())
-                .and_modify(|x| *x += 1)
-                .or_insert(1);
-        })
-    });
-
-    diagram.iter().filter(|(_, n)| **n >= 2).count()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
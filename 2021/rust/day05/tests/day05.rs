@@ -1,3 +1,5 @@
+use util::Solution;
+
 const DATA: &str = "\
 0,9 -> 5,9
 8,0 -> 0,8
@@ -12,21 +14,27 @@ const DATA: &str = "\
 ";
 
 #[test]
-fn day05p01_sample() {
-    assert_eq!(day05::part1(DATA.to_string()), 5)
+fn example_1() {
+    assert_eq!(day05::Day::part1(day05::Day::parse(DATA)), 5)
 }
 
 #[test]
-fn day05p01() {
-    assert_eq!(day05::part1(util::read_input("../..", 5)), 6564)
+fn input_1() {
+    assert_eq!(
+        day05::Day::part1(day05::Day::parse(&util::test_input("../..", 5))),
+        6564
+    )
 }
 
 #[test]
-fn day05p02_sample() {
-    assert_eq!(day05::part2(DATA.to_string()), 12)
+fn example_2() {
+    assert_eq!(day05::Day::part2(day05::Day::parse(DATA)), 12)
 }
 
 #[test]
-fn day05p02() {
-    assert_eq!(day05::part2(util::read_input("../..", 5)), 19172)
+fn input_2() {
+    assert_eq!(
+        day05::Day::part2(day05::Day::parse(&util::test_input("../..", 5))),
+        19172
+    )
 }
@@ -1,9 +1,8 @@
 use parser::separated_digits;
 use std::collections::HashMap;
+use util::Solution;
 
-fn frisky_fish(data: String, days: usize) -> u64 {
-    let (_rest, fish) = separated_digits(&data).unwrap();
-
+fn frisky_fish(fish: Vec<i32>, days: usize) -> u64 {
     let mut school = HashMap::new();
 
     for f in fish {
@@ -36,10 +35,23 @@ fn frisky_fish(data: String, days: usize) -> u64 {
     school.values().sum::<u64>()
 }
 
-pub fn part1(data: String) -> u64 {
-    frisky_fish(data, 80)
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<i32>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        let (_rest, fish) = separated_digits(data).unwrap();
+        fish
+    }
+
+    fn part1(fish: Self::Input<'_>) -> Self::Output1 {
+        frisky_fish(fish, 80)
+    }
 
-pub fn part2(data: String) -> u64 {
-    frisky_fish(data, 256)
+    fn part2(fish: Self::Input<'_>) -> Self::Output2 {
+        frisky_fish(fish, 256)
+    }
 }
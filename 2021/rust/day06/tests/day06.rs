@@ -1,21 +1,29 @@
+use util::Solution;
+
 const DATA: &str = "3,4,3,1,2";
 
 #[test]
-fn day06p01_sample() {
-    assert_eq!(day06::part1(DATA.to_string()), 5934u64)
+fn example_1() {
+    assert_eq!(day06::Day::part1(day06::Day::parse(DATA)), 5934u64)
 }
 
 #[test]
-fn day06p01() {
-    assert_eq!(day06::part1(util::read_input("../..", 6)), 361169u64)
+fn input_1() {
+    assert_eq!(
+        day06::Day::part1(day06::Day::parse(&util::test_input("../..", 6))),
+        361169u64
+    )
 }
 
 #[test]
-fn day06p02_sample() {
-    assert_eq!(day06::part2(DATA.to_string()), 26984457539u64)
+fn example_2() {
+    assert_eq!(day06::Day::part2(day06::Day::parse(DATA)), 26984457539u64)
 }
 
 #[test]
-fn day06p02() {
-    assert_eq!(day06::part2(util::read_input("../..", 6)), 1634946868992u64)
+fn input_2() {
+    assert_eq!(
+        day06::Day::part2(day06::Day::parse(&util::test_input("../..", 6))),
+        1634946868992u64
+    )
 }
@@ -1,31 +1,41 @@
 use parser::separated_digits;
+use util::Solution;
 
-pub fn part1(data: String) -> i32 {
-    let (_rest, mut positions) = separated_digits(&data).unwrap();
+pub struct Day;
 
-    positions.sort();
+impl Solution for Day {
+    type Input<'a> = Vec<i32>;
+    type Output1 = i32;
+    type Output2 = i32;
 
-    let target = positions[positions.len() / 2];
-
-    let mut sum: i32 = 0;
-    for pos in positions {
-        let dist = (pos - target).abs();
-        sum += dist;
+    fn parse(data: &str) -> Self::Input<'_> {
+        let (_rest, positions) = separated_digits(data).unwrap();
+        positions
     }
 
-    sum
-}
+    fn part1(mut positions: Self::Input<'_>) -> Self::Output1 {
+        positions.sort();
 
-pub fn part2(data: String) -> i32 {
-    let (_rest, positions) = separated_digits(&data).unwrap();
+        let target = positions[positions.len() / 2];
 
-    let target: i32 = positions.iter().sum::<i32>() / positions.len() as i32;
+        let mut sum: i32 = 0;
+        for pos in positions {
+            let dist = (pos - target).abs();
+            sum += dist;
+        }
 
-    let mut sum: i32 = 0;
-    for pos in positions {
-        let dist = (pos - target).abs();
-        sum += (1..=dist).fold(0, |a, b| a + b);
+        sum
     }
 
-    sum
+    fn part2(positions: Self::Input<'_>) -> Self::Output2 {
+        let target: i32 = positions.iter().sum::<i32>() / positions.len() as i32;
+
+        let mut sum: i32 = 0;
+        for pos in positions {
+            let dist = (pos - target).abs();
+            sum += (1..=dist).fold(0, |a, b| a + b);
+        }
+
+        sum
+    }
 }
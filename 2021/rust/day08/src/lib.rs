@@ -5,6 +5,7 @@ use nom::{
     sequence::{terminated, tuple},
     IResult,
 };
+use util::Solution;
 
 fn pattern(s: &str) -> IResult<&str, &str> {
     alpha1(s)
@@ -18,21 +19,74 @@ fn parse(s: &str) -> IResult<&str, Vec<(Vec<&str>, &str, Vec<&str>)>> {
             separated_list1(tag(" "), pattern),
         )),
         line_ending,
-    ))(&s)
+    ))(s)
 }
 
-pub fn part1(data: String) -> i32 {
-    let (_rest, lines) = parse(&data).unwrap();
+pub struct Day;
 
-    lines
-        .iter()
-        .map(|(_, _, output)| {
-            output
-                .iter()
-                .filter(|digit| [2, 3, 4, 7].contains(&digit.len()))
-                .count() as i32
-        })
-        .sum()
+impl Solution for Day {
+    type Input<'a> = Vec<(Vec<&'a str>, &'a str, Vec<&'a str>)>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        let (_rest, lines) = parse(data).unwrap();
+        lines
+    }
+
+    fn part1(lines: Self::Input<'_>) -> Self::Output1 {
+        lines
+            .iter()
+            .map(|(_, _, output)| {
+                output
+                    .iter()
+                    .filter(|digit| [2, 3, 4, 7].contains(&digit.len()))
+                    .count() as i32
+            })
+            .sum()
+    }
+
+    fn part2(lines: Self::Input<'_>) -> Self::Output2 {
+        lines
+            .iter()
+            .map(|(mixed_wires, _, output)| {
+                let one = mixed_wires
+                    .iter()
+                    .find(|pattern| pattern.len() == 2)
+                    .unwrap();
+                let four = mixed_wires
+                    .iter()
+                    .find(|pattern| pattern.len() == 4)
+                    .unwrap();
+
+                output
+                    .iter()
+                    .rev()
+                    .map(|pattern| {
+                        match (
+                            pattern.len(),
+                            common_with(pattern, one),
+                            common_with(pattern, four),
+                        ) {
+                            (2, _, _) => 1,
+                            (5, _, 2) => 2,
+                            (5, 2, _) => 3,
+                            (4, _, _) => 4,
+                            (5, 1, _) => 5,
+                            (6, 1, _) => 6,
+                            (3, _, _) => 7,
+                            (7, _, _) => 8,
+                            (6, _, 4) => 9,
+                            (6, _, 3) => 0,
+                            (_, _, _) => panic!(),
+                        }
+                    })
+                    .enumerate()
+                    .map(|(i, n)| n * (10i32.pow(i as u32)))
+                    .sum::<i32>()
+            })
+            .sum()
+    }
 }
 
 fn common_with(pattern: &str, target: &str) -> i32 {
@@ -40,47 +94,3 @@ fn common_with(pattern: &str, target: &str) -> i32 {
         .chars()
         .fold(0, |acc, c| if pattern.contains(c) { acc + 1 } else { acc })
 }
-
-pub fn part2(data: String) -> i32 {
-    let (_rest, lines) = parse(&data).unwrap();
-
-    lines
-        .iter()
-        .map(|(mixed_wires, _, output)| {
-            let one = mixed_wires
-                .iter()
-                .find(|pattern| pattern.len() == 2)
-                .unwrap();
-            let four = mixed_wires
-                .iter()
-                .find(|pattern| pattern.len() == 4)
-                .unwrap();
-
-            output
-                .iter()
-                .rev()
-                .map(|pattern| {
-                    match (
-                        pattern.len(),
-                        common_with(pattern, one),
-                        common_with(pattern, four),
-                    ) {
-                        (2, _, _) => 1,
-                        (5, _, 2) => 2,
-                        (5, 2, _) => 3,
-                        (4, _, _) => 4,
-                        (5, 1, _) => 5,
-                        (6, 1, _) => 6,
-                        (3, _, _) => 7,
-                        (7, _, _) => 8,
-                        (6, _, 4) => 9,
-                        (6, _, 3) => 0,
-                        (_, _, _) => panic!(),
-                    }
-                })
-                .enumerate()
-                .map(|(i, n)| n * (10i32.pow(i as u32)))
-                .sum::<i32>()
-        })
-        .sum()
-}
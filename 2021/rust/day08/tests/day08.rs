@@ -1,3 +1,5 @@
+use util::Solution;
+
 const DATA: &str = "\
 be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
 edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
@@ -12,21 +14,27 @@ gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
 ";
 
 #[test]
-fn day08p01_sample() {
-    assert_eq!(day08::part1(DATA.to_string()), 26)
+fn example_1() {
+    assert_eq!(day08::Day::part1(day08::Day::parse(DATA)), 26)
 }
 
 #[test]
-fn day08p01() {
-    assert_eq!(day08::part1(util::read_input("../..", 8)), 554)
+fn input_1() {
+    assert_eq!(
+        day08::Day::part1(day08::Day::parse(&util::test_input("../..", 8))),
+        554
+    )
 }
 
 #[test]
-fn day08p02_sample() {
-    assert_eq!(day08::part2(DATA.to_string()), 61229)
+fn example_2() {
+    assert_eq!(day08::Day::part2(day08::Day::parse(DATA)), 61229)
 }
 
 #[test]
-fn day08p02() {
-    assert_eq!(day08::part2(util::read_input("../..", 8)), 990964)
+fn input_2() {
+    assert_eq!(
+        day08::Day::part2(day08::Day::parse(&util::test_input("../..", 8))),
+        990964
+    )
 }
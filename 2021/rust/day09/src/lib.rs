@@ -1,4 +1,5 @@
 use std::collections::{HashMap, HashSet};
+use util::Solution;
 
 use nom::{
     bytes::complete::take,
@@ -63,10 +64,44 @@ fn find_lows(map: &HashMap<(usize, usize), usize>) -> HashMap<(usize, usize), us
     lows
 }
 
-pub fn part1(data: String) -> usize {
-    let (_rest, map) = parse_map(&data).unwrap();
+pub struct Day;
 
-    find_lows(&map).values().map(|x| x + 1).sum()
+impl Solution for Day {
+    type Input<'a> = HashMap<(usize, usize), usize>;
+    type Output1 = usize;
+    type Output2 = i32;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        let (_rest, map) = parse_map(data).unwrap();
+        map
+    }
+
+    fn part1(map: Self::Input<'_>) -> Self::Output1 {
+        find_lows(&map).values().map(|x| x + 1).sum()
+    }
+
+    fn part2(mut map: Self::Input<'_>) -> Self::Output2 {
+        for (point, v) in map.clone().into_iter() {
+            if v >= 9usize {
+                map.remove(&point);
+            }
+        }
+
+        let lows = find_lows(&map);
+
+        let mut sizes = lows
+            .iter()
+            .map(|(point, _)| {
+                let mut visited: HashSet<(usize, usize)> = HashSet::new();
+                basin_size(&map, *point, &mut visited);
+                visited.len() as i32
+            })
+            .collect::<Vec<i32>>();
+
+        sizes.sort();
+
+        sizes.iter().rev().take(3).product()
+    }
 }
 
 fn basin_size<'a>(
@@ -84,28 +119,3 @@ fn basin_size<'a>(
 
     visited
 }
-
-pub fn part2(data: String) -> i32 {
-    let (_rest, mut map) = parse_map(&data).unwrap();
-
-    for (point, v) in map.clone().into_iter() {
-        if v >= 9usize {
-            map.remove(&point);
-        }
-    }
-
-    let lows = find_lows(&map);
-
-    let mut sizes = lows
-        .iter()
-        .map(|(point, _)| {
-            let mut visited: HashSet<(usize, usize)> = HashSet::new();
-            basin_size(&map, *point, &mut visited);
-            visited.len() as i32
-        })
-        .collect::<Vec<i32>>();
-
-    sizes.sort();
-
-    sizes.iter().rev().take(3).product()
-}
@@ -1,3 +1,5 @@
+use util::Solution;
+
 const DATA: &str = "\
 2199943210
 3987894921
@@ -7,21 +9,27 @@ const DATA: &str = "\
 ";
 
 #[test]
-fn day09p01_sample() {
-    assert_eq!(day09::part1(DATA.to_string()), 15)
+fn example_1() {
+    assert_eq!(day09::Day::part1(day09::Day::parse(DATA)), 15)
 }
 
 #[test]
-fn day09p01() {
-    assert_eq!(day09::part1(util::read_input("../..", 9)), 566)
+fn input_1() {
+    assert_eq!(
+        day09::Day::part1(day09::Day::parse(&util::test_input("../..", 9))),
+        566
+    )
 }
 
 #[test]
-fn day09p02_sample() {
-    assert_eq!(day09::part2(DATA.to_string()), 1134)
+fn example_2() {
+    assert_eq!(day09::Day::part2(day09::Day::parse(DATA)), 1134)
 }
 
 #[test]
-fn day09p02() {
-    assert_eq!(day09::part2(util::read_input("../..", 9)), 891684)
+fn input_2() {
+    assert_eq!(
+        day09::Day::part2(day09::Day::parse(&util::test_input("../..", 9))),
+        891684
+    )
 }
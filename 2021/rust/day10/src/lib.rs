@@ -1,4 +1,5 @@
 use std::collections::VecDeque;
+use util::Solution;
 
 const OPEN: &[char] = &['(', '<', '[', '{'];
 const CLOSE: &[char] = &[')', '>', ']', '}'];
@@ -20,8 +21,8 @@ enum NavigationValidation {
     INCOMPLETE(VecDeque<char>),
 }
 
-fn validate(data: &String) -> impl Iterator<Item = NavigationValidation> + '_ {
-    data.lines().map(|line| {
+fn validate<'a>(lines: &'a [&str]) -> impl Iterator<Item = NavigationValidation> + 'a {
+    lines.iter().map(|line| {
         let mut stack: VecDeque<char> = VecDeque::new();
         let mut ch: Option<char> = None;
 
@@ -60,50 +61,62 @@ fn validate(data: &String) -> impl Iterator<Item = NavigationValidation> + '_ {
     })
 }
 
-pub fn part1(data: String) -> i64 {
-    validate(&data)
-        .filter(|c| matches!(c, NavigationValidation::INVALID(_)))
-        .map(|s| {
-            if let NavigationValidation::INVALID(s) = s {
-                match s {
-                    ')' => 3,
-                    ']' => 57,
-                    '}' => 1197,
-                    '>' => 25137,
-                    _ => {
-                        unimplemented!()
-                    }
-                }
-            } else {
-                0
-            }
-        })
-        .sum()
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<&'a str>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        data.lines().collect()
+    }
 
-pub fn part2(data: String) -> i64 {
-    let mut values: Vec<i64> = validate(&data)
-        .filter(|c| matches!(c, NavigationValidation::INCOMPLETE(_)))
-        .map(|s| {
-            if let NavigationValidation::INCOMPLETE(s) = s {
-                s.iter()
-                    .map(|c| match close_from_open(*c) {
-                        ')' => 1,
-                        ']' => 2,
-                        '}' => 3,
-                        '>' => 4,
+    fn part1(lines: Self::Input<'_>) -> Self::Output1 {
+        validate(&lines)
+            .filter(|c| matches!(c, NavigationValidation::INVALID(_)))
+            .map(|s| {
+                if let NavigationValidation::INVALID(s) = s {
+                    match s {
+                        ')' => 3,
+                        ']' => 57,
+                        '}' => 1197,
+                        '>' => 25137,
                         _ => {
                             unimplemented!()
                         }
-                    })
-                    .fold(0, |acc, v| (acc * 5) + v)
-            } else {
-                unimplemented!()
-            }
-        })
-        .collect();
+                    }
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+
+    fn part2(lines: Self::Input<'_>) -> Self::Output2 {
+        let mut values: Vec<i64> = validate(&lines)
+            .filter(|c| matches!(c, NavigationValidation::INCOMPLETE(_)))
+            .map(|s| {
+                if let NavigationValidation::INCOMPLETE(s) = s {
+                    s.iter()
+                        .map(|c| match close_from_open(*c) {
+                            ')' => 1,
+                            ']' => 2,
+                            '}' => 3,
+                            '>' => 4,
+                            _ => {
+                                unimplemented!()
+                            }
+                        })
+                        .fold(0, |acc, v| (acc * 5) + v)
+                } else {
+                    unimplemented!()
+                }
+            })
+            .collect();
 
-    values.sort();
+        values.sort();
 
-    values[values.len() / 2]
+        values[values.len() / 2]
+    }
 }
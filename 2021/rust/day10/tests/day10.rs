@@ -1,3 +1,5 @@
+use util::Solution;
+
 const DATA: &str = "\
 [({(<(())[]>[[{[]{<()<>>
 [(()[<>])]({[<{<<[]>>(
@@ -12,21 +14,27 @@ const DATA: &str = "\
 ";
 
 #[test]
-fn day10p01_sample() {
-    assert_eq!(day10::part1(DATA.to_string()), 26397)
+fn example_1() {
+    assert_eq!(day10::Day::part1(day10::Day::parse(DATA)), 26397)
 }
 
 #[test]
-fn day10p01() {
-    assert_eq!(day10::part1(util::read_input("../..", 10)), 316851)
+fn input_1() {
+    assert_eq!(
+        day10::Day::part1(day10::Day::parse(&util::test_input("../..", 10))),
+        316851
+    )
 }
 
 #[test]
-fn day10p02_sample() {
-    assert_eq!(day10::part2(DATA.to_string()), 288957)
+fn example_2() {
+    assert_eq!(day10::Day::part2(day10::Day::parse(DATA)), 288957)
 }
 
 #[test]
-fn day10p02() {
-    assert_eq!(day10::part2(util::read_input("../..", 10)), 2182912364)
+fn input_2() {
+    assert_eq!(
+        day10::Day::part2(day10::Day::parse(&util::test_input("../..", 10))),
+        2182912364
+    )
 }
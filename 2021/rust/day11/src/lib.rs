@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use util::Solution;
 
 #[derive(Hash, Eq, PartialEq, Copy, Clone)]
 struct Point {
@@ -12,7 +13,7 @@ impl Point {
     }
 }
 
-struct OctopusGarden {
+pub struct OctopusGarden {
     octopi: HashMap<Point, u32>,
     width: usize,
     height: usize,
@@ -21,7 +22,7 @@ struct OctopusGarden {
 }
 
 impl OctopusGarden {
-    pub fn new() -> OctopusGarden {
+    fn new() -> OctopusGarden {
         OctopusGarden {
             octopi: HashMap::new(),
             width: 0usize,
@@ -104,7 +105,7 @@ impl OctopusGarden {
     }
 }
 
-fn parse_garden(data: String) -> OctopusGarden {
+fn parse_garden(data: &str) -> OctopusGarden {
     data.lines()
         .enumerate()
         .fold(OctopusGarden::new(), |garden, (y, row)| {
@@ -115,25 +116,33 @@ fn parse_garden(data: String) -> OctopusGarden {
         })
 }
 
-pub fn part1(data: String) -> u32 {
-    let mut garden = parse_garden(data);
+pub struct Day;
 
-    for _ in 0..100 {
-        garden.flash_all();
+impl Solution for Day {
+    type Input<'a> = OctopusGarden;
+    type Output1 = u32;
+    type Output2 = i32;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        parse_garden(data)
     }
 
-    garden.flashes
-}
+    fn part1(mut garden: Self::Input<'_>) -> Self::Output1 {
+        for _ in 0..100 {
+            garden.flash_all();
+        }
 
-pub fn part2(data: String) -> i32 {
-    let mut garden = parse_garden(data);
+        garden.flashes
+    }
 
-    let mut counter = 0;
+    fn part2(mut garden: Self::Input<'_>) -> Self::Output2 {
+        let mut counter = 0;
 
-    while garden.recently_flashed.len() < garden.octopi.len() {
-        garden.flash_all();
-        counter += 1;
-    }
+        while garden.recently_flashed.len() < garden.octopi.len() {
+            garden.flash_all();
+            counter += 1;
+        }
 
-    counter
+        counter
+    }
 }
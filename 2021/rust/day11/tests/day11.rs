@@ -1,3 +1,5 @@
+use util::Solution;
+
 const DATA: &str = "\
 5483143223
 2745854711
@@ -12,21 +14,27 @@ const DATA: &str = "\
 ";
 
 #[test]
-fn day11p01_sample() {
-    assert_eq!(day11::part1(DATA.to_string()), 1656)
+fn example_1() {
+    assert_eq!(day11::Day::part1(day11::Day::parse(DATA)), 1656)
 }
 
 #[test]
-fn day11p01() {
-    assert_eq!(day11::part1(util::read_input("../..", 11)), 1717)
+fn input_1() {
+    assert_eq!(
+        day11::Day::part1(day11::Day::parse(&util::test_input("../..", 11))),
+        1717
+    )
 }
 
 #[test]
-fn day11p02_sample() {
-    assert_eq!(day11::part2(DATA.to_string()), 195)
+fn example_2() {
+    assert_eq!(day11::Day::part2(day11::Day::parse(DATA)), 195)
 }
 
 #[test]
-fn day11p02() {
-    assert_eq!(day11::part2(util::read_input("../..", 11)), 476)
+fn input_2() {
+    assert_eq!(
+        day11::Day::part2(day11::Day::parse(&util::test_input("../..", 11))),
+        476
+    )
 }
@@ -6,6 +6,7 @@ use nom::{
     sequence::tuple,
     IResult,
 };
+use util::Solution;
 
 use std::fmt;
 use std::fmt::Debug;
@@ -13,7 +14,7 @@ use std::fmt::Debug;
 pub type NodeIndex = usize;
 pub type EdgeIndex = usize;
 
-struct Graph {
+pub struct Graph {
     nodes: Vec<NodeData>,
     edges: Vec<EdgeData>,
 }
@@ -47,7 +48,7 @@ struct EdgeData {
 }
 
 impl Graph {
-    pub fn new() -> Graph {
+    fn new() -> Graph {
         Graph {
             nodes: vec![],
             edges: vec![],
@@ -173,8 +174,8 @@ fn connection(s: &str) -> IResult<&str, (Cave, Cave)> {
     Ok((rest, (start, end)))
 }
 
-fn graph_from_data(data: String) -> Graph {
-    let (_rest, caves) = separated_list1(line_ending, connection)(&data).unwrap();
+fn graph_from_data(data: &str) -> Graph {
+    let (_rest, caves) = separated_list1(line_ending, connection)(data).unwrap();
     let mut graph = Graph::new();
 
     for (source, dest) in caves {
@@ -187,30 +188,40 @@ fn graph_from_data(data: String) -> Graph {
     graph
 }
 
-pub fn part1(data: String) -> usize {
-    let graph = graph_from_data(data);
-    let mut small_counts: Vec<usize> = Vec::new();
-    small_counts.resize(graph.size(), 0);
+pub struct Day;
 
-    count_paths(
-        &graph,
-        graph.get_node(&Cave::START).unwrap(),
-        small_counts,
-        1,
-    )
-}
+impl Solution for Day {
+    type Input<'a> = Graph;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        graph_from_data(data)
+    }
 
-pub fn part2(data: String) -> usize {
-    let graph = graph_from_data(data);
-    let mut small_counts: Vec<usize> = Vec::new();
-    small_counts.resize(graph.size(), 0);
+    fn part1(graph: Self::Input<'_>) -> Self::Output1 {
+        let mut small_counts: Vec<usize> = Vec::new();
+        small_counts.resize(graph.size(), 0);
 
-    count_paths(
-        &graph,
-        graph.get_node(&Cave::START).unwrap(),
-        small_counts,
-        2,
-    )
+        count_paths(
+            &graph,
+            graph.get_node(&Cave::START).unwrap(),
+            small_counts,
+            1,
+        )
+    }
+
+    fn part2(graph: Self::Input<'_>) -> Self::Output2 {
+        let mut small_counts: Vec<usize> = Vec::new();
+        small_counts.resize(graph.size(), 0);
+
+        count_paths(
+            &graph,
+            graph.get_node(&Cave::START).unwrap(),
+            small_counts,
+            2,
+        )
+    }
 }
 
 fn count_paths(
@@ -1,3 +1,5 @@
+use util::Solution;
+
 const DATA: &str = "\
 start-A
 start-b
@@ -9,21 +11,27 @@ b-end
 ";
 
 #[test]
-fn day12p01_sample() {
-    assert_eq!(day12::part1(DATA.to_string()), 10)
+fn example_1() {
+    assert_eq!(day12::Day::part1(day12::Day::parse(DATA)), 10)
 }
 
 #[test]
-fn day12p01() {
-    assert_eq!(day12::part1(util::read_input("../..", 12)), 4885)
+fn input_1() {
+    assert_eq!(
+        day12::Day::part1(day12::Day::parse(&util::test_input("../..", 12))),
+        4885
+    )
 }
 
 #[test]
-fn day12p02_sample() {
-    assert_eq!(day12::part2(DATA.to_string()), 36)
+fn example_2() {
+    assert_eq!(day12::Day::part2(day12::Day::parse(DATA)), 36)
 }
 
 #[test]
-fn day12p02() {
-    assert_eq!(day12::part2(util::read_input("../..", 12)), 117095)
+fn input_2() {
+    assert_eq!(
+        day12::Day::part2(day12::Day::parse(&util::test_input("../..", 12))),
+        117095
+    )
 }
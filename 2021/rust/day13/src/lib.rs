@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use util::Solution;
 
 use nom::{
     bytes::complete::{tag, take},
@@ -9,13 +10,13 @@ use nom::{
 };
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
-struct Point {
+pub struct Point {
     x: i32,
     y: i32,
 }
 
 #[derive(Debug)]
-enum Fold {
+pub enum Fold {
     X(i32),
     Y(i32),
 }
@@ -53,8 +54,8 @@ fn folds(s: &str) -> IResult<&str, Vec<Fold>> {
     separated_list1(line_ending, fold)(s)
 }
 
-fn parse_data(data: String) -> (HashSet<Point>, Vec<Fold>) {
-    let (_rest, (points, _, folds)) = tuple((points, many1(line_ending), folds))(&data).unwrap();
+fn parse_data(data: &str) -> (HashSet<Point>, Vec<Fold>) {
+    let (_rest, (points, _, folds)) = tuple((points, many1(line_ending), folds))(data).unwrap();
 
     (points, folds)
 }
@@ -85,36 +86,44 @@ fn fold_grid<'a, 'b>(points: &'a mut HashSet<Point>, fold: &'b Fold) -> &'a Hash
     points
 }
 
-pub fn part1(data: String) -> usize {
-    let (mut points, folds) = parse_data(data);
+pub struct Day;
 
-    fold_grid(&mut points, &folds[0]);
+impl Solution for Day {
+    type Input<'a> = (HashSet<Point>, Vec<Fold>);
+    type Output1 = usize;
+    type Output2 = String;
 
-    points.len()
-}
+    fn parse(data: &str) -> Self::Input<'_> {
+        parse_data(data)
+    }
 
-pub fn part2(data: String) -> String {
-    let (mut points, folds) = parse_data(data);
+    fn part1((mut points, folds): Self::Input<'_>) -> Self::Output1 {
+        fold_grid(&mut points, &folds[0]);
 
-    for fold in folds {
-        fold_grid(&mut points, &fold);
+        points.len()
     }
 
-    let max_x = points.iter().fold(0, |max, point| max.max(point.x));
-    let max_y = points.iter().fold(0, |max, point| max.max(point.y));
+    fn part2((mut points, folds): Self::Input<'_>) -> Self::Output2 {
+        for fold in folds {
+            fold_grid(&mut points, &fold);
+        }
+
+        let max_x = points.iter().fold(0, |max, point| max.max(point.x));
+        let max_y = points.iter().fold(0, |max, point| max.max(point.y));
 
-    let mut s: String = String::new();
-    s.push('\n');
+        let mut s: String = String::new();
+        s.push('\n');
 
-    for y in 0..=max_y {
-        for x in 0..=max_x {
-            match points.contains(&Point { x, y }) {
-                true => s.push('#'),
-                false => s.push('.'),
+        for y in 0..=max_y {
+            for x in 0..=max_x {
+                match points.contains(&Point { x, y }) {
+                    true => s.push('#'),
+                    false => s.push('.'),
+                }
             }
+            s.push('\n')
         }
-        s.push('\n')
-    }
 
-    s
+        s
+    }
 }
@@ -1,3 +1,5 @@
+use util::Solution;
+
 const DATA: &str = "\
 6,10
 0,14
@@ -23,19 +25,22 @@ fold along x=5
 ";
 
 #[test]
-fn day13p01_sample() {
-    assert_eq!(day13::part1(DATA.to_string()), 17)
+fn example_1() {
+    assert_eq!(day13::Day::part1(day13::Day::parse(DATA)), 17)
 }
 
 #[test]
-fn day13p01() {
-    assert_eq!(day13::part1(util::read_input("../..", 13)), 689)
+fn input_1() {
+    assert_eq!(
+        day13::Day::part1(day13::Day::parse(&util::test_input("../..", 13))),
+        689
+    )
 }
 
 #[test]
-fn day13p02_sample() {
+fn example_2() {
     assert_eq!(
-        day13::part2(DATA.to_string()),
+        day13::Day::part2(day13::Day::parse(DATA)),
         "
 #####
 #...#
@@ -47,9 +52,9 @@ fn day13p02_sample() {
 }
 
 #[test]
-fn day13p02() {
+fn input_2() {
     assert_eq!(
-        day13::part2(util::read_input("../..", 13)),
+        day13::Day::part2(day13::Day::parse(&util::test_input("../..", 13))),
         "
 ###..#....###...##....##..##..#....#..#
 #..#.#....#..#.#..#....#.#..#.#....#..#
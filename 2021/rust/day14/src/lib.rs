@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use util::Solution;
 
 use nom::{
     bytes::complete::tag,
@@ -70,24 +71,32 @@ fn count(template: &str, rules: &HashMap<(char, char), char>, steps: u8) -> Hash
     counter
 }
 
-pub fn part1(data: String) -> i64 {
-    let (template, rules) = parse(&data);
+pub struct Day;
 
-    let counts = count(template, &rules, 10);
+impl Solution for Day {
+    type Input<'a> = (&'a str, HashMap<(char, char), char>);
+    type Output1 = i64;
+    type Output2 = i64;
 
-    let (_, max) = counts.iter().max_by_key(|(_c, v)| *v).unwrap();
-    let (_, min) = counts.iter().min_by_key(|(_c, v)| *v).unwrap();
+    fn parse(data: &str) -> Self::Input<'_> {
+        parse(data)
+    }
 
-    max - min
-}
+    fn part1((template, rules): Self::Input<'_>) -> Self::Output1 {
+        let counts = count(template, &rules, 10);
 
-pub fn part2(data: String) -> i64 {
-    let (template, rules) = parse(&data);
+        let (_, max) = counts.iter().max_by_key(|(_c, v)| *v).unwrap();
+        let (_, min) = counts.iter().min_by_key(|(_c, v)| *v).unwrap();
 
-    let counts = count(template, &rules, 40);
+        max - min
+    }
 
-    let (_, max) = counts.iter().max_by_key(|(_c, v)| *v).unwrap();
-    let (_, min) = counts.iter().min_by_key(|(_c, v)| *v).unwrap();
+    fn part2((template, rules): Self::Input<'_>) -> Self::Output2 {
+        let counts = count(template, &rules, 40);
 
-    max - min
+        let (_, max) = counts.iter().max_by_key(|(_c, v)| *v).unwrap();
+        let (_, min) = counts.iter().min_by_key(|(_c, v)| *v).unwrap();
+
+        max - min
+    }
 }
@@ -1,3 +1,5 @@
+use util::Solution;
+
 const DATA: &str = "\
 NNCB
 
@@ -20,21 +22,27 @@ CN -> C
 ";
 
 #[test]
-fn day14p01_sample() {
-    assert_eq!(day14::part1(DATA.to_string()), 1588)
+fn example_1() {
+    assert_eq!(day14::Day::part1(day14::Day::parse(DATA)), 1588)
 }
 
 #[test]
-fn day14p01() {
-    assert_eq!(day14::part1(util::read_input("../..", 14)), 2797)
+fn input_1() {
+    assert_eq!(
+        day14::Day::part1(day14::Day::parse(&util::test_input("../..", 14))),
+        2797
+    )
 }
 
 #[test]
-fn day14p02_sample() {
-    assert_eq!(day14::part2(DATA.to_string()), 2188189693529)
+fn example_2() {
+    assert_eq!(day14::Day::part2(day14::Day::parse(DATA)), 2188189693529)
 }
 
 #[test]
-fn day14p02() {
-    assert_eq!(day14::part2(util::read_input("../..", 14)), 2926813379532)
+fn input_2() {
+    assert_eq!(
+        day14::Day::part2(day14::Day::parse(&util::test_input("../..", 14))),
+        2926813379532
+    )
 }
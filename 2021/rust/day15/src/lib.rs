@@ -3,6 +3,7 @@ use std::collections::hash_map::Iter;
 use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::fmt::Debug;
+use util::Solution;
 
 use std::collections::BinaryHeap;
 
@@ -18,10 +19,10 @@ enum Direction {
 }
 
 #[derive(Clone, Eq, PartialEq)]
-struct Map(HashMap<Pos, u32>);
+pub struct Map(HashMap<Pos, u32>);
 
 impl Map {
-    pub fn new() -> Self {
+    fn new() -> Self {
         Self(HashMap::new())
     }
 
@@ -174,7 +175,7 @@ fn shortest_path(map: &Map, start: Pos, goal: Pos) -> Option<u32> {
     None
 }
 
-fn parse_map(data: String) -> Map {
+fn parse_map(data: &str) -> Map {
     data.lines().enumerate().fold(Map::new(), |acc, (y, line)| {
         line.chars().enumerate().fold(acc, |mut acc, (x, c)| {
             let v = c.to_digit(10).unwrap();
@@ -191,31 +192,41 @@ fn parse_map(data: String) -> Map {
     })
 }
 
-pub fn part1(data: String) -> u32 {
-    let map = parse_map(data);
-    let goal = Pos {
-        x: map.max_x(),
-        y: map.max_y(),
-    };
+pub struct Day;
 
-    let result = shortest_path(&map, Pos { x: 0, y: 0 }, goal);
+impl Solution for Day {
+    type Input<'a> = Map;
+    type Output1 = u32;
+    type Output2 = u32;
 
-    result.unwrap()
-}
+    fn parse(data: &str) -> Self::Input<'_> {
+        parse_map(data)
+    }
 
-pub fn part2(data: String) -> u32 {
-    let mut map = parse_map(data);
-    map.expand(Direction::X, 5);
-    map.expand(Direction::Y, 5);
+    fn part1(map: Self::Input<'_>) -> Self::Output1 {
+        let goal = Pos {
+            x: map.max_x(),
+            y: map.max_y(),
+        };
 
-    let goal = Pos {
-        x: map.max_x(),
-        y: map.max_y(),
-    };
+        let result = shortest_path(&map, Pos { x: 0, y: 0 }, goal);
+
+        result.unwrap()
+    }
 
-    let result = shortest_path(&map, Pos { x: 0, y: 0 }, goal);
+    fn part2(mut map: Self::Input<'_>) -> Self::Output2 {
+        map.expand(Direction::X, 5);
+        map.expand(Direction::Y, 5);
 
-    result.unwrap()
+        let goal = Pos {
+            x: map.max_x(),
+            y: map.max_y(),
+        };
+
+        let result = shortest_path(&map, Pos { x: 0, y: 0 }, goal);
+
+        result.unwrap()
+    }
 }
 
 #[cfg(test)]
@@ -224,8 +235,8 @@ mod tests {
 
     #[test]
     fn test_expand_x_1() {
-        let mut map = parse_map("123456789".to_string());
-        let new_map = parse_map("123456789".to_string());
+        let mut map = parse_map("123456789");
+        let new_map = parse_map("123456789");
         map.expand(Direction::X, 1);
 
         assert_eq!(map, new_map);
@@ -233,8 +244,8 @@ mod tests {
 
     #[test]
     fn test_expand_x_2() {
-        let mut map = parse_map("123456789".to_string());
-        let new_map = parse_map("123456789234567891".to_string());
+        let mut map = parse_map("123456789");
+        let new_map = parse_map("123456789234567891");
         map.expand(Direction::X, 2);
 
         assert_eq!(map, new_map);
@@ -242,14 +253,14 @@ mod tests {
 
     #[test]
     fn test_expand_y() {
-        let mut map = parse_map("123456789".to_string());
-        let new_map = parse_map("123456789".to_string());
+        let mut map = parse_map("123456789");
+        let new_map = parse_map("123456789");
         map.expand(Direction::Y, 1);
 
         assert_eq!(map, new_map);
 
-        let mut map = parse_map("123456789".to_string());
-        let new_map = parse_map("123456789\n234567891".to_string());
+        let mut map = parse_map("123456789");
+        let new_map = parse_map("123456789\n234567891");
         map.expand(Direction::Y, 2);
 
         assert_eq!(map, new_map);
@@ -257,8 +268,8 @@ mod tests {
 
     #[test]
     fn test_expand_x_y() {
-        let mut map = parse_map("123".to_string());
-        let new_map = parse_map("123234345\n234345456\n345456567".to_string());
+        let mut map = parse_map("123");
+        let new_map = parse_map("123234345\n234345456\n345456567");
         map.expand(Direction::X, 3);
         map.expand(Direction::Y, 3);
 
@@ -1,3 +1,5 @@
+use util::Solution;
+
 const DATA: &str = "\
 1163751742
 1381373672
@@ -12,21 +14,27 @@ const DATA: &str = "\
 ";
 
 #[test]
-fn day15p01_sample() {
-    assert_eq!(day15::part1(DATA.to_string()), 40)
+fn example_1() {
+    assert_eq!(day15::Day::part1(day15::Day::parse(DATA)), 40)
 }
 
 #[test]
-fn day15p01() {
-    assert_eq!(day15::part1(util::read_input("../..", 15)), 673)
+fn input_1() {
+    assert_eq!(
+        day15::Day::part1(day15::Day::parse(&util::test_input("../..", 15))),
+        673
+    )
 }
 
 #[test]
-fn day15p02_sample() {
-    assert_eq!(day15::part2(DATA.to_string()), 315)
+fn example_2() {
+    assert_eq!(day15::Day::part2(day15::Day::parse(DATA)), 315)
 }
 
 #[test]
-fn day15p02() {
-    assert_eq!(day15::part2(util::read_input("../..", 15)), 2893)
+fn input_2() {
+    assert_eq!(
+        day15::Day::part2(day15::Day::parse(&util::test_input("../..", 15))),
+        2893
+    )
 }
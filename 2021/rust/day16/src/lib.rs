@@ -7,11 +7,12 @@ use nom::{
     sequence::{preceded, tuple},
     IResult,
 };
+use util::Solution;
 use Op::*;
 use Type::*;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
-struct Packet(i64, Type<i64>);
+pub struct Packet(i64, Type<i64>);
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 enum Op {
@@ -99,17 +100,32 @@ fn parse(data: &[u8]) -> Packet {
     packet((data, 0)).unwrap().1
 }
 
-fn decode(data: String) -> Vec<u8> {
+fn decode(data: &str) -> Vec<u8> {
     data.chars()
         .tuples()
         .map(|(a, b)| u8::from_str_radix(&[a, b].iter().join(""), 16).unwrap())
         .collect()
 }
 
-pub fn part1(data: String) -> i64 {
-    let decoded = decode(data);
-    let parsed = parse(&decoded);
-    sum_versions(&parsed)
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Packet;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        let decoded = decode(data);
+        parse(&decoded)
+    }
+
+    fn part1(parsed: Self::Input<'_>) -> Self::Output1 {
+        sum_versions(&parsed)
+    }
+
+    fn part2(parsed: Self::Input<'_>) -> Self::Output2 {
+        apply(&parsed)
+    }
 }
 
 fn sum_versions(Packet(v, t): &Packet) -> i64 {
@@ -159,33 +175,27 @@ fn apply(Packet(_v, t): &Packet) -> i64 {
     }
 }
 
-pub fn part2(data: String) -> i64 {
-    let decoded = decode(data);
-    let parsed = parse(&decoded);
-    apply(&parsed)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_decode() {
-        let data = "D2FE28".to_string();
+        let data = "D2FE28";
 
         assert_eq!(decode(data), vec![210, 254, 40]);
     }
 
     #[test]
     fn test_parse_1() {
-        let data = decode("D2FE28".to_string());
+        let data = decode("D2FE28");
 
         assert_eq!(Packet(6, Literal(2021)), parse(&data))
     }
 
     #[test]
     fn test_parse_2() {
-        let data = decode("38006F45291200".to_string());
+        let data = decode("38006F45291200");
 
         assert_eq!(
             Packet(
@@ -198,7 +208,7 @@ mod tests {
 
     #[test]
     fn test_parse_3() {
-        let data = decode("EE00D40C823060".to_string());
+        let data = decode("EE00D40C823060");
 
         assert_eq!(
             Packet(
@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use util::Solution;
 
 use nom::{
     bytes::complete::tag,
@@ -19,7 +20,7 @@ impl Point {
 }
 
 #[derive(Debug)]
-struct Target {
+pub struct Target {
     min_x: i32,
     max_x: i32,
     min_y: i32,
@@ -28,7 +29,7 @@ struct Target {
 }
 
 impl Target {
-    pub fn new() -> Self {
+    fn new() -> Self {
         Self {
             min_x: i32::MAX,
             max_x: i32::MIN,
@@ -57,11 +58,11 @@ fn range(s: &str) -> IResult<&str, (i32, i32)> {
     separated_pair(parser::signed_dig, tag(".."), parser::signed_dig)(s)
 }
 
-fn parse_input(data: String) -> Target {
+fn parse_input(data: &str) -> Target {
     let (_, ((x1, x2), (y1, y2))) = preceded(
         tag("target area: x="),
         tuple((terminated(range, tag(", ")), preceded(tag("y="), range))),
-    )(&data)
+    )(data)
     .unwrap();
 
     let mut target = Target::new();
@@ -87,69 +88,77 @@ fn step(x: &mut i32, y: &mut i32, xvel: &mut i32, yvel: &mut i32) -> () {
     };
 }
 
-pub fn part1(data: String) -> i32 {
-    let target = parse_input(data);
+pub struct Day;
 
-    let min_xvel = ((target.min_x * 2) as f64).sqrt() as i32;
-    let max_xvel = target.max_x;
+impl Solution for Day {
+    type Input<'a> = Target;
+    type Output1 = i32;
+    type Output2 = usize;
 
-    let min_yvel = target.min_y;
-    let max_yvel = target.min_y.abs() - 1;
+    fn parse(data: &str) -> Self::Input<'_> {
+        parse_input(data)
+    }
+
+    fn part1(target: Self::Input<'_>) -> Self::Output1 {
+        let min_xvel = ((target.min_x * 2) as f64).sqrt() as i32;
+        let max_xvel = target.max_x;
+
+        let min_yvel = target.min_y;
+        let max_yvel = target.min_y.abs() - 1;
 
-    let mut highest_pos = i32::MIN;
-    for xvel in min_xvel..=max_xvel {
-        for yvel in min_yvel..=max_yvel {
-            let mut local_xvel = xvel;
-            let mut local_yvel = yvel;
-            let mut x = 0;
-            let mut y = 0;
+        let mut highest_pos = i32::MIN;
+        for xvel in min_xvel..=max_xvel {
+            for yvel in min_yvel..=max_yvel {
+                let mut local_xvel = xvel;
+                let mut local_yvel = yvel;
+                let mut x = 0;
+                let mut y = 0;
 
-            let mut local_highest_pos = i32::MIN;
+                let mut local_highest_pos = i32::MIN;
 
-            while x <= target.max_x && y >= target.min_y {
-                local_highest_pos = local_highest_pos.max(y);
+                while x <= target.max_x && y >= target.min_y {
+                    local_highest_pos = local_highest_pos.max(y);
 
-                if target.contains(Point::new(x, y)) {
-                    highest_pos = highest_pos.max(local_highest_pos);
-                };
+                    if target.contains(Point::new(x, y)) {
+                        highest_pos = highest_pos.max(local_highest_pos);
+                    };
 
-                step(&mut x, &mut y, &mut local_xvel, &mut local_yvel);
+                    step(&mut x, &mut y, &mut local_xvel, &mut local_yvel);
+                }
             }
         }
-    }
 
-    highest_pos
-}
-
-pub fn part2(data: String) -> usize {
-    let target = parse_input(data);
+        highest_pos
+    }
 
-    let mut target_hit: HashSet<(i32, i32)> = HashSet::new();
+    fn part2(target: Self::Input<'_>) -> Self::Output2 {
+        let mut target_hit: HashSet<(i32, i32)> = HashSet::new();
 
-    let min_xvel = ((target.min_x * 2) as f64).sqrt() as i32;
-    let max_xvel = target.max_x;
+        let min_xvel = ((target.min_x * 2) as f64).sqrt() as i32;
+        let max_xvel = target.max_x;
 
-    let min_yvel = target.min_y;
-    let max_yvel = target.min_y.abs() - 1;
+        let min_yvel = target.min_y;
+        let max_yvel = target.min_y.abs() - 1;
 
-    for xvel in min_xvel..=max_xvel {
-        for yvel in min_yvel..=max_yvel {
-            let mut local_xvel = xvel;
-            let mut local_yvel = yvel;
-            let mut x = 0;
-            let mut y = 0;
+        for xvel in min_xvel..=max_xvel {
+            for yvel in min_yvel..=max_yvel {
+                let mut local_xvel = xvel;
+                let mut local_yvel = yvel;
+                let mut x = 0;
+                let mut y = 0;
 
-            while x <= target.max_x && y >= target.min_y {
-                if target.contains(Point::new(x, y)) {
-                    target_hit.insert((xvel, yvel));
-                };
+                while x <= target.max_x && y >= target.min_y {
+                    if target.contains(Point::new(x, y)) {
+                        target_hit.insert((xvel, yvel));
+                    };
 
-                step(&mut x, &mut y, &mut local_xvel, &mut local_yvel);
+                    step(&mut x, &mut y, &mut local_xvel, &mut local_yvel);
+                }
             }
         }
-    }
 
-    target_hit.len()
+        target_hit.len()
+    }
 }
 
 #[cfg(test)]
@@ -158,7 +167,7 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        let data = "target area: x=1..3, y=-1..1".to_string();
+        let data = "target area: x=1..3, y=-1..1";
 
         let mut val = HashSet::new();
         val.insert(Point::new(1, -1));
@@ -1,23 +1,31 @@
+use util::Solution;
+
 const DATA: &str = "\
 target area: x=20..30, y=-10..-5
 ";
 
 #[test]
-fn day17p01_sample() {
-    assert_eq!(day17::part1(DATA.to_string()), 45)
+fn example_1() {
+    assert_eq!(day17::Day::part1(day17::Day::parse(DATA)), 45)
 }
 
 #[test]
-fn day17p01() {
-    assert_eq!(day17::part1(util::read_input("../..", 17)), 9870)
+fn input_1() {
+    assert_eq!(
+        day17::Day::part1(day17::Day::parse(&util::test_input("../..", 17))),
+        9870
+    )
 }
 
 #[test]
-fn day17p02_sample() {
-    assert_eq!(day17::part2(DATA.to_string()), 112)
+fn example_2() {
+    assert_eq!(day17::Day::part2(day17::Day::parse(DATA)), 112)
 }
 
 #[test]
-fn day17p02() {
-    assert_eq!(day17::part2(util::read_input("../..", 17)), 5523)
+fn input_2() {
+    assert_eq!(
+        day17::Day::part2(day17::Day::parse(&util::test_input("../..", 17))),
+        5523
+    )
 }
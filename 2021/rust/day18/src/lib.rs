@@ -1,5 +1,6 @@
 use std::fmt;
 use std::fmt::Debug;
+use util::Solution;
 
 use nom::{
     branch::alt,
@@ -9,7 +10,7 @@ use nom::{
 };
 
 #[derive(Eq, PartialEq, Clone)]
-struct Pair {
+pub struct Pair {
     l: Node,
     r: Node,
 }
@@ -27,7 +28,7 @@ impl Debug for Pair {
 }
 
 #[derive(Eq, PartialEq, Clone)]
-struct Value {
+pub struct Value {
     value: i32,
 }
 
@@ -44,7 +45,7 @@ impl Debug for Value {
 }
 
 #[derive(Eq, PartialEq, Clone)]
-enum Node {
+pub enum Node {
     Pair(Box<Pair>),
     Value(Value),
 }
@@ -172,41 +173,48 @@ fn pair(s: &str) -> IResult<&str, Node> {
     Ok((rest, Node::pair(a, b)))
 }
 
-fn parse(data: String) -> Node {
-    let (_rest, snums) = pair(&data).unwrap();
+fn parse(data: &str) -> Node {
+    let (_rest, snums) = pair(data).unwrap();
     snums
 }
 
-pub fn part1(data: String) -> i32 {
-    let added = data
-        .lines()
-        .map(|line| parse(line.to_string()))
-        .reduce(|acc, line| acc + line);
+pub struct Day;
 
-    added.unwrap().magnitude()
-}
+impl Solution for Day {
+    type Input<'a> = Vec<Node>;
+    type Output1 = i32;
+    type Output2 = i32;
 
-pub fn part2(data: String) -> i32 {
-    let snums: Vec<Node> = data.lines().map(|line| parse(line.to_string())).collect();
+    fn parse(data: &str) -> Self::Input<'_> {
+        data.lines().map(parse).collect()
+    }
 
-    let mut max: i32 = 0;
+    fn part1(snums: Self::Input<'_>) -> Self::Output1 {
+        let added = snums.into_iter().reduce(|acc, line| acc + line);
 
-    for i in 0..snums.len() {
-        for j in 0..snums.len() {
-            if i == j {
-                continue;
-            }
+        added.unwrap().magnitude()
+    }
+
+    fn part2(snums: Self::Input<'_>) -> Self::Output2 {
+        let mut max: i32 = 0;
 
-            let a = &snums[i];
-            let b = &snums[j];
+        for i in 0..snums.len() {
+            for j in 0..snums.len() {
+                if i == j {
+                    continue;
+                }
 
-            max = max
-                .max((a.clone() + b.clone()).magnitude())
-                .max((b.clone() + a.clone()).magnitude());
+                let a = &snums[i];
+                let b = &snums[j];
+
+                max = max
+                    .max((a.clone() + b.clone()).magnitude())
+                    .max((b.clone() + a.clone()).magnitude());
+            }
         }
-    }
 
-    max
+        max
+    }
 }
 
 #[cfg(test)]
@@ -215,7 +223,7 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        let data = "[[[[1,2],[3,4]],[[5,6],[7,8]]],9]".to_string();
+        let data = "[[[[1,2],[3,4]],[[5,6],[7,8]]],9]";
 
         assert_eq!(
             parse(data),
@@ -237,56 +245,53 @@ mod tests {
 
     #[test]
     fn test_reduce() {
-        let a = "[[[[4,3],4],4],[7,[[8,4],9]]]".to_string();
-        let b = "[1,1]".to_string();
+        let a = "[[[[4,3],4],4],[7,[[8,4],9]]]";
+        let b = "[1,1]";
 
         let snumsa = parse(a);
         let snumsb = parse(b);
 
-        assert_eq!(
-            snumsa + snumsb,
-            parse("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]".to_string())
-        )
+        assert_eq!(snumsa + snumsb, parse("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"))
     }
 
     #[test]
     fn test_magnitude_1() {
-        let data = "[[1,2],[[3,4],5]]".to_string();
+        let data = "[[1,2],[[3,4],5]]";
 
         assert_eq!(parse(data).magnitude(), 143)
     }
 
     #[test]
     fn test_magnitude_2() {
-        let data = "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]".to_string();
+        let data = "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]";
 
         assert_eq!(parse(data).magnitude(), 1384)
     }
 
     #[test]
     fn test_magnitude_3() {
-        let data = "[[[[1,1],[2,2]],[3,3]],[4,4]]".to_string();
+        let data = "[[[[1,1],[2,2]],[3,3]],[4,4]]";
 
         assert_eq!(parse(data).magnitude(), 445)
     }
 
     #[test]
     fn test_magnitude_4() {
-        let data = "[[[[3,0],[5,3]],[4,4]],[5,5]]".to_string();
+        let data = "[[[[3,0],[5,3]],[4,4]],[5,5]]";
 
         assert_eq!(parse(data).magnitude(), 791)
     }
 
     #[test]
     fn test_magnitude_5() {
-        let data = "[[[[5,0],[7,4]],[5,5]],[6,6]]".to_string();
+        let data = "[[[[5,0],[7,4]],[5,5]],[6,6]]";
 
         assert_eq!(parse(data).magnitude(), 1137)
     }
 
     #[test]
     fn test_magnitude_6() {
-        let data = "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]".to_string();
+        let data = "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]";
 
         assert_eq!(parse(data).magnitude(), 3488)
     }
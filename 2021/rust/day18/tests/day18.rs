@@ -1,3 +1,5 @@
+use util::Solution;
+
 const DATA: &str = "\
 [[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
 [[[5,[2,8]],4],[5,[[9,9],0]]]
@@ -12,21 +14,27 @@ const DATA: &str = "\
 ";
 
 #[test]
-fn day18p01_sample() {
-    assert_eq!(day18::part1(DATA.to_string()), 4140)
+fn example_1() {
+    assert_eq!(day18::Day::part1(day18::Day::parse(DATA)), 4140)
 }
 
 #[test]
-fn day18p01() {
-    assert_eq!(day18::part1(util::read_input("../..", 18)), 3411)
+fn input_1() {
+    assert_eq!(
+        day18::Day::part1(day18::Day::parse(&util::test_input("../..", 18))),
+        3411
+    )
 }
 
 #[test]
-fn day18p02_sample() {
-    assert_eq!(day18::part2(DATA.to_string()), 3993)
+fn example_2() {
+    assert_eq!(day18::Day::part2(day18::Day::parse(DATA)), 3993)
 }
 
 #[test]
-fn day18p02() {
-    assert_eq!(day18::part2(util::read_input("../..", 18)), 4680)
+fn input_2() {
+    assert_eq!(
+        day18::Day::part2(day18::Day::parse(&util::test_input("../..", 18))),
+        4680
+    )
 }
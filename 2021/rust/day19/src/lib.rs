@@ -1,6 +1,7 @@
 use std::collections::hash_set::Iter;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use util::Solution;
 
 use itertools::Itertools;
 
@@ -13,7 +14,7 @@ use nom::{
 };
 
 #[derive(Debug, Eq, PartialEq, Hash, Clone)]
-struct Beacon {
+pub struct Beacon {
     x: i32,
     y: i32,
     z: i32,
@@ -60,10 +61,10 @@ impl Offset {
 }
 
 #[derive(Debug, Eq, PartialEq, Clone)]
-struct Report(HashSet<Beacon>);
+pub struct Report(HashSet<Beacon>);
 
 impl Report {
-    pub fn new() -> Self {
+    fn new() -> Self {
         Report(HashSet::new())
     }
 
@@ -188,7 +189,7 @@ impl<'a> Iterator for ReportRotator<'a> {
     }
 }
 
-fn parse(data: String) -> Vec<Report> {
+fn parse(data: &str) -> Vec<Report> {
     let (_rest, scanners) = many1(terminated(
         preceded(
             tuple((tag("--- scanner "), digit1, tag(" ---"), line_ending)),
@@ -208,14 +209,13 @@ fn parse(data: String) -> Vec<Report> {
             ),
         ),
         many0(line_ending),
-    ))(&data)
+    ))(data)
     .unwrap();
 
     scanners
 }
 
-fn combine_scanners(data: String) -> (Report, Vec<Offset>) {
-    let mut reports = parse(data);
+fn combine_scanners(mut reports: Vec<Report>) -> (Report, Vec<Offset>) {
     let mut combined_report = reports.pop().unwrap();
     let mut offsets: Vec<Offset> = Vec::new();
 
@@ -232,21 +232,33 @@ fn combine_scanners(data: String) -> (Report, Vec<Offset>) {
     (combined_report, offsets)
 }
 
-pub fn part1(data: String) -> usize {
-    let (combined_report, _) = combine_scanners(data);
+pub struct Day;
 
-    combined_report.0.len()
-}
+impl Solution for Day {
+    type Input<'a> = Vec<Report>;
+    type Output1 = usize;
+    type Output2 = i32;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        parse(data)
+    }
+
+    fn part1(reports: Self::Input<'_>) -> Self::Output1 {
+        let (combined_report, _) = combine_scanners(reports);
 
-pub fn part2(data: String) -> i32 {
-    let (_, offsets) = combine_scanners(data);
+        combined_report.0.len()
+    }
+
+    fn part2(reports: Self::Input<'_>) -> Self::Output2 {
+        let (_, offsets) = combine_scanners(reports);
 
-    offsets
-        .iter()
-        .cartesian_product(offsets.iter())
-        .map(|(a, b)| (a.dx - b.dx).abs() + (a.dy - b.dy).abs() + (a.dz - b.dz).abs())
-        .max()
-        .unwrap()
+        offsets
+            .iter()
+            .cartesian_product(offsets.iter())
+            .map(|(a, b)| (a.dx - b.dx).abs() + (a.dy - b.dy).abs() + (a.dz - b.dz).abs())
+            .max()
+            .unwrap()
+    }
 }
 
 #[cfg(test)]
@@ -264,8 +276,7 @@ mod tests {
 --- scanner 1 ---
 -1,-1,-1
 -5,0,4
--2,1,1"
-            .to_string();
+-2,1,1";
 
         assert_eq!(
             parse(data),
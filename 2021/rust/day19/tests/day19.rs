@@ -1,3 +1,5 @@
+use util::Solution;
+
 const DATA: &str = "\
 --- scanner 0 ---
 404,-588,-901
@@ -138,21 +140,27 @@ const DATA: &str = "\
 ";
 
 #[test]
-fn day19p01_sample() {
-    assert_eq!(day19::part1(DATA.to_string()), 79)
+fn example_1() {
+    assert_eq!(day19::Day::part1(day19::Day::parse(DATA)), 79)
 }
 
 #[test]
-fn day19p01() {
-    assert_eq!(day19::part1(util::read_input("../..", 19)), 451)
+fn input_1() {
+    assert_eq!(
+        day19::Day::part1(day19::Day::parse(&util::test_input("../..", 19))),
+        451
+    )
 }
 
 #[test]
-fn day19p02_sample() {
-    assert_eq!(day19::part2(DATA.to_string()), 3621)
+fn example_2() {
+    assert_eq!(day19::Day::part2(day19::Day::parse(DATA)), 3621)
 }
 
 #[test]
-fn day19p02() {
-    assert_eq!(day19::part2(util::read_input("../..", 19)), 13184)
+fn input_2() {
+    assert_eq!(
+        day19::Day::part2(day19::Day::parse(&util::test_input("../..", 19))),
+        13184
+    )
 }
@@ -3,6 +3,7 @@ use std::{
     fmt,
     fmt::Debug,
 };
+use util::Solution;
 
 use nom::{
     branch::alt,
@@ -32,7 +33,7 @@ impl Debug for Point {
     }
 }
 
-struct Image {
+pub struct Image {
     data: HashMap<Point, bool>,
     inverted: bool,
     min_x: i32,
@@ -42,7 +43,7 @@ struct Image {
 }
 
 impl Image {
-    pub fn new() -> Self {
+    fn new() -> Self {
         Self {
             data: HashMap::new(),
             inverted: false,
@@ -143,14 +144,14 @@ fn pixel(s: &str) -> IResult<&str, bool> {
     })(s)
 }
 
-fn parse(data: String) -> (Vec<bool>, Image) {
+fn parse(data: &str) -> (Vec<bool>, Image) {
     let (_rest, (algo, raw_input)) = tuple((
         terminated(many1(pixel), line_ending),
         preceded(
             many1(line_ending),
             separated_list1(line_ending, many1(pixel)),
         ),
-    ))(&data)
+    ))(data)
     .unwrap();
 
     let mut input = Image::new();
@@ -177,20 +178,28 @@ fn bits_to_number(b: Vec<bool>) -> usize {
     u
 }
 
-pub fn part1(data: String) -> usize {
-    let (algo, input) = parse(data);
+pub struct Day;
 
-    let e = input.enhance_n(&algo, 2);
+impl Solution for Day {
+    type Input<'a> = (Vec<bool>, Image);
+    type Output1 = usize;
+    type Output2 = usize;
 
-    e.iter().filter(|(_k, v)| **v).count()
-}
+    fn parse(data: &str) -> Self::Input<'_> {
+        parse(data)
+    }
 
-pub fn part2(data: String) -> usize {
-    let (algo, input) = parse(data);
+    fn part1((algo, input): Self::Input<'_>) -> Self::Output1 {
+        let e = input.enhance_n(&algo, 2);
 
-    let e = input.enhance_n(&algo, 50);
+        e.iter().filter(|(_k, v)| **v).count()
+    }
 
-    e.iter().filter(|(_k, v)| **v).count()
+    fn part2((algo, input): Self::Input<'_>) -> Self::Output2 {
+        let e = input.enhance_n(&algo, 50);
+
+        e.iter().filter(|(_k, v)| **v).count()
+    }
 }
 
 #[cfg(test)]
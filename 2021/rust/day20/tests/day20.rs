@@ -1,3 +1,5 @@
+use util::Solution;
+
 const DATA: &str = "\
 ..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
 
@@ -9,21 +11,27 @@ const DATA: &str = "\
 ";
 
 #[test]
-fn day20p01_sample() {
-    assert_eq!(day20::part1(DATA.to_string()), 35)
+fn example_1() {
+    assert_eq!(day20::Day::part1(day20::Day::parse(DATA)), 35)
 }
 
 #[test]
-fn day20p01() {
-    assert_eq!(day20::part1(util::read_input("../..", 20)), 5663)
+fn input_1() {
+    assert_eq!(
+        day20::Day::part1(day20::Day::parse(&util::test_input("../..", 20))),
+        5663
+    )
 }
 
 #[test]
-fn day20p02_sample() {
-    assert_eq!(day20::part2(DATA.to_string()), 3351)
+fn example_2() {
+    assert_eq!(day20::Day::part2(day20::Day::parse(DATA)), 3351)
 }
 
 #[test]
-fn day20p02() {
-    assert_eq!(day20::part2(util::read_input("../..", 20)), 19638)
+fn input_2() {
+    assert_eq!(
+        day20::Day::part2(day20::Day::parse(&util::test_input("../..", 20))),
+        19638
+    )
 }
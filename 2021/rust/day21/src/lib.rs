@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use util::Solution;
 
 use nom::{
     bytes::complete::tag,
@@ -7,7 +8,7 @@ use nom::{
     sequence::{preceded, separated_pair},
 };
 
-fn parse(data: String) -> Vec<(i32, i32, i32)> {
+fn parse(data: &str) -> Vec<(i32, i32, i32)> {
     let (_rest, players) = separated_list1(
         line_ending,
         preceded(
@@ -18,35 +19,82 @@ fn parse(data: String) -> Vec<(i32, i32, i32)> {
                 parser::from_dig,
             ),
         ),
-    )(&data)
+    )(data)
     .unwrap();
 
     players.iter().map(|(pl, p)| (*pl, *p, 0)).collect()
 }
 
-pub fn part1(data: String) -> i32 {
-    let mut players = parse(data);
-    let mut die = (1..=100).cycle();
-    let (mut player, mut rolls, mut losing_score) = (0, 0, 0);
+pub struct Day;
 
-    while let Some((_pl, p, score)) = players.get_mut(player) {
-        let roll: i32 = (0..3).map(|_| die.next().unwrap()).sum();
+impl Solution for Day {
+    type Input<'a> = Vec<(i32, i32, i32)>;
+    type Output1 = i32;
+    type Output2 = i64;
 
-        rolls += 3;
+    fn parse(data: &str) -> Self::Input<'_> {
+        parse(data)
+    }
+
+    fn part1(mut players: Self::Input<'_>) -> Self::Output1 {
+        let mut die = (1..=100).cycle();
+        let (mut player, mut rolls, mut losing_score) = (0, 0, 0);
+
+        while let Some((_pl, p, score)) = players.get_mut(player) {
+            let roll: i32 = (0..3).map(|_| die.next().unwrap()).sum();
 
-        *p = 1 + (*p + roll - 1) % 10;
+            rolls += 3;
 
-        *score += *p;
+            *p = 1 + (*p + roll - 1) % 10;
 
-        if *score >= 1000 {
-            break;
+            *score += *p;
+
+            if *score >= 1000 {
+                break;
+            }
+            losing_score = *score;
+
+            player = if player == 0 { 1 } else { 0 };
         }
-        losing_score = *score;
 
-        player = if player == 0 { 1 } else { 0 };
+        losing_score * rolls
     }
 
-    losing_score * rolls
+    fn part2(players: Self::Input<'_>) -> Self::Output2 {
+        let (_, p1, _) = players[0];
+        let (_, p2, _) = players[1];
+
+        let mut p1_wins = HashMap::new();
+        let mut p2_wins = HashMap::new();
+
+        game(p1, &mut p1_wins, 0, 0, 1);
+        game(p2, &mut p2_wins, 0, 0, 1);
+
+        let max_p1_round = p1_wins.keys().max().unwrap();
+        let max_p2_round = p2_wins.keys().max().unwrap();
+
+        let mut p1_score: i64 = 0;
+        let mut p2_score: i64 = 0;
+
+        for i in 0..=*max_p1_round {
+            if let Some((p1_wins, _p1_losses)) = p1_wins.get(&i) {
+                if let Some((_p2_wins, p2_losses)) = i.checked_sub(1).and_then(|j| p2_wins.get(&j))
+                {
+                    p1_score += p1_wins * p2_losses
+                }
+            }
+        }
+
+        for i in 0..=*max_p2_round {
+            if let Some((p2_wins, _p2_losses)) = p2_wins.get(&i) {
+                if let Some((_p1_wins, p1_losses)) = p1_wins.get(&i) {
+                    p2_score += p2_wins * p1_losses
+                }
+            }
+        }
+
+        p1_score.max(p2_score)
+    }
 }
 
 const FREQUENCIES: [(u8, u8); 7] = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
@@ -82,42 +130,6 @@ fn game(
     }
 }
 
-pub fn part2(data: String) -> i64 {
-    let players = parse(data);
-    let (_, p1, _) = players[0];
-    let (_, p2, _) = players[1];
-
-    let mut p1_wins = HashMap::new();
-    let mut p2_wins = HashMap::new();
-
-    game(p1, &mut p1_wins, 0, 0, 1);
-    game(p2, &mut p2_wins, 0, 0, 1);
-
-    let max_p1_round = p1_wins.keys().max().unwrap();
-    let max_p2_round = p2_wins.keys().max().unwrap();
-
-    let mut p1_score: i64 = 0;
-    let mut p2_score: i64 = 0;
-
-    for i in 0..=*max_p1_round {
-        if let Some((p1_wins, _p1_losses)) = p1_wins.get(&i) {
-            if let Some((_p2_wins, p2_losses)) = i.checked_sub(1).and_then(|j| p2_wins.get(&j)) {
-                p1_score += p1_wins * p2_losses
-            }
-        }
-    }
-
-    for i in 0..=*max_p2_round {
-        if let Some((p2_wins, _p2_losses)) = p2_wins.get(&i) {
-            if let Some((_p1_wins, p1_losses)) = p1_wins.get(&i) {
-                p2_score += p2_wins * p1_losses
-            }
-        }
-    }
-
-    p1_score.max(p2_score)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -126,8 +138,7 @@ mod tests {
     fn test_parse() {
         let data = "\
 Player 1 starting position: 4
-Player 2 starting position: 8"
-            .to_string();
+Player 2 starting position: 8";
 
         assert_eq!(parse(data), vec![(1, 4, 0), (2, 8, 0)])
     }
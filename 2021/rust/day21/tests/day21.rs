@@ -1,24 +1,32 @@
+use util::Solution;
+
 const DATA: &str = "\
 Player 1 starting position: 4
 Player 2 starting position: 8
 ";
 
 #[test]
-fn day21p01_sample() {
-    assert_eq!(day21::part1(DATA.to_string()), 739785)
+fn example_1() {
+    assert_eq!(day21::Day::part1(day21::Day::parse(DATA)), 739785)
 }
 
 #[test]
-fn day21p01() {
-    assert_eq!(day21::part1(util::read_input("../..", 21)), 734820)
+fn input_1() {
+    assert_eq!(
+        day21::Day::part1(day21::Day::parse(&util::test_input("../..", 21))),
+        734820
+    )
 }
 
 #[test]
-fn day21p02_sample() {
-    assert_eq!(day21::part2(DATA.to_string()), 444356092776315)
+fn example_2() {
+    assert_eq!(day21::Day::part2(day21::Day::parse(DATA)), 444356092776315)
 }
 
 #[test]
-fn day21p02() {
-    assert_eq!(day21::part2(util::read_input("../..", 21)), 193170338541590)
+fn input_2() {
+    assert_eq!(
+        day21::Day::part2(day21::Day::parse(&util::test_input("../..", 21))),
+        193170338541590
+    )
 }
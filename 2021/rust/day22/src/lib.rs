@@ -1,4 +1,5 @@
 use std::{ops::RangeInclusive, slice::Iter};
+use util::Solution;
 
 use nom::{
     branch::alt,
@@ -10,7 +11,7 @@ use nom::{
 };
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-struct Cube {
+pub struct Cube {
     x: RangeInclusive<i32>,
     y: RangeInclusive<i32>,
     z: RangeInclusive<i32>,
@@ -102,7 +103,7 @@ fn range(s: &str) -> IResult<&str, RangeInclusive<i32>> {
     Ok((rest, RangeInclusive::new(start, end)))
 }
 
-fn parse(data: String) -> Vec<Cube> {
+fn parse(data: &str) -> Vec<Cube> {
     let (_rest, ranges) = separated_list1(
         line_ending,
         tuple((
@@ -111,7 +112,7 @@ fn parse(data: String) -> Vec<Cube> {
             preceded(tag(",y="), range),
             preceded(tag(",z="), range),
         )),
-    )(&data)
+    )(data)
     .unwrap();
 
     ranges
@@ -124,36 +125,45 @@ fn parse(data: String) -> Vec<Cube> {
         .collect()
 }
 
-pub fn part1(data: String) -> usize {
-    let cubes = parse(data);
+pub struct Day;
 
-    let mut reactor = Reactor::new();
+impl Solution for Day {
+    type Input<'a> = Vec<Cube>;
+    type Output1 = usize;
+    type Output2 = usize;
 
-    for cube in cubes {
-        if !(-50..=50).contains(cube.x.start())
-            || !(-50..=50).contains(cube.x.end())
-            || !(-50..=50).contains(cube.y.start())
-            || !(-50..=50).contains(cube.y.end())
-            || !(-50..=50).contains(cube.z.start())
-            || !(-50..=50).contains(cube.z.end())
-        {
-            continue;
+    fn parse(data: &str) -> Self::Input<'_> {
+        parse(data)
+    }
+
+    fn part1(cubes: Self::Input<'_>) -> Self::Output1 {
+        let mut reactor = Reactor::new();
+
+        for cube in cubes {
+            if !(-50..=50).contains(cube.x.start())
+                || !(-50..=50).contains(cube.x.end())
+                || !(-50..=50).contains(cube.y.start())
+                || !(-50..=50).contains(cube.y.end())
+                || !(-50..=50).contains(cube.z.start())
+                || !(-50..=50).contains(cube.z.end())
+            {
+                continue;
+            }
+            reactor.add(&cube)
         }
-        reactor.add(&cube)
+
+        reactor.size()
     }
 
-    reactor.size()
-}
-pub fn part2(data: String) -> usize {
-    let cubes = parse(data);
+    fn part2(cubes: Self::Input<'_>) -> Self::Output2 {
+        let mut reactor = Reactor::new();
 
-    let mut reactor = Reactor::new();
+        for cube in cubes {
+            reactor.add(&cube)
+        }
 
-    for cube in cubes {
-        reactor.add(&cube)
+        reactor.size()
     }
-
-    reactor.size()
 }
 
 #[cfg(test)]
@@ -166,8 +176,7 @@ mod tests {
 on x=10..12,y=10..12,z=10..12
 on x=11..13,y=11..13,z=11..13
 off x=9..11,y=9..11,z=9..11
-on x=10..10,y=10..10,z=10..10"
-            .to_string();
+on x=10..10,y=10..10,z=10..10";
 
         assert_eq!(
             parse(data),
@@ -206,9 +215,8 @@ on x=10..10,y=10..10,z=10..10"
 on x=10..12,y=10..12,z=10..12
 on x=11..13,y=11..13,z=11..13
 off x=9..11,y=9..11,z=9..11
-on x=10..10,y=10..10,z=10..10"
-            .to_string();
+on x=10..10,y=10..10,z=10..10";
 
-        assert_eq!(part1(data), 39)
+        assert_eq!(Day::part1(Day::parse(data)), 39)
     }
 }
@@ -1,3 +1,5 @@
+use util::Solution;
+
 const DATA: &str = "\
 on x=-20..26,y=-36..17,z=-47..7
 on x=-20..33,y=-21..23,z=-26..28
@@ -87,24 +89,30 @@ off x=-93533..-4276,y=-16170..68771,z=-104985..-24507
 ";
 
 #[test]
-fn day22p01_sample() {
-    assert_eq!(day22::part1(DATA.to_string()), 590784)
+fn example_1() {
+    assert_eq!(day22::Day::part1(day22::Day::parse(DATA)), 590784)
 }
 
 #[test]
-fn day22p01() {
-    assert_eq!(day22::part1(util::read_input("../..", 22)), 648681)
+fn input_1() {
+    assert_eq!(
+        day22::Day::part1(day22::Day::parse(&util::test_input("../..", 22))),
+        648681
+    )
 }
 
 #[test]
-fn day22p02_sample() {
-    assert_eq!(day22::part2(PART2DATA.to_string()), 2758514936282235)
+fn example_2() {
+    assert_eq!(
+        day22::Day::part2(day22::Day::parse(PART2DATA)),
+        2758514936282235
+    )
 }
 
 #[test]
-fn day22p02() {
+fn input_2() {
     assert_eq!(
-        day22::part2(util::read_input("../..", 22)),
+        day22::Day::part2(day22::Day::parse(&util::test_input("../..", 22))),
         1302784472088899
     )
 }
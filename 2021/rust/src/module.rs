@@ -2,32 +2,32 @@
 macro_rules! generate_main {
     ($($mod_name:ident)*) => {
         use util;
-        use std::time::{Duration, Instant};
-        use num_format::{Locale, ToFormattedString};
-
-        fn measure_time<T, F: Fn() -> T>(func: F) -> (T, Duration) {
-            let start = Instant::now();
-            let res = func();
-            let duration = start.elapsed();
-            (res, duration)
-        }
 
         $(
             use $mod_name;
         )*
 
-        fn main() {
+        // Only counts with --alloc.
+        #[global_allocator]
+        static ALLOC: runner::Counting = runner::Counting;
 
-            $(
-              let day_s = stringify!($mod_name).trim_start_matches("day");
-              let day = usize::from_str_radix(day_s, 10).unwrap();
-
-              let (res, duration) = measure_time(|| $mod_name::part1(util::read_input("..", day)));
-              println!("Day{:0>2}-01 {: >10}μs:\t{}", day, duration.as_micros().to_formatted_string(&Locale::en), res);
+        fn main() {
+            runner::main(2021, &[
+                $(
+                  runner::Day::new(stringify!($mod_name), |ctx| {
+                    // Each day uses only one of the two.
+                    #[allow(unused_imports)]
+                    use runner::{ViaClone, ViaReparse};
+                    use util::Solution;
 
-              let (res, duration) = measure_time(|| $mod_name::part2(util::read_input("..", day)));
-              println!("Day{:0>2}-02 {: >10}μs:\t{}", day, duration.as_micros().to_formatted_string(&Locale::en), res);
-            )*
+                    let Some(input) = ctx.read(|day| util::read_input("..", day as usize)) else { return };
+                    let parse = || $mod_name::Day::parse(&input);
+                    let cloner = (&&runner::Cloner::of(&parse)).cloner();
+                    ctx.solve(parse, cloner, |data| $mod_name::Day::part1(data), |data| $mod_name::Day::part2(data));
+                  })
+                  .with_code(option_env!(concat!("AOC_CODE_", stringify!($mod_name)))),
+                )*
+            ]);
         }
     };
 }
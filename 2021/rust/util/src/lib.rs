@@ -1,31 +1,72 @@
-use cookie_store::*;
-use std::fs;
-use std::fs::File;
-use std::io::Write;
-use ureq::AgentBuilder;
-
-pub fn read_input(prefix: &str, day: usize) -> String {
-    let filename = [prefix, &format!("inputs/day{:0>2}", day)].join("/");
-    let cookiepath = [prefix, "cookie.json"].join("/");
-
-    if !std::path::Path::new(&filename).exists() {
-        let cookie_json = fs::read_to_string(cookiepath).unwrap().replace("\n", "");
-        let cookie_store = CookieStore::load_json(cookie_json.as_bytes()).unwrap();
-
-        let url = format!("https://adventofcode.com/{:0>4}/day/{}/input", 2021, day);
-
-        let body = AgentBuilder::new()
-            .cookie_store(cookie_store)
-            .build()
-            .get(&url)
-            .call()
-            .unwrap()
-            .into_string()
-            .unwrap();
-
-        let mut writer = File::create(&filename).unwrap();
-        write!(writer, "{}", body).unwrap();
-    }
-
-    std::fs::read_to_string(&filename).unwrap()
+use runner::Inputs;
+use std::path::{Path, PathBuf};
+
+pub use runner::InputError;
+pub use runner::Solution;
+
+/// Reads the day's input, downloading it first when it isn't cached yet.
+pub fn read_input(prefix: &str, day: usize) -> Result<String, InputError> {
+    Inputs::with_root(2021, prefix)
+        .with_cookie(Path::new(prefix).join("cookie.json"))
+        .read(day as u32)
+}
+
+/// The input for tests. Panics with the reason when it can't be read.
+pub fn test_input(prefix: &str, day: usize) -> String {
+    read_input(prefix, day).unwrap_or_else(|e| panic!("Day {}: {}", day, e))
+}
+
+/// Where the day's input is cached.
+pub fn input_path(prefix: &str, day: usize) -> PathBuf {
+    Inputs::with_root(2021, prefix).path(day as u32)
+}
+
+/// The input if it has already been downloaded, without fetching it.
+pub fn cached_input(prefix: &str, day: usize) -> Option<String> {
+    std::fs::read_to_string(input_path(prefix, day)).ok()
+}
+
+#[macro_export]
+macro_rules! generate_bench {
+    ($year:expr, $day:expr) => {
+        use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+        use std::hint::black_box;
+        use $crate::Solution;
+
+        // Benches run in the day's crate, the year is always 2021 and its inputs are
+        // two directories up.
+        const PREFIX: &str = "../..";
+
+        fn bench(c: &mut Criterion) {
+            let Some(input) = util::cached_input(PREFIX, $day) else {
+                eprintln!(
+                    "Skipping day{:0>2} benchmarks, no cached input at {}",
+                    $day,
+                    util::input_path(PREFIX, $day).display()
+                );
+                return;
+            };
+
+            let mut group = c.benchmark_group(format!("day{:0>2}", $day));
+            group.bench_function("parse", |b| b.iter(|| Day::parse(black_box(&input))));
+            group.bench_function("part1", |b| {
+                b.iter_batched(
+                    || Day::parse(&input),
+                    |data| Day::part1(data),
+                    BatchSize::SmallInput,
+                )
+            });
+            group.bench_function("part2", |b| {
+                b.iter_batched(
+                    || Day::parse(&input),
+                    |data| Day::part2(data),
+                    BatchSize::SmallInput,
+                )
+            });
+            group.finish();
+        }
+
+        criterion_group!(benches, bench);
+        criterion_main!(benches);
+    };
 }
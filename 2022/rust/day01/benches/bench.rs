@@ -0,0 +1,3 @@
+use day01::*;
+
+util::generate_bench!(2022, 1);
@@ -1,29 +1,39 @@
-pub fn part1(elves: impl Iterator<Item = i32>) -> i32 {
-    elves.max().unwrap()
-}
-
-pub fn part2(elves: impl Iterator<Item = i32>) -> i32 {
-    let mut calories: Vec<i32> = elves.collect();
+use util::Solution;
 
-    calories.sort();
+pub struct Day;
 
-    calories.iter().rev().take(3).sum()
-}
+impl Solution for Day {
+    type Input<'a> = std::vec::IntoIter<i32>;
+    type Output1 = i32;
+    type Output2 = i32;
 
-pub fn parse<'a>(data: &'a str) -> impl Iterator<Item = i32> + 'a {
-    let mut t: i32 = 0;
-    let mut v: Vec<i32> = vec![];
-    for n in data.lines() {
-        match n {
-            "" => {
-                v.push(t);
-                t = 0;
+    fn parse(data: &str) -> Self::Input<'_> {
+        let mut t: i32 = 0;
+        let mut v: Vec<i32> = vec![];
+        for n in data.lines() {
+            match n {
+                "" => {
+                    v.push(t);
+                    t = 0;
+                }
+                _ => t += n.parse::<i32>().unwrap(),
             }
-            _ => t += n.parse::<i32>().unwrap(),
         }
+        if t > 0 {
+            v.push(t);
+        }
+        v.into_iter()
     }
-    if t > 0 {
-        v.push(t);
+
+    fn part1(elves: Self::Input<'_>) -> Self::Output1 {
+        elves.max().unwrap()
+    }
+
+    fn part2(elves: Self::Input<'_>) -> Self::Output2 {
+        let mut calories: Vec<i32> = elves.collect();
+
+        calories.sort();
+
+        calories.iter().rev().take(3).sum()
     }
-    v.into_iter()
 }
@@ -1,4 +1,5 @@
 use std::{cmp::Ordering, str::Lines};
+use util::Solution;
 
 #[derive(Debug, Clone, PartialEq)]
 enum Choice {
@@ -158,20 +159,28 @@ impl Round {
     }
 }
 
-pub fn parse<'a>(data: &'a str) -> Lines<'a> {
-    data.lines()
-}
+pub struct Day;
 
-pub fn part1(lines: Lines) -> i32 {
-    lines
-        .map(|l| Round::from_str(l))
-        .map(|round| round.grade())
-        .sum()
-}
+impl Solution for Day {
+    type Input<'a> = Lines<'a>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        data.lines()
+    }
 
-pub fn part2(lines: Lines) -> i32 {
-    lines
-        .map(|l| Round::cheat_from_str(l))
-        .map(|round| round.grade())
-        .sum()
+    fn part1(lines: Self::Input<'_>) -> Self::Output1 {
+        lines
+            .map(|l| Round::from_str(l))
+            .map(|round| round.grade())
+            .sum()
+    }
+
+    fn part2(lines: Self::Input<'_>) -> Self::Output2 {
+        lines
+            .map(|l| Round::cheat_from_str(l))
+            .map(|round| round.grade())
+            .sum()
+    }
 }
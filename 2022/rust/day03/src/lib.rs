@@ -2,6 +2,7 @@ use lazy_static::lazy_static;
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 use std::ops::RangeInclusive;
+use util::Solution;
 
 const LOWER: RangeInclusive<char> = 'a'..='z';
 const UPPER: RangeInclusive<char> = 'A'..='Z';
@@ -40,15 +41,45 @@ impl Rucksack {
     }
 }
 
-pub fn parse(data: &str) -> Vec<Rucksack> {
-    data.lines()
-        .map(|line| {
-            let chars = line.chars().collect::<Vec<char>>();
-            let len = chars.len();
-            let (left, right) = chars.split_at(len / 2);
-            Rucksack::from_vec_char(left.to_vec(), right.to_vec())
-        })
-        .collect()
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Rucksack>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        data.lines()
+            .map(|line| {
+                let chars = line.chars().collect::<Vec<char>>();
+                let len = chars.len();
+                let (left, right) = chars.split_at(len / 2);
+                Rucksack::from_vec_char(left.to_vec(), right.to_vec())
+            })
+            .collect()
+    }
+
+    fn part1(rucksacks: Self::Input<'_>) -> Self::Output1 {
+        rucksacks
+            .iter()
+            .map(|sack| sack.common().iter().map(|c| value(*c)).sum::<i32>())
+            .sum()
+    }
+
+    fn part2(rucksacks: Self::Input<'_>) -> Self::Output2 {
+        rucksacks
+            .iter()
+            .map(|sack| sack.all())
+            .collect::<Vec<_>>()
+            .chunks(3)
+            .map(|chunk| {
+                let i = chunk.iter().cloned();
+                let set = hash_sets_intersection(i);
+                let c = set.iter().next().unwrap();
+                value(*c)
+            })
+            .sum()
+    }
 }
 
 pub fn value(c: char) -> i32 {
@@ -72,25 +103,3 @@ where
     }
     s
 }
-
-pub fn part1(rucksacks: Vec<Rucksack>) -> i32 {
-    rucksacks
-        .iter()
-        .map(|sack| sack.common().iter().map(|c| value(*c)).sum::<i32>())
-        .sum()
-}
-
-pub fn part2(rucksacks: Vec<Rucksack>) -> i32 {
-    rucksacks
-        .iter()
-        .map(|sack| sack.all())
-        .collect::<Vec<_>>()
-        .chunks(3)
-        .map(|chunk| {
-            let i = chunk.iter().cloned();
-            let set = hash_sets_intersection(i);
-            let c = set.iter().next().unwrap();
-            value(*c)
-        })
-        .sum()
-}
@@ -1,21 +1,35 @@
 use std::ops::RangeInclusive;
+use util::Solution;
 
 use nom::{bytes::complete::tag, multi::separated_list0, sequence::tuple, IResult};
 
-pub fn part1(assignment_pairs: impl Iterator<Item = AssignmnetPair>) -> i32 {
-    assignment_pairs
-        .filter(|pair| pair.full_overlap())
-        .count()
-        .try_into()
-        .unwrap()
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = std::vec::IntoIter<AssignmnetPair>;
+    type Output1 = i32;
+    type Output2 = i32;
 
-pub fn part2(assignment_pairs: impl Iterator<Item = AssignmnetPair>) -> i32 {
-    assignment_pairs
-        .filter(|pair| pair.any_overlap())
-        .count()
-        .try_into()
-        .unwrap()
+    fn parse(data: &str) -> Self::Input<'_> {
+        let (_, pairs) = parse_assignment_pairs(data).unwrap();
+        pairs.into_iter()
+    }
+
+    fn part1(assignment_pairs: Self::Input<'_>) -> Self::Output1 {
+        assignment_pairs
+            .filter(|pair| pair.full_overlap())
+            .count()
+            .try_into()
+            .unwrap()
+    }
+
+    fn part2(assignment_pairs: Self::Input<'_>) -> Self::Output2 {
+        assignment_pairs
+            .filter(|pair| pair.any_overlap())
+            .count()
+            .try_into()
+            .unwrap()
+    }
 }
 
 pub struct AssignmnetPair {
@@ -70,11 +84,6 @@ fn parse_assignment_pairs(s: &str) -> IResult<&str, Vec<AssignmnetPair>> {
     Ok((rest, assignment_pairs))
 }
 
-pub fn parse<'a>(data: &'a str) -> impl Iterator<Item = AssignmnetPair> + 'a {
-    let (_, pairs) = parse_assignment_pairs(data).unwrap();
-    pairs.into_iter()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
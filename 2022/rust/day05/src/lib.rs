@@ -8,27 +8,36 @@ use nom::{
     IResult,
 };
 use std::fmt;
+use util::Solution;
 
-pub fn part1((mut stacks, moves): (Stacks, Vec<Move>)) -> String {
-    stacks
-        .process_moves(moves, Stack::take_9000)
-        .top_crates()
-        .into_iter()
-        .collect()
-}
+pub struct Day;
 
-pub fn part2((mut stacks, moves): (Stacks, Vec<Move>)) -> String {
-    stacks
-        .process_moves(moves, Stack::take_9001)
-        .top_crates()
-        .into_iter()
-        .collect()
-}
+impl Solution for Day {
+    type Input<'a> = (Stacks, Vec<Move>);
+    type Output1 = String;
+    type Output2 = String;
 
-pub fn parse<'a>(data: &'a str) -> (Stacks, Vec<Move>) {
-    let (_, (stacks, moves)) = parse_data(data).unwrap();
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let (_, (stacks, moves)) = parse_data(data).unwrap();
+
+        (stacks, moves)
+    }
 
-    (stacks, moves)
+    fn part1((mut stacks, moves): Self::Input<'_>) -> Self::Output1 {
+        stacks
+            .process_moves(moves, Stack::take_9000)
+            .top_crates()
+            .into_iter()
+            .collect()
+    }
+
+    fn part2((mut stacks, moves): Self::Input<'_>) -> Self::Output2 {
+        stacks
+            .process_moves(moves, Stack::take_9001)
+            .top_crates()
+            .into_iter()
+            .collect()
+    }
 }
 
 fn parse_data(s: &str) -> IResult<&str, (Stacks, Vec<Move>)> {
@@ -282,8 +291,8 @@ move 3 from 1 to 3
 move 2 from 2 to 1
 move 1 from 1 to 2
         "#;
-        let parsed = parse(data);
-        let res = part1(parsed);
+        let parsed = Day::parse(data);
+        let res = Day::part1(parsed);
         assert_eq!(res, "CMZ")
     }
 }
@@ -1,9 +1,23 @@
-pub fn part1<'a>(data: &'a str) -> usize {
-    find_consecutive_unique(data, 4)
-}
+use util::Solution;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = &'a str;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        data
+    }
 
-pub fn part2<'a>(data: &'a str) -> usize {
-    find_consecutive_unique(data, 14)
+    fn part1<'a>(data: Self::Input<'a>) -> Self::Output1 {
+        find_consecutive_unique(data, 4)
+    }
+
+    fn part2<'a>(data: Self::Input<'a>) -> Self::Output2 {
+        find_consecutive_unique(data, 14)
+    }
 }
 
 pub fn find_consecutive_unique<'a>(data: &'a str, num: usize) -> usize {
@@ -26,16 +40,12 @@ pub fn find_consecutive_unique<'a>(data: &'a str, num: usize) -> usize {
     unreachable!()
 }
 
-pub fn parse<'a>(data: &'a str) -> &'a str {
-    data
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_1() {
-        assert_eq!(part1("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 7)
+        assert_eq!(Day::part1("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), 7)
     }
 }
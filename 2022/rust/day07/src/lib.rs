@@ -1,4 +1,5 @@
 use std::iter;
+use util::Solution;
 
 use nom::{
     branch::alt,
@@ -10,32 +11,40 @@ use nom::{
     IResult,
 };
 
-pub fn part1<'a>(root: Directory) -> i32 {
-    root.all_dirs()
-        .map(|x| x.total_size())
-        .filter(|x| x <= &100000)
-        .sum()
-}
+pub struct Day;
 
-pub fn part2<'a>(root: Directory) -> i32 {
-    let total = root.total_size();
-    let free = 70000000 - total;
-    let to_delete = 30000000 - free;
+impl Solution for Day {
+    type Input<'a> = Directory;
+    type Output1 = i32;
+    type Output2 = i32;
 
-    let mut big_enonugh: Vec<&Directory> = root
-        .all_dirs()
-        .filter(|x| x.total_size() > to_delete)
-        .collect();
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let (_, root) = parse_directory(data).unwrap();
 
-    big_enonugh.sort_by_key(|x| x.total_size());
+        root
+    }
 
-    big_enonugh.first().unwrap().total_size()
-}
+    fn part1<'a>(root: Self::Input<'a>) -> Self::Output1 {
+        root.all_dirs()
+            .map(|x| x.total_size())
+            .filter(|x| x <= &100000)
+            .sum()
+    }
+
+    fn part2<'a>(root: Self::Input<'a>) -> Self::Output2 {
+        let total = root.total_size();
+        let free = 70000000 - total;
+        let to_delete = 30000000 - free;
 
-pub fn parse<'a>(data: &'a str) -> Directory {
-    let (_, root) = parse_directory(data).unwrap();
+        let mut big_enonugh: Vec<&Directory> = root
+            .all_dirs()
+            .filter(|x| x.total_size() > to_delete)
+            .collect();
 
-    root
+        big_enonugh.sort_by_key(|x| x.total_size());
+
+        big_enonugh.first().unwrap().total_size()
+    }
 }
 
 pub fn parse_file(s: &str) -> IResult<&str, File> {
@@ -167,8 +176,8 @@ $ ls
 8033020 d.log
 5626152 d.ext
 7214296 k"#;
-        let parsed = parse(input);
-        let res = part1(parsed);
+        let parsed = Day::parse(input);
+        let res = Day::part1(parsed);
         assert_eq!(res, 95437)
     }
 }
@@ -1,34 +1,91 @@
 use itertools::FoldWhile::{self, Continue, Done};
 use itertools::Itertools;
 use std::fmt::{self, Display};
+use util::Solution;
 
-pub fn part1<'a>(grid: Grid<i32>) -> i32 {
-    let mut count: i32 = 0;
+pub struct Day;
 
-    for x in 0..=grid.max_x {
-        for y in 0..=grid.max_y {
-            if x == 0 || y == 0 || x == grid.max_x || y == grid.max_y {
-                count += 1;
-            } else {
-                let v = grid.get(x, y).unwrap();
-                let mut left = 0..x;
-                let mut right = (x + 1)..=grid.max_x;
-                let mut above = 0..y;
-                let mut below = (y + 1)..=grid.max_y;
+impl Solution for Day {
+    type Input<'a> = Grid<i32>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let mut grid = Grid::new();
+
+        for (y, line) in data.lines().enumerate() {
+            for (x, char) in line.chars().enumerate() {
+                let v: i32 = char.to_string().parse().unwrap();
+                grid.insert(x, y, v);
+            }
+        }
+
+        grid
+    }
 
-                let any_left = left.any(|x| grid.get(x, y).unwrap() >= v);
-                let any_right = right.any(|x| grid.get(x, y).unwrap() >= v);
-                let any_above = above.any(|y| grid.get(x, y).unwrap() >= v);
-                let any_below = below.any(|y| grid.get(x, y).unwrap() >= v);
+    fn part1<'a>(grid: Self::Input<'a>) -> Self::Output1 {
+        let mut count: i32 = 0;
 
-                if !(any_left && any_right && any_above && any_below) {
+        for x in 0..=grid.max_x {
+            for y in 0..=grid.max_y {
+                if x == 0 || y == 0 || x == grid.max_x || y == grid.max_y {
                     count += 1;
+                } else {
+                    let v = grid.get(x, y).unwrap();
+                    let mut left = 0..x;
+                    let mut right = (x + 1)..=grid.max_x;
+                    let mut above = 0..y;
+                    let mut below = (y + 1)..=grid.max_y;
+
+                    let any_left = left.any(|x| grid.get(x, y).unwrap() >= v);
+                    let any_right = right.any(|x| grid.get(x, y).unwrap() >= v);
+                    let any_above = above.any(|y| grid.get(x, y).unwrap() >= v);
+                    let any_below = below.any(|y| grid.get(x, y).unwrap() >= v);
+
+                    if !(any_left && any_right && any_above && any_below) {
+                        count += 1;
+                    }
                 }
             }
         }
+
+        count
     }
 
-    count
+    fn part2<'a>(grid: Self::Input<'a>) -> Self::Output2 {
+        let mut max_scenic_score: i32 = 0;
+
+        for x in 0..=grid.max_x {
+            for y in 0..=grid.max_y {
+                let v = grid.get(x, y).unwrap();
+                let mut left = (0..x).rev();
+                let mut right = (x + 1)..=grid.max_x;
+                let mut above = (0..y).rev();
+                let mut below = (y + 1)..=grid.max_y;
+
+                let left_score = left
+                    .fold_while(0, |acc, x| fold_scenic_score(&grid, x, y, v, acc))
+                    .into_inner();
+                let right_score = right
+                    .fold_while(0, |acc, x| fold_scenic_score(&grid, x, y, v, acc))
+                    .into_inner();
+                let above_score = above
+                    .fold_while(0, |acc, y| fold_scenic_score(&grid, x, y, v, acc))
+                    .into_inner();
+                let below_score = below
+                    .fold_while(0, |acc, y| fold_scenic_score(&grid, x, y, v, acc))
+                    .into_inner();
+
+                let total_score = left_score * right_score * above_score * below_score;
+
+                if total_score > max_scenic_score {
+                    max_scenic_score = total_score;
+                }
+            }
+        }
+
+        max_scenic_score
+    }
 }
 
 fn fold_scenic_score<T: Clone + PartialOrd>(
@@ -46,54 +103,6 @@ fn fold_scenic_score<T: Clone + PartialOrd>(
     }
 }
 
-pub fn part2<'a>(grid: Grid<i32>) -> i32 {
-    let mut max_scenic_score: i32 = 0;
-
-    for x in 0..=grid.max_x {
-        for y in 0..=grid.max_y {
-            let v = grid.get(x, y).unwrap();
-            let mut left = (0..x).rev();
-            let mut right = (x + 1)..=grid.max_x;
-            let mut above = (0..y).rev();
-            let mut below = (y + 1)..=grid.max_y;
-
-            let left_score = left
-                .fold_while(0, |acc, x| fold_scenic_score(&grid, x, y, v, acc))
-                .into_inner();
-            let right_score = right
-                .fold_while(0, |acc, x| fold_scenic_score(&grid, x, y, v, acc))
-                .into_inner();
-            let above_score = above
-                .fold_while(0, |acc, y| fold_scenic_score(&grid, x, y, v, acc))
-                .into_inner();
-            let below_score = below
-                .fold_while(0, |acc, y| fold_scenic_score(&grid, x, y, v, acc))
-                .into_inner();
-
-            let total_score = left_score * right_score * above_score * below_score;
-
-            if total_score > max_scenic_score {
-                max_scenic_score = total_score;
-            }
-        }
-    }
-
-    max_scenic_score
-}
-
-pub fn parse<'a>(data: &'a str) -> Grid<i32> {
-    let mut grid = Grid::new();
-
-    for (y, line) in data.lines().enumerate() {
-        for (x, char) in line.chars().enumerate() {
-            let v: i32 = char.to_string().parse().unwrap();
-            grid.insert(x, y, v);
-        }
-    }
-
-    grid
-}
-
 pub struct Grid<T> {
     data: Vec<Vec<Option<T>>>,
     max_x: usize,
@@ -170,8 +179,8 @@ mod tests {
 65332
 33549
 35390"#;
-        let parsed = parse(data);
-        let res = part1(parsed);
+        let parsed = Day::parse(data);
+        let res = Day::part1(parsed);
         assert_eq!(res, 21)
     }
 
@@ -182,8 +191,8 @@ mod tests {
 65332
 33549
 35390"#;
-        let parsed = parse(data);
-        let res = part2(parsed);
+        let parsed = Day::parse(data);
+        let res = Day::part2(parsed);
         assert_eq!(res, 8)
     }
 }
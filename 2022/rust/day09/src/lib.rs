@@ -1,27 +1,36 @@
 use std::{collections::HashSet, fmt};
+use util::Solution;
 
-pub fn part1<'a>(moves: Vec<Move>) -> usize {
-    let mut state = State::new(2);
+pub struct Day;
 
-    for mv in moves {
-        state.apply_move(&mv);
+impl Solution for Day {
+    type Input<'a> = Vec<Move>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        data.lines().map(Move::from_str).collect()
     }
 
-    state.tail_locations.len()
-}
+    fn part1<'a>(moves: Self::Input<'a>) -> Self::Output1 {
+        let mut state = State::new(2);
 
-pub fn part2<'a>(moves: Vec<Move>) -> usize {
-    let mut state = State::new(10);
+        for mv in moves {
+            state.apply_move(&mv);
+        }
 
-    for mv in moves {
-        state.apply_move(&mv);
+        state.tail_locations.len()
     }
 
-    state.tail_locations.len()
-}
+    fn part2<'a>(moves: Self::Input<'a>) -> Self::Output2 {
+        let mut state = State::new(10);
 
-pub fn parse<'a>(data: &'a str) -> Vec<Move> {
-    data.lines().map(Move::from_str).collect()
+        for mv in moves {
+            state.apply_move(&mv);
+        }
+
+        state.tail_locations.len()
+    }
 }
 
 #[derive(Debug)]
@@ -165,8 +174,8 @@ R 4
 D 1
 L 5
 R 2"#;
-        let parsed = parse(input);
-        let res = part1(parsed);
+        let parsed = Day::parse(input);
+        let res = Day::part1(parsed);
         assert_eq!(13, res)
     }
 
@@ -180,8 +189,8 @@ R 4
 D 1
 L 5
 R 2"#;
-        let parsed = parse(input);
-        let res = part2(parsed);
+        let parsed = Day::parse(input);
+        let res = Day::part2(parsed);
         assert_eq!(1, res)
     }
 
@@ -195,8 +204,8 @@ R 17
 D 10
 L 25
 U 20"#;
-        let parsed = parse(input);
-        let res = part2(parsed);
+        let parsed = Day::parse(input);
+        let res = Day::part2(parsed);
         assert_eq!(36, res)
     }
 }
@@ -3,72 +3,81 @@ use nom::{
     branch::alt, bytes::complete::tag, character::complete::newline, multi::separated_list1,
     sequence::preceded, IResult,
 };
+use util::Solution;
 
-pub fn part1<'a>(instructions: Vec<Instruction>) -> i32 {
-    instructions
-        .iter()
-        .fold((1, 1, vec![]), |(cycle, x, mut signal_strengths), op| {
-            let next_cycle = cycle + op.cycles();
-            let next_x = match op {
-                Instruction::NOOP => x,
-                Instruction::ADDX(v) => x + v,
-            };
+pub struct Day;
 
-            let key_cycle = (cycle..next_cycle).find_map(|x| {
-                if x == 20 || (x - 20) % 40 == 0 {
-                    Some(x)
-                } else {
-                    None
-                }
-            });
+impl Solution for Day {
+    type Input<'a> = Vec<Instruction>;
+    type Output1 = i32;
+    type Output2 = String;
 
-            if let Some(cycle) = key_cycle {
-                signal_strengths.push(cycle * x);
-            }
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let (_, instructions) = parse_instuctions(data).unwrap();
+        instructions
+    }
 
-            (next_cycle, next_x, signal_strengths)
-        })
-        .2
-        .iter()
-        .sum()
-}
+    fn part1<'a>(instructions: Self::Input<'a>) -> Self::Output1 {
+        instructions
+            .iter()
+            .fold((1, 1, vec![]), |(cycle, x, mut signal_strengths), op| {
+                let next_cycle = cycle + op.cycles();
+                let next_x = match op {
+                    Instruction::NOOP => x,
+                    Instruction::ADDX(v) => x + v,
+                };
 
-pub fn part2<'a>(instructions: Vec<Instruction>) -> String {
-    let output = instructions
-        .iter()
-        .scan(1, |x, op| {
-            let res = Some(vec![x.clone(); op.cycles().try_into().unwrap()]);
-            match op {
-                Instruction::NOOP => (),
-                Instruction::ADDX(v) => *x += v,
-            };
-            res
-        })
-        .flatten()
-        .enumerate()
-        .map(|(c, sprite_x)| {
-            let pixel = (c % 40) as i32;
-            let sprite_pos = (sprite_x - 1)..=(sprite_x + 1);
+                let key_cycle = (cycle..next_cycle).find_map(|x| {
+                    if x == 20 || (x - 20) % 40 == 0 {
+                        Some(x)
+                    } else {
+                        None
+                    }
+                });
 
-            if sprite_pos.contains(&pixel) {
-                "#"
-            } else {
-                "."
-            }
-        })
-        .chunks(40)
-        .into_iter()
-        .map(|mut line| line.join(""))
-        .collect_vec()
-        .iter()
-        .join("\n");
+                if let Some(cycle) = key_cycle {
+                    signal_strengths.push(cycle * x);
+                }
 
-    format!("\n{}", output)
-}
+                (next_cycle, next_x, signal_strengths)
+            })
+            .2
+            .iter()
+            .sum()
+    }
+
+    fn part2<'a>(instructions: Self::Input<'a>) -> Self::Output2 {
+        let output = instructions
+            .iter()
+            .scan(1, |x, op| {
+                let res = Some(vec![x.clone(); op.cycles().try_into().unwrap()]);
+                match op {
+                    Instruction::NOOP => (),
+                    Instruction::ADDX(v) => *x += v,
+                };
+                res
+            })
+            .flatten()
+            .enumerate()
+            .map(|(c, sprite_x)| {
+                let pixel = (c % 40) as i32;
+                let sprite_pos = (sprite_x - 1)..=(sprite_x + 1);
+
+                if sprite_pos.contains(&pixel) {
+                    "#"
+                } else {
+                    "."
+                }
+            })
+            .chunks(40)
+            .into_iter()
+            .map(|mut line| line.join(""))
+            .collect_vec()
+            .iter()
+            .join("\n");
 
-pub fn parse<'a>(data: &'a str) -> Vec<Instruction> {
-    let (_, instructions) = parse_instuctions(data).unwrap();
-    instructions
+        format!("\n{}", output)
+    }
 }
 
 fn parse_instuctions(s: &str) -> IResult<&str, Vec<Instruction>> {
@@ -254,8 +263,8 @@ noop
 noop
 noop"#;
 
-        let parsed = parse(input);
-        let res = part1(parsed);
+        let parsed = Day::parse(input);
+        let res = Day::part1(parsed);
         assert_eq!(13140, res)
     }
 }
@@ -8,75 +8,84 @@ use nom::{
     sequence::{preceded, tuple},
     IResult,
 };
+use util::Solution;
 
-pub fn part1<'a>(mut monkeys: Vec<Monkey>) -> u64 {
-    for _ in 0..20 {
-        for idx in 0..monkeys.len() {
-            let items: Vec<u64> = monkeys[idx].items.drain(..).collect();
-            monkeys[idx].inspected += items.len() as u64;
-            let monkey = monkeys[idx].clone();
-            for mut item in items {
-                match monkey.operation {
-                    Op::Add(v) => item += v,
-                    Op::Multiply(v) => item *= v,
-                    Op::Square => item *= item,
-                }
-                item /= 3;
-                if item % monkey.test == 0 {
-                    monkeys[monkey.true_idx].items.push(item);
-                } else {
-                    monkeys[monkey.false_idx].items.push(item);
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Monkey>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let (_rest, monkeys) = parse_monkeys(data).unwrap();
+
+        monkeys
+    }
+
+    fn part1<'a>(mut monkeys: Self::Input<'a>) -> Self::Output1 {
+        for _ in 0..20 {
+            for idx in 0..monkeys.len() {
+                let items: Vec<u64> = monkeys[idx].items.drain(..).collect();
+                monkeys[idx].inspected += items.len() as u64;
+                let monkey = monkeys[idx].clone();
+                for mut item in items {
+                    match monkey.operation {
+                        Op::Add(v) => item += v,
+                        Op::Multiply(v) => item *= v,
+                        Op::Square => item *= item,
+                    }
+                    item /= 3;
+                    if item % monkey.test == 0 {
+                        monkeys[monkey.true_idx].items.push(item);
+                    } else {
+                        monkeys[monkey.false_idx].items.push(item);
+                    }
                 }
             }
         }
-    }
 
-    monkeys
-        .iter()
-        .map(|x| x.inspected)
-        .sorted()
-        .rev()
-        .take(2)
-        .product()
-}
+        monkeys
+            .iter()
+            .map(|x| x.inspected)
+            .sorted()
+            .rev()
+            .take(2)
+            .product()
+    }
 
-pub fn part2<'a>(mut monkeys: Vec<Monkey>) -> u64 {
-    let common_multiple: u64 = monkeys.iter().map(|monkey| monkey.test).product();
-
-    for _ in 0..10000 {
-        for idx in 0..monkeys.len() {
-            let items: Vec<u64> = monkeys[idx].items.drain(..).collect();
-            monkeys[idx].inspected += items.len() as u64;
-            let monkey = monkeys[idx].clone();
-            for mut item in items {
-                match monkey.operation {
-                    Op::Add(v) => item += v,
-                    Op::Multiply(v) => item *= v,
-                    Op::Square => item *= item,
-                }
-                item %= common_multiple;
-                if item % monkey.test == 0 {
-                    monkeys[monkey.true_idx].items.push(item);
-                } else {
-                    monkeys[monkey.false_idx].items.push(item);
+    fn part2<'a>(mut monkeys: Self::Input<'a>) -> Self::Output2 {
+        let common_multiple: u64 = monkeys.iter().map(|monkey| monkey.test).product();
+
+        for _ in 0..10000 {
+            for idx in 0..monkeys.len() {
+                let items: Vec<u64> = monkeys[idx].items.drain(..).collect();
+                monkeys[idx].inspected += items.len() as u64;
+                let monkey = monkeys[idx].clone();
+                for mut item in items {
+                    match monkey.operation {
+                        Op::Add(v) => item += v,
+                        Op::Multiply(v) => item *= v,
+                        Op::Square => item *= item,
+                    }
+                    item %= common_multiple;
+                    if item % monkey.test == 0 {
+                        monkeys[monkey.true_idx].items.push(item);
+                    } else {
+                        monkeys[monkey.false_idx].items.push(item);
+                    }
                 }
             }
         }
-    }
 
-    monkeys
-        .iter()
-        .map(|x| x.inspected)
-        .sorted()
-        .rev()
-        .take(2)
-        .product()
-}
-
-pub fn parse<'a>(data: &'a str) -> Vec<Monkey> {
-    let (_rest, monkeys) = parse_monkeys(data).unwrap();
-
-    monkeys
+        monkeys
+            .iter()
+            .map(|x| x.inspected)
+            .sorted()
+            .rev()
+            .take(2)
+            .product()
+    }
 }
 
 fn parse_monkeys(s: &str) -> IResult<&str, Vec<Monkey>> {
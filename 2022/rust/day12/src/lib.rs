@@ -3,49 +3,58 @@ use std::{
     collections::{BinaryHeap, HashMap, HashSet},
     fmt,
 };
+use util::Solution;
 
 use rayon::prelude::*;
 
-pub fn part1<'a>(map: Map) -> u32 {
-    shortest_path(&map, map.start, map.end).unwrap()
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Map;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        data.lines()
+            .enumerate()
+            .fold(Map::new(), |mut map, (y, line)| {
+                map.max_y = map.max_y.max(y);
+                line.chars().enumerate().fold(map, |mut map, (x, char)| {
+                    map.max_x = map.max_x.max(x);
+                    let point = Pos::new(x, y);
+
+                    match char {
+                        'S' => {
+                            map.start = point.clone();
+                            map.curr = point.clone();
+                            map.insert(point, 0);
+                        }
+                        'E' => {
+                            map.end = point.clone();
+                            map.insert(point, 25);
+                        }
+                        _ => {
+                            let val = (char as u32) - 97;
+                            map.insert(point, val);
+                        }
+                    };
+                    map
+                })
+            })
+    }
 
-pub fn part2<'a>(map: Map) -> u32 {
-    map.points
-        .par_iter()
-        .filter(|(_, val)| **val == 0)
-        .filter_map(|(pos, _)| shortest_path(&map, *pos, map.end))
-        .min()
-        .unwrap()
-}
+    fn part1<'a>(map: Self::Input<'a>) -> Self::Output1 {
+        shortest_path(&map, map.start, map.end).unwrap()
+    }
 
-pub fn parse<'a>(data: &'a str) -> Map {
-    data.lines()
-        .enumerate()
-        .fold(Map::new(), |mut map, (y, line)| {
-            map.max_y = map.max_y.max(y);
-            line.chars().enumerate().fold(map, |mut map, (x, char)| {
-                map.max_x = map.max_x.max(x);
-                let point = Pos::new(x, y);
-
-                match char {
-                    'S' => {
-                        map.start = point.clone();
-                        map.curr = point.clone();
-                        map.insert(point, 0);
-                    }
-                    'E' => {
-                        map.end = point.clone();
-                        map.insert(point, 25);
-                    }
-                    _ => {
-                        let val = (char as u32) - 97;
-                        map.insert(point, val);
-                    }
-                };
-                map
-            })
-        })
+    fn part2<'a>(map: Self::Input<'a>) -> Self::Output2 {
+        map.points
+            .par_iter()
+            .filter(|(_, val)| **val == 0)
+            .filter_map(|(pos, _)| shortest_path(&map, *pos, map.end))
+            .min()
+            .unwrap()
+    }
 }
 
 #[derive(Clone, Eq, PartialEq, Debug)]
@@ -213,8 +222,8 @@ abcryxxl
 accszExk
 acctuvwj
 abdefghi"#;
-        let parsed = parse(input);
-        let res = part1(parsed);
+        let parsed = Day::parse(input);
+        let res = Day::part1(parsed);
         assert_eq!(31, res)
     }
 }
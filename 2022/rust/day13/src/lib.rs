@@ -1,4 +1,5 @@
 use std::{cmp::Ordering, fmt};
+use util::Solution;
 
 use itertools::Itertools;
 use nom::{
@@ -10,49 +11,57 @@ use nom::{
     IResult,
 };
 
-pub fn part1<'a>(pairs: Vec<Pair>) -> usize {
-    pairs
-        .iter()
-        .enumerate()
-        .filter_map(|(i, pair)| {
-            let mut a = vec![(pair.0).0.clone(), (pair.0).1.clone()];
-            let b = vec![(pair.0).0.clone(), (pair.0).1.clone()];
-            a.sort();
-            if a == b {
-                Some(i + 1)
-            } else {
-                None
-            }
-        })
-        .sum()
-}
+pub struct Day;
 
-pub fn part2<'a>(pairs: Vec<Pair>) -> usize {
-    let divider1 = Packet::List(vec![Packet::List(vec![Packet::I(6)])]);
-    let divider2 = Packet::List(vec![Packet::List(vec![Packet::I(2)])]);
-
-    pairs
-        .into_iter()
-        .chain(vec![Pair((divider1.clone(), divider2.clone()))].into_iter())
-        .flat_map(|pair| vec![(pair.0).0, (pair.0).1])
-        .sorted()
-        .enumerate()
-        .filter_map(|(i, pair)| {
-            if pair == divider1 {
-                return Some(i + 1);
-            }
-            if pair == divider2 {
-                return Some(i + 1);
-            }
-            None
-        })
-        .product()
-}
+impl Solution for Day {
+    type Input<'a> = Vec<Pair>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let (_rest, pairs) = parse_pairs(data).unwrap();
+        assert_eq!(_rest.trim(), "");
+        pairs
+    }
 
-pub fn parse<'a>(data: &'a str) -> Vec<Pair> {
-    let (_rest, pairs) = parse_pairs(data).unwrap();
-    assert_eq!(_rest.trim(), "");
-    pairs
+    fn part1<'a>(pairs: Self::Input<'a>) -> Self::Output1 {
+        pairs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, pair)| {
+                let mut a = vec![(pair.0).0.clone(), (pair.0).1.clone()];
+                let b = vec![(pair.0).0.clone(), (pair.0).1.clone()];
+                a.sort();
+                if a == b {
+                    Some(i + 1)
+                } else {
+                    None
+                }
+            })
+            .sum()
+    }
+
+    fn part2<'a>(pairs: Self::Input<'a>) -> Self::Output2 {
+        let divider1 = Packet::List(vec![Packet::List(vec![Packet::I(6)])]);
+        let divider2 = Packet::List(vec![Packet::List(vec![Packet::I(2)])]);
+
+        pairs
+            .into_iter()
+            .chain(vec![Pair((divider1.clone(), divider2.clone()))].into_iter())
+            .flat_map(|pair| vec![(pair.0).0, (pair.0).1])
+            .sorted()
+            .enumerate()
+            .filter_map(|(i, pair)| {
+                if pair == divider1 {
+                    return Some(i + 1);
+                }
+                if pair == divider2 {
+                    return Some(i + 1);
+                }
+                None
+            })
+            .product()
+    }
 }
 
 fn parse_pairs(s: &str) -> IResult<&str, Vec<Pair>> {
@@ -1,4 +1,5 @@
 use std::{collections::HashMap, fmt};
+use util::Solution;
 
 use itertools::Itertools;
 use nom::{
@@ -10,35 +11,43 @@ use nom::{
     IResult,
 };
 
-pub fn part1<'a>(mut cave: Cave) -> usize {
-    let pos = Pos::new(500, 0);
-    while cave.drop(&pos, None, &Contents::Sand) {}
-    cave.points
-        .iter()
-        .filter(|(_, contents)| ***contents == Contents::Sand)
-        .count()
-}
+pub struct Day;
 
-pub fn part2<'a>(mut cave: Cave) -> usize {
-    let pos = Pos::new(500, 0);
-    let floor_y = cave.max_y + 2;
-    while cave.drop(&pos, Some(floor_y), &Contents::Sand) {}
-    cave.points
-        .iter()
-        .filter(|(_, contents)| ***contents == Contents::Sand)
-        .count()
-}
+impl Solution for Day {
+    type Input<'a> = Cave<'a>;
+    type Output1 = usize;
+    type Output2 = usize;
 
-pub fn parse<'a>(data: &'a str) -> Cave {
-    let (rest, segment_lines) = segment_lines(data).unwrap();
-    assert_eq!(rest.trim(), "");
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let (rest, segment_lines) = segment_lines(data).unwrap();
+        assert_eq!(rest.trim(), "");
+
+        let mut cave = Cave::new();
+        for segment in segment_lines.iter().flatten() {
+            cave.add(segment, &Contents::Rock)
+        }
 
-    let mut cave = Cave::new();
-    for segment in segment_lines.iter().flatten() {
-        cave.add(segment, &Contents::Rock)
+        cave
     }
 
-    cave
+    fn part1<'a>(mut cave: Self::Input<'a>) -> Self::Output1 {
+        let pos = Pos::new(500, 0);
+        while cave.drop(&pos, None, &Contents::Sand) {}
+        cave.points
+            .iter()
+            .filter(|(_, contents)| ***contents == Contents::Sand)
+            .count()
+    }
+
+    fn part2<'a>(mut cave: Self::Input<'a>) -> Self::Output2 {
+        let pos = Pos::new(500, 0);
+        let floor_y = cave.max_y + 2;
+        while cave.drop(&pos, Some(floor_y), &Contents::Sand) {}
+        cave.points
+            .iter()
+            .filter(|(_, contents)| ***contents == Contents::Sand)
+            .count()
+    }
 }
 
 fn segment_lines(s: &str) -> IResult<&str, Vec<Vec<Segment>>> {
@@ -251,8 +260,8 @@ mod tests {
     fn test_sand1() {
         let input = r#"498,4 -> 498,6 -> 496,6
 503,4 -> 502,4 -> 502,9 -> 494,9"#;
-        let parsed = parse(input);
-        let res = part1(parsed);
+        let parsed = Day::parse(input);
+        let res = Day::part1(parsed);
         assert_eq!(24, res)
     }
 
@@ -260,8 +269,8 @@ mod tests {
     fn test_sand2() {
         let input = r#"498,4 -> 498,6 -> 496,6
 503,4 -> 502,4 -> 502,9 -> 494,9"#;
-        let parsed = parse(input);
-        let res = part2(parsed);
+        let parsed = Day::parse(input);
+        let res = Day::part2(parsed);
         assert_eq!(93, res)
     }
 }
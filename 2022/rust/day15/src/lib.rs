@@ -1,4 +1,5 @@
 use std::{collections::HashMap, fmt};
+use util::Solution;
 
 use nom::{
     bytes::complete::tag, character::complete::newline, multi::separated_list1, sequence::tuple,
@@ -7,8 +8,26 @@ use nom::{
 
 use rayon::prelude::*;
 
-pub fn part1(map: Map) -> usize {
-    _do_part1(map, 2000000)
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Map;
+    type Output1 = usize;
+    type Output2 = i64;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let (rest, map) = parse_map(data).unwrap();
+        assert_eq!(rest.trim(), "");
+        map
+    }
+
+    fn part1(map: Self::Input<'_>) -> Self::Output1 {
+        _do_part1(map, 2000000)
+    }
+
+    fn part2(map: Self::Input<'_>) -> Self::Output2 {
+        _do_part2(map, 4000000)
+    }
 }
 
 fn _do_part1(map: Map, target: i32) -> usize {
@@ -22,10 +41,6 @@ fn _do_part1(map: Map, target: i32) -> usize {
     res
 }
 
-pub fn part2(map: Map) -> i64 {
-    _do_part2(map, 4000000)
-}
-
 fn _do_part2(map: Map, max: i32) -> i64 {
     let pos = map
         .edge_points()
@@ -43,12 +58,6 @@ fn _do_part2(map: Map, max: i32) -> i64 {
     (pos.x as i64 * 4000000) + pos.y as i64
 }
 
-pub fn parse<'a>(data: &'a str) -> Map {
-    let (rest, map) = parse_map(data).unwrap();
-    assert_eq!(rest.trim(), "");
-    map
-}
-
 fn parse_map(s: &str) -> IResult<&str, Map> {
     let (rest, sensors) = separated_list1(newline, sensor)(s)?;
 
@@ -252,7 +261,7 @@ Sensor at x=17, y=20: closest beacon is at x=21, y=22
 Sensor at x=16, y=7: closest beacon is at x=15, y=3
 Sensor at x=14, y=3: closest beacon is at x=15, y=3
 Sensor at x=20, y=1: closest beacon is at x=15, y=3"#;
-        let parsed = parse(input);
+        let parsed = Day::parse(input);
         let res = _do_part1(parsed, 10);
         assert_eq!(26, res)
     }
@@ -273,7 +282,7 @@ Sensor at x=17, y=20: closest beacon is at x=21, y=22
 Sensor at x=16, y=7: closest beacon is at x=15, y=3
 Sensor at x=14, y=3: closest beacon is at x=15, y=3
 Sensor at x=20, y=1: closest beacon is at x=15, y=3"#;
-        let parsed = parse(input);
+        let parsed = Day::parse(input);
         let res = _do_part2(parsed, 20);
         assert_eq!(56000011, res)
     }
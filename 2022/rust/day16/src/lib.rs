@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use util::Solution;
 
 use nom::{
     branch::alt,
@@ -10,12 +11,47 @@ use nom::{
     IResult,
 };
 
-pub fn part1(v: Valves) -> u16 {
-    let mm: usize = 1 << v.positive_flow().count();
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Valves<'a>;
+    type Output1 = u16;
+    type Output2 = u16;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let (rest, valves) = separated_list1(newline, valve)(data).unwrap();
+        assert_eq!(rest.trim(), "");
+
+        let mut vs = Valves::new();
 
-    let opt = generate_matrix(&v);
+        for valve in valves {
+            vs.insert(valve);
+        }
+
+        vs
+    }
 
-    opt[29][v.idx_by_name("AA").unwrap()][mm - 1]
+    fn part1(v: Self::Input<'_>) -> Self::Output1 {
+        let mm: usize = 1 << v.positive_flow().count();
+
+        let opt = generate_matrix(&v);
+
+        opt[29][v.idx_by_name("AA").unwrap()][mm - 1]
+    }
+
+    fn part2(v: Self::Input<'_>) -> Self::Output2 {
+        let mm: usize = 1 << v.positive_flow().count();
+        let opt = generate_matrix(&v);
+
+        let mut best = 0;
+        for x in 0..mm / 2 {
+            let y = mm - 1 - x;
+            best = best.max(
+                opt[25][v.idx_by_name("AA").unwrap()][x] + opt[25][v.idx_by_name("AA").unwrap()][y],
+            );
+        }
+        best
+    }
 }
 
 fn generate_matrix(v: &Valves) -> Vec<Vec<Vec<u16>>> {
@@ -53,33 +89,6 @@ fn generate_matrix(v: &Valves) -> Vec<Vec<Vec<u16>>> {
     opt
 }
 
-pub fn part2(v: Valves) -> u16 {
-    let mm: usize = 1 << v.positive_flow().count();
-    let opt = generate_matrix(&v);
-
-    let mut best = 0;
-    for x in 0..mm / 2 {
-        let y = mm - 1 - x;
-        best = best.max(
-            opt[25][v.idx_by_name("AA").unwrap()][x] + opt[25][v.idx_by_name("AA").unwrap()][y],
-        );
-    }
-    best
-}
-
-pub fn parse<'a>(data: &'a str) -> Valves<'a> {
-    let (rest, valves) = separated_list1(newline, valve)(data).unwrap();
-    assert_eq!(rest.trim(), "");
-
-    let mut vs = Valves::new();
-
-    for valve in valves {
-        vs.insert(valve);
-    }
-
-    vs
-}
-
 fn valve(s: &str) -> IResult<&str, Valve> {
     let (rest, (_, name, _, flow_rate, _, connections)) = tuple((
         tag("Valve "),
@@ -186,8 +195,8 @@ Valve GG has flow rate=0; tunnels lead to valves FF, HH
 Valve HH has flow rate=22; tunnel leads to valve GG
 Valve II has flow rate=0; tunnels lead to valves AA, JJ
 Valve JJ has flow rate=21; tunnel leads to valve II"#;
-        let parsed = parse(input);
-        let res = part1(parsed);
+        let parsed = Day::parse(input);
+        let res = Day::part1(parsed);
         assert_eq!(1651, res)
     }
 
@@ -1,29 +1,51 @@
 use std::{collections::VecDeque, fmt};
+use util::Solution;
 
 const MAX_BLOCKS: usize = 20;
 
-pub fn part1(jets: impl Iterator<Item = Direction> + Clone) -> usize {
-    let mut state = State::new(jets.cycle(), Shape::generate().cycle());
-    state.run(2022);
-    state.height()
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = std::vec::IntoIter<Direction>;
+    type Output1 = usize;
+    type Output2 = usize;
 
-pub fn part2(jets: impl Iterator<Item = Direction> + Clone) -> usize {
-    let mut state = State::new(jets.cycle(), Shape::generate().cycle());
+    fn parse(data: &str) -> Self::Input<'_> {
+        data.trim()
+            .chars()
+            .map(|c| match c {
+                '<' => &Direction::Left,
+                '>' => &Direction::Right,
+                x => unimplemented!("Direction: '{}' unknown", x),
+            })
+            .cloned()
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
 
-    let (cycle_size, _val, start) = floyd(state.run(1), |mut last_state| last_state.run(1));
+    fn part1(jets: Self::Input<'_>) -> Self::Output1 {
+        let mut state = State::new(jets.cycle(), Shape::generate().cycle());
+        state.run(2022);
+        state.height()
+    }
 
-    let mut remaining_cycles = 1000000000000 - start;
-    let init_state = state.clone().run(start);
-    let one_cycle = init_state.clone().run(cycle_size);
-    let cycle_height = one_cycle.height() - init_state.height();
-    let repeated_cycles = remaining_cycles / cycle_size;
-    remaining_cycles -= repeated_cycles * cycle_size;
-    let bonus_cycle = init_state.clone().run(remaining_cycles);
-    let bonus_height = bonus_cycle.height() - init_state.height();
-    let height = init_state.height() + (repeated_cycles * cycle_height) + bonus_height;
+    fn part2(jets: Self::Input<'_>) -> Self::Output2 {
+        let mut state = State::new(jets.cycle(), Shape::generate().cycle());
 
-    height - 1
+        let (cycle_size, _val, start) = floyd(state.run(1), |mut last_state| last_state.run(1));
+
+        let mut remaining_cycles = 1000000000000 - start;
+        let init_state = state.clone().run(start);
+        let one_cycle = init_state.clone().run(cycle_size);
+        let cycle_height = one_cycle.height() - init_state.height();
+        let repeated_cycles = remaining_cycles / cycle_size;
+        remaining_cycles -= repeated_cycles * cycle_size;
+        let bonus_cycle = init_state.clone().run(remaining_cycles);
+        let bonus_height = bonus_cycle.height() - init_state.height();
+        let height = init_state.height() + (repeated_cycles * cycle_height) + bonus_height;
+
+        height - 1
+    }
 }
 
 pub fn floyd<T, FS>(start: T, successor: FS) -> (usize, T, usize)
@@ -49,17 +71,6 @@ where
     (lam, tortoise, mu)
 }
 
-pub fn parse<'a>(data: &'a str) -> impl Iterator<Item = Direction> + 'a + Clone {
-    data.trim()
-        .chars()
-        .map(|c| match c {
-            '<' => &Direction::Left,
-            '>' => &Direction::Right,
-            x => unimplemented!("Direction: '{}' unknown", x),
-        })
-        .cloned()
-}
-
 #[derive(Clone)]
 struct State<J, R>
 where
@@ -367,16 +378,16 @@ mod tests {
     #[test]
     fn test1() {
         let input = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>\n";
-        let parsed = parse(input);
-        let res = part1(parsed);
+        let parsed = Day::parse(input);
+        let res = Day::part1(parsed);
         assert_eq!(3068, res)
     }
 
     #[test]
     fn test2() {
         let input = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>\n";
-        let parsed = parse(input);
-        let res = part2(parsed);
+        let parsed = Day::parse(input);
+        let res = Day::part2(parsed);
         assert_eq!(1514285714288, res)
     }
 }
@@ -1,55 +1,64 @@
 use std::collections::{HashSet, VecDeque};
+use util::Solution;
 
 use itertools::Itertools;
 
-pub fn part1(v: Volcano) -> usize {
-    v.lava.iter().fold(0, |acc, c| {
-        acc + c.neighbors().iter().filter(|n| !v.lava.contains(n)).count()
-    })
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Volcano;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        data.lines()
+            .map(|l| {
+                Cube::new(
+                    l.split(",")
+                        .map(|c| c.parse().unwrap())
+                        .collect_tuple()
+                        .unwrap(),
+                )
+            })
+            .collect::<Volcano>()
+    }
 
-pub fn part2(v: Volcano) -> usize {
-    let mut outside: HashSet<Cube> = HashSet::new();
-    let mut q: VecDeque<Cube> = VecDeque::new();
-    q.push_front(Cube::new((v.min_x - 1, v.min_y - 1, v.min_z - 1)));
-
-    while let Some(cube) = q.pop_back() {
-        for n in cube.neighbors() {
-            if !((v.min_x - 1)..=(v.max_x + 1)).contains(&n.x)
-                || !((v.min_y - 1)..=(v.max_y + 1)).contains(&n.y)
-                || !((v.min_z - 1)..=(v.max_z + 1)).contains(&n.z)
-            {
-                continue;
-            }
+    fn part1(v: Self::Input<'_>) -> Self::Output1 {
+        v.lava.iter().fold(0, |acc, c| {
+            acc + c.neighbors().iter().filter(|n| !v.lava.contains(n)).count()
+        })
+    }
 
-            if !v.lava.contains(&cube) {
-                if outside.insert(n.clone()) {
-                    q.push_back(n);
+    fn part2(v: Self::Input<'_>) -> Self::Output2 {
+        let mut outside: HashSet<Cube> = HashSet::new();
+        let mut q: VecDeque<Cube> = VecDeque::new();
+        q.push_front(Cube::new((v.min_x - 1, v.min_y - 1, v.min_z - 1)));
+
+        while let Some(cube) = q.pop_back() {
+            for n in cube.neighbors() {
+                if !((v.min_x - 1)..=(v.max_x + 1)).contains(&n.x)
+                    || !((v.min_y - 1)..=(v.max_y + 1)).contains(&n.y)
+                    || !((v.min_z - 1)..=(v.max_z + 1)).contains(&n.z)
+                {
+                    continue;
+                }
+
+                if !v.lava.contains(&cube) {
+                    if outside.insert(n.clone()) {
+                        q.push_back(n);
+                    }
                 }
             }
         }
-    }
 
-    v.lava.iter().fold(0, |acc, c| {
-        acc + c
-            .neighbors()
-            .iter()
-            .filter(|n| !v.lava.contains(n) && outside.contains(n))
-            .count()
-    })
-}
-
-pub fn parse<'a>(data: &'a str) -> Volcano {
-    data.lines()
-        .map(|l| {
-            Cube::new(
-                l.split(",")
-                    .map(|c| c.parse().unwrap())
-                    .collect_tuple()
-                    .unwrap(),
-            )
+        v.lava.iter().fold(0, |acc, c| {
+            acc + c
+                .neighbors()
+                .iter()
+                .filter(|n| !v.lava.contains(n) && outside.contains(n))
+                .count()
         })
-        .collect::<Volcano>()
+    }
 }
 
 pub fn dfs<N, FN, IN, FS>(start: N, mut successors: FN, mut success: FS) -> Option<Vec<N>>
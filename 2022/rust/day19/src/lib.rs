@@ -2,6 +2,7 @@ use std::{
     collections::{HashSet, VecDeque},
     fmt,
 };
+use util::Solution;
 
 use nom::{
     bytes::complete::tag,
@@ -13,41 +14,49 @@ use nom::{
 
 use rayon::prelude::*;
 
-pub fn part1(blueprints: Vec<Blueprint>) -> i32 {
-    let states: Vec<State> = blueprints
-        .into_iter()
-        .map(|blueprint| State::new(blueprint, 24))
-        .collect();
-
-    states
-        .into_par_iter()
-        .map(|state| {
-            BestSteps::new(state)
-                .map(|s| s.quality_level())
-                .max()
-                .unwrap()
-        })
-        .sum()
-}
+pub struct Day;
 
-pub fn part2(blueprints: Vec<Blueprint>) -> i32 {
-    let states: Vec<State> = blueprints
-        .into_iter()
-        .map(|blueprint| State::new(blueprint, 32))
-        .collect();
-
-    states
-        .into_par_iter()
-        .take(3)
-        .map(|state| BestSteps::new(state).map(|s| s.geode).max().unwrap())
-        .product()
-}
+impl Solution for Day {
+    type Input<'a> = Vec<Blueprint>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let (rest, blueprints) = blueprints(data).unwrap();
+        assert_eq!("", rest.trim());
 
-pub fn parse<'a>(data: &'a str) -> Vec<Blueprint> {
-    let (rest, blueprints) = blueprints(data).unwrap();
-    assert_eq!("", rest.trim());
+        blueprints
+    }
+
+    fn part1(blueprints: Self::Input<'_>) -> Self::Output1 {
+        let states: Vec<State> = blueprints
+            .into_iter()
+            .map(|blueprint| State::new(blueprint, 24))
+            .collect();
+
+        states
+            .into_par_iter()
+            .map(|state| {
+                BestSteps::new(state)
+                    .map(|s| s.quality_level())
+                    .max()
+                    .unwrap()
+            })
+            .sum()
+    }
 
-    blueprints
+    fn part2(blueprints: Self::Input<'_>) -> Self::Output2 {
+        let states: Vec<State> = blueprints
+            .into_iter()
+            .map(|blueprint| State::new(blueprint, 32))
+            .collect();
+
+        states
+            .into_par_iter()
+            .take(3)
+            .map(|state| BestSteps::new(state).map(|s| s.geode).max().unwrap())
+            .product()
+    }
 }
 
 fn blueprints(s: &str) -> IResult<&str, Vec<Blueprint>> {
@@ -416,8 +425,8 @@ Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsid
 
     #[test]
     fn test1() {
-        let parsed = parse(INPUT);
-        let res = part1(parsed);
+        let parsed = Day::parse(INPUT);
+        let res = Day::part1(parsed);
         assert_eq!(33, res)
     }
 }
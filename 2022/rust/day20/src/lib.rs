@@ -1,9 +1,23 @@
-pub fn part1(state: Vec<i64>) -> i64 {
-    decode(state, 1, 1)
-}
+use util::Solution;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<i64>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        data.lines().map(|f| f.parse().unwrap()).collect()
+    }
 
-pub fn part2(state: Vec<i64>) -> i64 {
-    decode(state, 811589153, 10)
+    fn part1(state: Self::Input<'_>) -> Self::Output1 {
+        decode(state, 1, 1)
+    }
+
+    fn part2(state: Self::Input<'_>) -> Self::Output2 {
+        decode(state, 811589153, 10)
+    }
 }
 
 fn decode(state: Vec<i64>, key: i64, times: usize) -> i64 {
@@ -19,10 +33,7 @@ fn decode(state: Vec<i64>, key: i64, times: usize) -> i64 {
                 continue;
             }
 
-            let pos = q
-                .iter()
-                .position(|x| x.0 == oidx.try_into().unwrap())
-                .unwrap();
+            let pos = q.iter().position(|x| x.0 == oidx).unwrap();
 
             let val = q.remove(pos);
 
@@ -42,10 +53,6 @@ fn decode(state: Vec<i64>, key: i64, times: usize) -> i64 {
     a + b + c
 }
 
-pub fn parse<'a>(data: &'a str) -> Vec<i64> {
-    data.lines().map(|f| f.parse().unwrap()).collect()
-}
-
 #[cfg(test)]
 mod tests {
 
@@ -61,8 +68,8 @@ mod tests {
 
     #[test]
     fn test1() {
-        let parsed = parse(INPUT);
-        let res = part1(parsed);
+        let parsed = Day::parse(INPUT);
+        let res = Day::part1(parsed);
         assert_eq!(3, res)
     }
 }
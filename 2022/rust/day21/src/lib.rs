@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use util::Solution;
 
 use nom::{
     branch::alt,
@@ -10,8 +11,193 @@ use nom::{
     IResult,
 };
 
-pub fn part1(t: Tree) -> i64 {
-    math(&t, "root")
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Tree;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let (rest, monkeys) = separated_list1(newline, monkey)(data).unwrap();
+
+        assert_eq!("", rest.trim());
+
+        let mut t = Tree::new();
+
+        for monkey in monkeys {
+            t.insert(monkey);
+        }
+
+        t
+    }
+
+    fn part1(t: Self::Input<'_>) -> Self::Output1 {
+        math(&t, "root")
+    }
+
+    fn part2(mut t: Self::Input<'_>) -> Self::Output2 {
+        let mut cloned = t.clone();
+        let mut iter = cloned.arena.values().into_iter().filter(not_humn_leaf);
+
+        while let Some(m) = iter.next() {
+            let mut parent: &mut Monkey = t.parent_mut(&m.name).unwrap();
+
+            let val = if let Op::Value(Val::Num(x)) = m.op {
+                x
+            } else {
+                unreachable!()
+            };
+
+            match &parent.op {
+                Op::Add(Val::Name(x), Val::Name(y)) if *x == m.name => {
+                    parent.op = Op::Add(Val::Num(val), Val::Name(y.clone()));
+                    t.remove(&m.name);
+                }
+                Op::Add(Val::Name(x), Val::Name(y)) if y.clone() == m.name => {
+                    parent.op = Op::Add(Val::Name(x.clone()), Val::Num(val));
+                    t.remove(&m.name);
+                }
+                Op::Add(Val::Name(x), Val::Num(y)) if x.clone() == m.name => {
+                    parent.op = Op::Value(Val::Num(val + y));
+                    t.remove(&m.name);
+                }
+                Op::Add(Val::Num(x), Val::Name(y)) if y.clone() == m.name => {
+                    parent.op = Op::Value(Val::Num(x + val));
+                    t.remove(&m.name);
+                }
+                Op::Subtract(Val::Name(x), Val::Name(y)) if x.clone() == m.name => {
+                    parent.op = Op::Subtract(Val::Num(val), Val::Name(y.clone()));
+                    t.remove(&m.name);
+                }
+                Op::Subtract(Val::Name(x), Val::Name(y)) if y.clone() == m.name => {
+                    parent.op = Op::Subtract(Val::Name(x.clone()), Val::Num(val));
+                    t.remove(&m.name);
+                }
+                Op::Subtract(Val::Name(x), Val::Num(y)) if x.clone() == m.name => {
+                    parent.op = Op::Value(Val::Num(val - y));
+                    t.remove(&m.name);
+                }
+                Op::Subtract(Val::Num(x), Val::Name(y)) if y.clone() == m.name => {
+                    parent.op = Op::Value(Val::Num(x - val));
+                    t.remove(&m.name);
+                }
+                Op::Multiply(Val::Name(x), Val::Name(y)) if x.clone() == m.name => {
+                    parent.op = Op::Multiply(Val::Num(val), Val::Name(y.clone()));
+                    t.remove(&m.name);
+                }
+                Op::Multiply(Val::Name(x), Val::Name(y)) if y.clone() == m.name => {
+                    parent.op = Op::Multiply(Val::Name(x.clone()), Val::Num(val));
+                    t.remove(&m.name);
+                }
+                Op::Multiply(Val::Name(x), Val::Num(y)) if x.clone() == m.name => {
+                    parent.op = Op::Value(Val::Num(val * y));
+                    t.remove(&m.name);
+                }
+                Op::Multiply(Val::Num(x), Val::Name(y)) if y.clone() == m.name => {
+                    parent.op = Op::Value(Val::Num(x * val));
+                    t.remove(&m.name);
+                }
+                Op::Divide(Val::Name(x), Val::Name(y)) if x.clone() == m.name => {
+                    parent.op = Op::Divide(Val::Num(val), Val::Name(y.clone()));
+                    t.remove(&m.name);
+                }
+                Op::Divide(Val::Name(x), Val::Name(y)) if y.clone() == m.name => {
+                    parent.op = Op::Divide(Val::Name(x.clone()), Val::Num(val));
+                    t.remove(&m.name);
+                }
+                Op::Divide(Val::Name(x), Val::Num(y)) if x.clone() == m.name => {
+                    parent.op = Op::Value(Val::Num(val / y));
+                    t.remove(&m.name);
+                }
+                Op::Divide(Val::Num(x), Val::Name(y)) if y.clone() == m.name => {
+                    parent.op = Op::Value(Val::Num(x / val));
+                    t.remove(&m.name);
+                }
+                _ => unreachable!(),
+            }
+
+            cloned = t.clone();
+            iter = cloned.arena.values().into_iter().filter(not_humn_leaf);
+        }
+
+        let c = t.get("root").unwrap();
+        let (target, new_root) = match &c.op {
+            Op::Add(Val::Num(x), Val::Name(y)) => (x, y),
+            Op::Add(Val::Name(x), Val::Num(y)) => (y, x),
+            Op::Subtract(Val::Num(x), Val::Name(y)) => (x, y),
+            Op::Subtract(Val::Name(x), Val::Num(y)) => (y, x),
+            Op::Multiply(Val::Num(x), Val::Name(y)) => (x, y),
+            Op::Multiply(Val::Name(x), Val::Num(y)) => (y, x),
+            Op::Divide(Val::Num(x), Val::Name(y)) => (x, y),
+            Op::Divide(Val::Name(x), Val::Num(y)) => (y, x),
+            _ => unreachable!(),
+        };
+
+        let mut nr = new_root.to_owned();
+        let mut acc = target.to_owned();
+
+        'l: loop {
+            match t.get(&nr).unwrap().op.clone() {
+                // # X + y = Z
+                // # y = Z - X
+                Op::Add(Val::Num(x), Val::Name(y)) => {
+                    acc = acc - x;
+                    nr = y;
+                }
+                // # x + Y = Z
+                // # x = Z - Y
+                Op::Add(Val::Name(x), Val::Num(y)) => {
+                    acc = acc - y;
+                    nr = x;
+                }
+                // # X - y = Z
+                // # y = X - Z
+                Op::Subtract(Val::Num(x), Val::Name(y)) => {
+                    acc = x - acc;
+                    nr = y;
+                }
+                // # x - Y = Z
+                // # x = Z + Y
+                Op::Subtract(Val::Name(x), Val::Num(y)) => {
+                    acc = y + acc;
+                    nr = x;
+                }
+                // # X * y = Z
+                // # y = Z / x
+                Op::Multiply(Val::Num(x), Val::Name(y)) => {
+                    acc = acc / x;
+                    nr = y;
+                }
+                // # x * Y = Z
+                // # x = Z / y
+                Op::Multiply(Val::Name(x), Val::Num(y)) => {
+                    acc = acc / y;
+                    nr = x;
+                }
+                // # X / y = Z
+                // # X = y * Z
+                // # X / Z = y
+                Op::Divide(Val::Num(x), Val::Name(y)) => {
+                    acc = x / acc;
+                    nr = y;
+                }
+                // # x / Y = Z
+                // # x = Z * Y
+                Op::Divide(Val::Name(x), Val::Num(y)) => {
+                    acc = y * acc;
+                    nr = x;
+                }
+                _ => unreachable!(),
+            }
+
+            if nr == "humn" {
+                break;
+            }
+        }
+
+        acc
+    }
 }
 
 pub fn math(t: &Tree, m: &str) -> i64 {
@@ -42,183 +228,6 @@ fn not_humn_leaf<'a>(m: &'a &Monkey) -> bool {
     not_humn(m) && leaf(m)
 }
 
-pub fn part2(mut t: Tree) -> i64 {
-    let mut cloned = t.clone();
-    let mut iter = cloned.arena.values().into_iter().filter(not_humn_leaf);
-
-    while let Some(m) = iter.next() {
-        let mut parent: &mut Monkey = t.parent_mut(&m.name).unwrap();
-
-        let val = if let Op::Value(Val::Num(x)) = m.op {
-            x
-        } else {
-            unreachable!()
-        };
-
-        match &parent.op {
-            Op::Add(Val::Name(x), Val::Name(y)) if *x == m.name => {
-                parent.op = Op::Add(Val::Num(val), Val::Name(y.clone()));
-                t.remove(&m.name);
-            }
-            Op::Add(Val::Name(x), Val::Name(y)) if y.clone() == m.name => {
-                parent.op = Op::Add(Val::Name(x.clone()), Val::Num(val));
-                t.remove(&m.name);
-            }
-            Op::Add(Val::Name(x), Val::Num(y)) if x.clone() == m.name => {
-                parent.op = Op::Value(Val::Num(val + y));
-                t.remove(&m.name);
-            }
-            Op::Add(Val::Num(x), Val::Name(y)) if y.clone() == m.name => {
-                parent.op = Op::Value(Val::Num(x + val));
-                t.remove(&m.name);
-            }
-            Op::Subtract(Val::Name(x), Val::Name(y)) if x.clone() == m.name => {
-                parent.op = Op::Subtract(Val::Num(val), Val::Name(y.clone()));
-                t.remove(&m.name);
-            }
-            Op::Subtract(Val::Name(x), Val::Name(y)) if y.clone() == m.name => {
-                parent.op = Op::Subtract(Val::Name(x.clone()), Val::Num(val));
-                t.remove(&m.name);
-            }
-            Op::Subtract(Val::Name(x), Val::Num(y)) if x.clone() == m.name => {
-                parent.op = Op::Value(Val::Num(val - y));
-                t.remove(&m.name);
-            }
-            Op::Subtract(Val::Num(x), Val::Name(y)) if y.clone() == m.name => {
-                parent.op = Op::Value(Val::Num(x - val));
-                t.remove(&m.name);
-            }
-            Op::Multiply(Val::Name(x), Val::Name(y)) if x.clone() == m.name => {
-                parent.op = Op::Multiply(Val::Num(val), Val::Name(y.clone()));
-                t.remove(&m.name);
-            }
-            Op::Multiply(Val::Name(x), Val::Name(y)) if y.clone() == m.name => {
-                parent.op = Op::Multiply(Val::Name(x.clone()), Val::Num(val));
-                t.remove(&m.name);
-            }
-            Op::Multiply(Val::Name(x), Val::Num(y)) if x.clone() == m.name => {
-                parent.op = Op::Value(Val::Num(val * y));
-                t.remove(&m.name);
-            }
-            Op::Multiply(Val::Num(x), Val::Name(y)) if y.clone() == m.name => {
-                parent.op = Op::Value(Val::Num(x * val));
-                t.remove(&m.name);
-            }
-            Op::Divide(Val::Name(x), Val::Name(y)) if x.clone() == m.name => {
-                parent.op = Op::Divide(Val::Num(val), Val::Name(y.clone()));
-                t.remove(&m.name);
-            }
-            Op::Divide(Val::Name(x), Val::Name(y)) if y.clone() == m.name => {
-                parent.op = Op::Divide(Val::Name(x.clone()), Val::Num(val));
-                t.remove(&m.name);
-            }
-            Op::Divide(Val::Name(x), Val::Num(y)) if x.clone() == m.name => {
-                parent.op = Op::Value(Val::Num(val / y));
-                t.remove(&m.name);
-            }
-            Op::Divide(Val::Num(x), Val::Name(y)) if y.clone() == m.name => {
-                parent.op = Op::Value(Val::Num(x / val));
-                t.remove(&m.name);
-            }
-            _ => unreachable!(),
-        }
-
-        cloned = t.clone();
-        iter = cloned.arena.values().into_iter().filter(not_humn_leaf);
-    }
-
-    let c = t.get("root").unwrap();
-    let (target, new_root) = match &c.op {
-        Op::Add(Val::Num(x), Val::Name(y)) => (x, y),
-        Op::Add(Val::Name(x), Val::Num(y)) => (y, x),
-        Op::Subtract(Val::Num(x), Val::Name(y)) => (x, y),
-        Op::Subtract(Val::Name(x), Val::Num(y)) => (y, x),
-        Op::Multiply(Val::Num(x), Val::Name(y)) => (x, y),
-        Op::Multiply(Val::Name(x), Val::Num(y)) => (y, x),
-        Op::Divide(Val::Num(x), Val::Name(y)) => (x, y),
-        Op::Divide(Val::Name(x), Val::Num(y)) => (y, x),
-        _ => unreachable!(),
-    };
-
-    let mut nr = new_root.to_owned();
-    let mut acc = target.to_owned();
-
-    'l: loop {
-        match t.get(&nr).unwrap().op.clone() {
-            // # X + y = Z
-            // # y = Z - X
-            Op::Add(Val::Num(x), Val::Name(y)) => {
-                acc = acc - x;
-                nr = y;
-            }
-            // # x + Y = Z
-            // # x = Z - Y
-            Op::Add(Val::Name(x), Val::Num(y)) => {
-                acc = acc - y;
-                nr = x;
-            }
-            // # X - y = Z
-            // # y = X - Z
-            Op::Subtract(Val::Num(x), Val::Name(y)) => {
-                acc = x - acc;
-                nr = y;
-            }
-            // # x - Y = Z
-            // # x = Z + Y
-            Op::Subtract(Val::Name(x), Val::Num(y)) => {
-                acc = y + acc;
-                nr = x;
-            }
-            // # X * y = Z
-            // # y = Z / x
-            Op::Multiply(Val::Num(x), Val::Name(y)) => {
-                acc = acc / x;
-                nr = y;
-            }
-            // # x * Y = Z
-            // # x = Z / y
-            Op::Multiply(Val::Name(x), Val::Num(y)) => {
-                acc = acc / y;
-                nr = x;
-            }
-            // # X / y = Z
-            // # X = y * Z
-            // # X / Z = y
-            Op::Divide(Val::Num(x), Val::Name(y)) => {
-                acc = x / acc;
-                nr = y;
-            }
-            // # x / Y = Z
-            // # x = Z * Y
-            Op::Divide(Val::Name(x), Val::Num(y)) => {
-                acc = y * acc;
-                nr = x;
-            }
-            _ => unreachable!(),
-        }
-
-        if nr == "humn" {
-            break;
-        }
-    }
-
-    acc
-}
-
-pub fn parse<'a>(data: &'a str) -> Tree {
-    let (rest, monkeys) = separated_list1(newline, monkey)(data).unwrap();
-
-    assert_eq!("", rest.trim());
-
-    let mut t = Tree::new();
-
-    for monkey in monkeys {
-        t.insert(monkey);
-    }
-
-    t
-}
-
 #[derive(Clone, Debug)]
 pub struct Tree {
     arena: HashMap<String, Monkey>,
@@ -408,15 +417,15 @@ hmdt: 32"#;
 
     #[test]
     fn test1() {
-        let parsed = parse(INPUT);
-        let res = part1(parsed);
+        let parsed = Day::parse(INPUT);
+        let res = Day::part1(parsed);
         assert_eq!(152, res)
     }
 
     #[test]
     fn test2() {
-        let parsed = parse(INPUT);
-        let res = part2(parsed);
+        let parsed = Day::parse(INPUT);
+        let res = Day::part2(parsed);
         assert_eq!(301, res)
     }
 }
@@ -2,6 +2,7 @@ use std::{
     collections::{HashMap, VecDeque},
     fmt,
 };
+use util::Solution;
 
 use nom::{
     branch::alt,
@@ -12,109 +13,117 @@ use nom::{
     IResult,
 };
 
-pub fn part1(mut state: State) -> usize {
-    for instruction in state.instructions.clone() {
-        match instruction {
-            Instruction::Move(x) => {
-                for _ in 0..x {
-                    state.step();
-                }
-            }
-            Instruction::Left => state.facing = state.facing.turn(Instruction::Left),
-            Instruction::Right => state.facing = state.facing.turn(Instruction::Right),
-        }
-    }
-
-    1000 * state.pos.y + 4 * state.pos.x + state.facing.value()
-}
-
-pub fn part2(mut state: State) -> usize {
-    for instruction in state.instructions.clone() {
-        match instruction {
-            Instruction::Move(x) => {
-                for _ in 0..x {
-                    state.step_folded();
-                }
-            }
-            Instruction::Left => state.facing = state.facing.turn(Instruction::Left),
-            Instruction::Right => state.facing = state.facing.turn(Instruction::Right),
-        }
-    }
+pub struct Day;
 
-    1000 * state.pos.y + 4 * state.pos.x + state.facing.value()
-}
+impl Solution for Day {
+    type Input<'a> = State;
+    type Output1 = usize;
+    type Output2 = usize;
 
-pub fn parse<'a>(data: &'a str) -> State {
-    let mut state = State::new();
-    let mut parse_moves = false;
-    let mut find_start = true;
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let mut state = State::new();
+        let mut parse_moves = false;
+        let mut find_start = true;
 
-    for (yidx, line) in data.lines().enumerate() {
-        if parse_moves {
-            let (_, instructions) = many1(alt((mv, left, right)))(line).unwrap();
-            state.set_instructions(instructions);
-            break;
-        }
+        for (yidx, line) in data.lines().enumerate() {
+            if parse_moves {
+                let (_, instructions) = many1(alt((mv, left, right)))(line).unwrap();
+                state.set_instructions(instructions);
+                break;
+            }
 
-        for (xidx, char) in line.chars().enumerate() {
-            if char == '.' {
-                if find_start && yidx == 0 {
-                    state.pos = Point::new(xidx + 1, yidx + 1);
-                    find_start = false;
+            for (xidx, char) in line.chars().enumerate() {
+                if char == '.' {
+                    if find_start && yidx == 0 {
+                        state.pos = Point::new(xidx + 1, yidx + 1);
+                        find_start = false;
+                    }
+                    let point = Point::new(xidx + 1, yidx + 1);
+                    state.add_point(point, Tile::Clear);
+                }
+                if char == '#' {
+                    let point = Point::new(xidx + 1, yidx + 1);
+                    state.add_point(point, Tile::Wall);
                 }
-                let point = Point::new(xidx + 1, yidx + 1);
-                state.add_point(point, Tile::Clear);
             }
-            if char == '#' {
-                let point = Point::new(xidx + 1, yidx + 1);
-                state.add_point(point, Tile::Wall);
+
+            if line.trim() == "" {
+                parse_moves = true;
             }
         }
 
-        if line.trim() == "" {
-            parse_moves = true;
-        }
-    }
+        state.cube_size = ((state.map.len() / 6) as f64).sqrt() as usize;
 
-    state.cube_size = ((state.map.len() / 6) as f64).sqrt() as usize;
+        let first_face_min_x = (1..=state.max_x)
+            .find(|x| state.map.get(&Point::new(*x, 1)).is_some())
+            .unwrap();
 
-    let first_face_min_x = (1..=state.max_x)
-        .find(|x| state.map.get(&Point::new(*x, 1)).is_some())
-        .unwrap();
+        let front_face = Face::new(FaceName::Front, first_face_min_x, 1, state.cube_size);
 
-    let front_face = Face::new(FaceName::Front, first_face_min_x, 1, state.cube_size);
+        let mut q: VecDeque<(Face, FaceName)> = VecDeque::new();
 
-    let mut q: VecDeque<(Face, FaceName)> = VecDeque::new();
+        state.faces.insert(FaceName::Front, front_face.clone());
+        q.push_front((front_face.clone(), FaceName::Bottom));
 
-    state.faces.insert(FaceName::Front, front_face.clone());
-    q.push_front((front_face.clone(), FaceName::Bottom));
+        while let Some((face, prev_face)) = q.pop_front() {
+            if state.faces.len() == 6 {
+                break;
+            }
 
-    while let Some((face, prev_face)) = q.pop_front() {
-        if state.faces.len() == 6 {
-            break;
+            for (name, top_left) in face.maybe_adjacent(prev_face) {
+                if state.faces.contains_key(&name) {
+                    continue;
+                }
+                if state
+                    .faces
+                    .values()
+                    .map(|f| f.top_left.clone())
+                    .any(|tl| tl == top_left)
+                {
+                    continue;
+                }
+                if state.map.get(&top_left).is_some() {
+                    let new_face = Face::new(name.clone(), top_left.x, top_left.y, state.cube_size);
+                    state.faces.insert(name, new_face.clone());
+                    q.push_front((new_face, face.name.clone()));
+                }
+            }
         }
 
-        for (name, top_left) in face.maybe_adjacent(prev_face) {
-            if state.faces.contains_key(&name) {
-                continue;
-            }
-            if state
-                .faces
-                .values()
-                .map(|f| f.top_left.clone())
-                .any(|tl| tl == top_left)
-            {
-                continue;
-            }
-            if state.map.get(&top_left).is_some() {
-                let new_face = Face::new(name.clone(), top_left.x, top_left.y, state.cube_size);
-                state.faces.insert(name, new_face.clone());
-                q.push_front((new_face, face.name.clone()));
+        state
+    }
+
+    fn part1(mut state: Self::Input<'_>) -> Self::Output1 {
+        for instruction in state.instructions.clone() {
+            match instruction {
+                Instruction::Move(x) => {
+                    for _ in 0..x {
+                        state.step();
+                    }
+                }
+                Instruction::Left => state.facing = state.facing.turn(Instruction::Left),
+                Instruction::Right => state.facing = state.facing.turn(Instruction::Right),
             }
         }
+
+        1000 * state.pos.y + 4 * state.pos.x + state.facing.value()
     }
 
-    state
+    fn part2(mut state: Self::Input<'_>) -> Self::Output2 {
+        for instruction in state.instructions.clone() {
+            match instruction {
+                Instruction::Move(x) => {
+                    for _ in 0..x {
+                        state.step_folded();
+                    }
+                }
+                Instruction::Left => state.facing = state.facing.turn(Instruction::Left),
+                Instruction::Right => state.facing = state.facing.turn(Instruction::Right),
+            }
+        }
+
+        1000 * state.pos.y + 4 * state.pos.x + state.facing.value()
+    }
 }
 
 fn mv(s: &str) -> IResult<&str, Instruction> {
@@ -811,34 +820,34 @@ mod tests {
 
     #[test]
     fn test1() {
-        let parsed = parse(INPUT);
-        let res = part1(parsed);
+        let parsed = Day::parse(INPUT);
+        let res = Day::part1(parsed);
         assert_eq!(6032, res)
     }
 
     #[test]
     fn test2() {
-        let parsed = parse(INPUT);
-        let res = part2(parsed);
+        let parsed = Day::parse(INPUT);
+        let res = Day::part2(parsed);
         assert_eq!(5031, res)
     }
 
     #[test]
     fn point_in_cube() {
-        let state = parse(SMALL_REAL);
+        let state = Day::parse(SMALL_REAL);
         assert_eq!(Point::new(7, 1), state.point_in_cube(2, 1, 2, 1));
         assert_eq!(Point::new(1, 12), state.point_in_cube(1, 3, 1, 2));
     }
 
     #[test]
     fn small_real() {
-        let state = parse(SMALL_REAL);
+        let state = Day::parse(SMALL_REAL);
         assert_eq!(5, state.cube_size);
     }
 
     #[test]
     fn internal_moves() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(2, 1, 3, 3);
         state.facing = North;
         state.step_folded();
@@ -876,7 +885,7 @@ mod tests {
 
     #[test]
     fn front_north() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(2, 1, 2, 1);
         state.facing = North;
         println!("Before:\n{:?}", state);
@@ -888,7 +897,7 @@ mod tests {
 
     #[test]
     fn front_west() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(2, 1, 1, 2);
         state.facing = West;
         println!("Before:\n{:?}", state);
@@ -900,7 +909,7 @@ mod tests {
 
     #[test]
     fn front_east() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(2, 1, 5, 2);
         state.facing = East;
         println!("Before:\n{:?}", state);
@@ -912,7 +921,7 @@ mod tests {
 
     #[test]
     fn front_south() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(2, 1, 2, 5);
         state.facing = South;
         println!("Before:\n{:?}", state);
@@ -924,7 +933,7 @@ mod tests {
 
     #[test]
     fn bottom_west() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(2, 2, 1, 2);
         state.facing = West;
         println!("Before:\n{:?}", state);
@@ -936,7 +945,7 @@ mod tests {
 
     #[test]
     fn bottom_east() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(2, 2, 5, 2);
         state.facing = East;
         println!("Before:\n{:?}", state);
@@ -948,7 +957,7 @@ mod tests {
 
     #[test]
     fn bottom_north() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(2, 2, 2, 1);
         state.facing = North;
         println!("Before:\n{:?}", state);
@@ -960,7 +969,7 @@ mod tests {
 
     #[test]
     fn bottom_south() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(2, 2, 2, 5);
         state.facing = South;
         println!("Before:\n{:?}", state);
@@ -972,7 +981,7 @@ mod tests {
 
     #[test]
     fn back_east() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(2, 3, 5, 2);
         state.facing = East;
         println!("Before:\n{:?}", state);
@@ -984,7 +993,7 @@ mod tests {
 
     #[test]
     fn back_south() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(2, 3, 2, 5);
         state.facing = South;
         println!("Before:\n{:?}", state);
@@ -996,7 +1005,7 @@ mod tests {
 
     #[test]
     fn back_west() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(2, 3, 1, 2);
         state.facing = West;
         println!("Before:\n{:?}", state);
@@ -1008,7 +1017,7 @@ mod tests {
 
     #[test]
     fn back_north() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(2, 3, 2, 1);
         state.facing = North;
         println!("Before:\n{:?}", state);
@@ -1020,7 +1029,7 @@ mod tests {
 
     #[test]
     fn left_north() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(1, 3, 2, 1);
         state.facing = North;
         println!("Before:\n{:?}", state);
@@ -1032,7 +1041,7 @@ mod tests {
 
     #[test]
     fn left_south() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(1, 3, 2, 5);
         state.facing = South;
         println!("Before:\n{:?}", state);
@@ -1044,7 +1053,7 @@ mod tests {
 
     #[test]
     fn left_west() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(1, 3, 1, 2);
         state.facing = West;
         println!("Before:\n{:?}", state);
@@ -1056,7 +1065,7 @@ mod tests {
 
     #[test]
     fn left_east() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(1, 3, 5, 2);
         state.facing = East;
         println!("Before:\n{:?}", state);
@@ -1068,7 +1077,7 @@ mod tests {
 
     #[test]
     fn top_west() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(1, 4, 1, 2);
         state.facing = West;
         println!("Before:\n{:?}", state);
@@ -1080,7 +1089,7 @@ mod tests {
 
     #[test]
     fn top_east() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(1, 4, 5, 2);
         state.facing = East;
         println!("Before:\n{:?}", state);
@@ -1092,7 +1101,7 @@ mod tests {
 
     #[test]
     fn top_south() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(1, 4, 2, 5);
         state.facing = South;
         println!("Before:\n{:?}", state);
@@ -1104,7 +1113,7 @@ mod tests {
 
     #[test]
     fn top_north() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(1, 4, 2, 1);
         state.facing = North;
         println!("Before:\n{:?}", state);
@@ -1116,7 +1125,7 @@ mod tests {
 
     #[test]
     fn right_east() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(3, 1, 5, 2);
         state.facing = East;
         println!("Before:\n{:?}", state);
@@ -1128,7 +1137,7 @@ mod tests {
 
     #[test]
     fn right_west() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(3, 1, 1, 2);
         state.facing = West;
         println!("Before:\n{:?}", state);
@@ -1140,7 +1149,7 @@ mod tests {
 
     #[test]
     fn right_north() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(3, 1, 2, 1);
         state.facing = North;
         println!("Before:\n{:?}", state);
@@ -1152,7 +1161,7 @@ mod tests {
 
     #[test]
     fn right_south() {
-        let mut state = parse(SMALL_REAL);
+        let mut state = Day::parse(SMALL_REAL);
         state.pos = state.point_in_cube(3, 1, 2, 5);
         state.facing = South;
         println!("Before:\n{:?}", state);
@@ -1166,7 +1175,7 @@ mod tests {
     fn cube_point() {
         use FaceName::*;
 
-        let state = parse(SMALL_REAL);
+        let state = Day::parse(SMALL_REAL);
         assert_eq!(5, state.cube_size);
         assert_eq!(Point::new(2, 1), state.cube_point(&Front).unwrap());
         assert_eq!(Point::new(3, 1), state.cube_point(&Right).unwrap());
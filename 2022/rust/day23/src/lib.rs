@@ -2,31 +2,40 @@ use std::{
     collections::{HashMap, HashSet, VecDeque},
     fmt,
 };
+use util::Solution;
 
-pub fn part1(mut state: State) -> i32 {
-    for _ in 0..10 {
-        state.step();
-    }
-
-    (state.width() * state.height()) - (state.elves.len() as i32)
-}
+pub struct Day;
 
-pub fn part2(mut state: State) -> usize {
-    (1..=usize::MAX).find(|_| !state.step()).unwrap()
-}
+impl Solution for Day {
+    type Input<'a> = State;
+    type Output1 = i32;
+    type Output2 = usize;
 
-pub fn parse<'a>(data: &'a str) -> State {
-    let mut state = State::new();
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let mut state = State::new();
 
-    for (yidx, line) in data.lines().enumerate() {
-        for (xidx, char) in line.chars().enumerate() {
-            if char == '#' {
-                let point = Point::new(xidx as i32, yidx as i32);
-                state.add_elf(point);
+        for (yidx, line) in data.lines().enumerate() {
+            for (xidx, char) in line.chars().enumerate() {
+                if char == '#' {
+                    let point = Point::new(xidx as i32, yidx as i32);
+                    state.add_elf(point);
+                }
             }
         }
+        state
+    }
+
+    fn part1(mut state: Self::Input<'_>) -> Self::Output1 {
+        for _ in 0..10 {
+            state.step();
+        }
+
+        (state.width() * state.height()) - (state.elves.len() as i32)
+    }
+
+    fn part2(mut state: Self::Input<'_>) -> Self::Output2 {
+        (1..=usize::MAX).find(|_| !state.step()).unwrap()
     }
-    state
 }
 
 type NextMoveFn = fn(&HashSet<Point>, &Point) -> Option<Point>;
@@ -229,15 +238,15 @@ mod tests {
 
     #[test]
     fn test1() {
-        let parsed = parse(INPUT);
-        let res = part1(parsed);
+        let parsed = Day::parse(INPUT);
+        let res = Day::part1(parsed);
         assert_eq!(110, res)
     }
 
     #[test]
     fn test2() {
-        let parsed = parse(INPUT);
-        let res = part2(parsed);
+        let parsed = Day::parse(INPUT);
+        let res = Day::part2(parsed);
         assert_eq!(20, res)
     }
 }
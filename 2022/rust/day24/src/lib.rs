@@ -2,19 +2,63 @@ use std::{
     collections::{HashSet, VecDeque},
     fmt,
 };
+use util::Solution;
 
 use itertools::Itertools;
 
-pub fn part1(state: State) -> u16 {
-    bfs(state).step
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = State;
+    type Output1 = u16;
+    type Output2 = u16;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let height = data.lines().count() as u16;
+        let width = data.lines().nth(0).unwrap().len() as u16;
+
+        let mut state = State::new(width, height);
+
+        for (yidx, line) in data.lines().enumerate() {
+            for (xidx, char) in line.chars().enumerate() {
+                match char {
+                    '^' => state.up_blizzards[xidx - 1].set((yidx - 1).try_into().unwrap(), true),
+                    'v' => state.down_blizzards[xidx - 1].set((yidx - 1).try_into().unwrap(), true),
+                    '>' => {
+                        state.right_blizzards[yidx - 1].set((xidx - 1).try_into().unwrap(), true)
+                    }
+                    '<' => state.left_blizzards[yidx - 1].set((xidx - 1).try_into().unwrap(), true),
+                    '#' => state.walls[yidx].set(xidx.try_into().unwrap(), true),
+                    '.' => {
+                        if yidx == 0 {
+                            state.start = Point::new(xidx.try_into().unwrap(), 0);
+                        }
+                        if yidx == usize::from(height - 1) {
+                            state.end =
+                                Point::new(xidx.try_into().unwrap(), yidx.try_into().unwrap());
+                        }
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        state.pos = state.start.clone();
 
-pub fn part2(mut state: State) -> u16 {
-    state = bfs(state);
-    std::mem::swap(&mut state.start, &mut state.end);
-    state = bfs(state);
-    std::mem::swap(&mut state.start, &mut state.end);
-    bfs(state).step
+        state
+    }
+
+    fn part1(state: Self::Input<'_>) -> Self::Output1 {
+        bfs(state).step
+    }
+
+    fn part2(mut state: Self::Input<'_>) -> Self::Output2 {
+        state = bfs(state);
+        std::mem::swap(&mut state.start, &mut state.end);
+        state = bfs(state);
+        std::mem::swap(&mut state.start, &mut state.end);
+        bfs(state).step
+    }
 }
 
 fn bfs(state: State) -> State {
@@ -37,38 +81,6 @@ fn bfs(state: State) -> State {
     state
 }
 
-pub fn parse<'a>(data: &'a str) -> State {
-    let height = data.lines().count() as u16;
-    let width = data.lines().nth(0).unwrap().len() as u16;
-
-    let mut state = State::new(width, height);
-
-    for (yidx, line) in data.lines().enumerate() {
-        for (xidx, char) in line.chars().enumerate() {
-            match char {
-                '^' => state.up_blizzards[xidx - 1].set((yidx - 1).try_into().unwrap(), true),
-                'v' => state.down_blizzards[xidx - 1].set((yidx - 1).try_into().unwrap(), true),
-                '>' => state.right_blizzards[yidx - 1].set((xidx - 1).try_into().unwrap(), true),
-                '<' => state.left_blizzards[yidx - 1].set((xidx - 1).try_into().unwrap(), true),
-                '#' => state.walls[yidx].set(xidx.try_into().unwrap(), true),
-                '.' => {
-                    if yidx == 0 {
-                        state.start = Point::new(xidx.try_into().unwrap(), 0);
-                    }
-                    if yidx == (height - 1).into() {
-                        state.end = Point::new(xidx.try_into().unwrap(), yidx.try_into().unwrap());
-                    }
-                }
-                _ => (),
-            }
-        }
-    }
-
-    state.pos = state.start.clone();
-
-    state
-}
-
 #[derive(Clone)]
 pub struct State {
     step: u16,
@@ -353,15 +365,15 @@ mod tests {
 
     #[test]
     fn test1() {
-        let parsed = parse(INPUT);
-        let res = part1(parsed);
+        let parsed = Day::parse(INPUT);
+        let res = Day::part1(parsed);
         assert_eq!(18, res)
     }
 
     #[test]
     fn test2() {
-        let parsed = parse(INPUT);
-        let res = part2(parsed);
+        let parsed = Day::parse(INPUT);
+        let res = Day::part2(parsed);
         assert_eq!(54, res)
     }
 
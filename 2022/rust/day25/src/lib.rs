@@ -1,13 +1,23 @@
-pub fn part1<T: Iterator<Item = i64> + Clone>(ns: T) -> String {
-    encode(ns.sum())
-}
+use util::Solution;
 
-pub fn part2<T: Iterator<Item = i64>>(_ns: T) -> &'static str {
-    ""
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = std::vec::IntoIter<i64>;
+    type Output1 = String;
+    type Output2 = &'static str;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        data.lines().map(decode).collect::<Vec<_>>().into_iter()
+    }
+
+    fn part1(ns: Self::Input<'_>) -> Self::Output1 {
+        encode(ns.sum())
+    }
 
-pub fn parse<'a>(data: &'a str) -> impl Iterator<Item = i64> + Clone + '_ {
-    data.lines().into_iter().map(|line| decode(line))
+    fn part2(_ns: Self::Input<'_>) -> Self::Output2 {
+        ""
+    }
 }
 
 fn decode(s: &str) -> i64 {
@@ -99,15 +109,15 @@ mod tests {
 
     #[test]
     fn test1() {
-        let parsed = parse(INPUT);
-        let res = part1(parsed);
+        let parsed = Day::parse(INPUT);
+        let res = Day::part1(parsed);
         assert_eq!("2=-1=0", res)
     }
 
     #[test]
     fn test2() {
-        let parsed = parse(INPUT);
-        let res = part2(parsed);
+        let parsed = Day::parse(INPUT);
+        let res = Day::part2(parsed);
         assert_eq!("", res)
     }
 }
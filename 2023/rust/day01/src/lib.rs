@@ -1,43 +1,54 @@
 use itertools::Itertools;
 use regex::Regex;
+use util::Solution;
 
-pub fn parse<'a>(data: &'a str) -> impl Iterator<Item = &'a str> + 'a {
-    data.lines()
-}
+pub struct Day;
 
-pub fn part1<'a>(input: impl Iterator<Item = &'a str>) -> i32 {
-    input
-        .map(|l| {
-            let ns = l
-                .chars()
-                .filter_map(|c| c.to_string().parse::<i32>().ok())
-                .collect_vec();
+impl Solution for Day {
+    type Input<'a> = std::str::Lines<'a>;
+    type Output1 = i32;
+    type Output2 = i32;
 
-            (ns.first().unwrap() * 10) + ns.last().unwrap()
-        })
-        .sum()
-}
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        data.lines()
+    }
 
-pub fn part2<'a>(input: impl Iterator<Item = &'a str>) -> i32 {
-    let re: Regex =
-        Regex::new(r"(1|2|3|4|5|6|7|8|9|one|two|three|four|five|six|seven|eight|nine)").unwrap();
-    let reverse_re: Regex =
-        Regex::new(r"(1|2|3|4|5|6|7|8|9|eno|owt|eerht|ruof|evif|xis|neves|thgie|enin)").unwrap();
+    fn part1<'a>(input: Self::Input<'a>) -> Self::Output1 {
+        input
+            .map(|l| {
+                let ns = l
+                    .chars()
+                    .filter_map(|c| c.to_string().parse::<i32>().ok())
+                    .collect_vec();
 
-    input
-        .map(|l| {
-            let first = re.find(l).unwrap().as_str();
-            let last = reverse_re
-                .find(&l.chars().rev().collect::<String>())
-                .unwrap()
-                .as_str()
-                .chars()
-                .rev()
-                .collect::<String>();
+                (ns.first().unwrap() * 10) + ns.last().unwrap()
+            })
+            .sum()
+    }
 
-            (match_string_num(first) * 10) + match_string_num(&last)
-        })
-        .sum()
+    fn part2<'a>(input: Self::Input<'a>) -> Self::Output2 {
+        let re: Regex =
+            Regex::new(r"(1|2|3|4|5|6|7|8|9|one|two|three|four|five|six|seven|eight|nine)")
+                .unwrap();
+        let reverse_re: Regex =
+            Regex::new(r"(1|2|3|4|5|6|7|8|9|eno|owt|eerht|ruof|evif|xis|neves|thgie|enin)")
+                .unwrap();
+
+        input
+            .map(|l| {
+                let first = re.find(l).unwrap().as_str();
+                let last = reverse_re
+                    .find(&l.chars().rev().collect::<String>())
+                    .unwrap()
+                    .as_str()
+                    .chars()
+                    .rev()
+                    .collect::<String>();
+
+                (match_string_num(first) * 10) + match_string_num(&last)
+            })
+            .sum()
+    }
 }
 
 fn match_string_num(str: &str) -> i32 {
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use util::Solution;
 
 use nom::{
     bytes::complete::tag,
@@ -56,32 +57,40 @@ fn parse_game(s: &str) -> IResult<&str, Game> {
     Ok((rest, Game { id, draws }))
 }
 
-pub fn parse<'a>(data: &'a str) -> impl Iterator<Item = Game> {
-    let (_, games) = separated_list1(newline, parse_game)(data).unwrap();
-    games.into_iter()
-}
+pub struct Day;
 
-pub fn part1<'a>(input: impl Iterator<Item = Game>) -> i32 {
-    input
-        .filter(|game| {
-            game.draws
-                .iter()
-                .all(|draw| draw.red <= 12 && draw.green <= 13 && draw.blue <= 14)
-        })
-        .map(|game| game.id)
-        .sum()
-}
+impl Solution for Day {
+    type Input<'a> = std::vec::IntoIter<Game>;
+    type Output1 = i32;
+    type Output2 = i32;
 
-pub fn part2<'a>(input: impl Iterator<Item = Game>) -> i32 {
-    input
-        .map(|game| {
-            let min_red = game.draws.iter().map(|draw| draw.red).max().unwrap_or(1);
-            let min_green = game.draws.iter().map(|draw| draw.green).max().unwrap_or(1);
-            let min_blue = game.draws.iter().map(|draw| draw.blue).max().unwrap_or(1);
+    fn parse(data: &str) -> Self::Input<'_> {
+        let (_, games) = separated_list1(newline, parse_game)(data).unwrap();
+        games.into_iter()
+    }
 
-            min_red * min_blue * min_green
-        })
-        .sum()
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        input
+            .filter(|game| {
+                game.draws
+                    .iter()
+                    .all(|draw| draw.red <= 12 && draw.green <= 13 && draw.blue <= 14)
+            })
+            .map(|game| game.id)
+            .sum()
+    }
+
+    fn part2(input: Self::Input<'_>) -> Self::Output2 {
+        input
+            .map(|game| {
+                let min_red = game.draws.iter().map(|draw| draw.red).max().unwrap_or(1);
+                let min_green = game.draws.iter().map(|draw| draw.green).max().unwrap_or(1);
+                let min_blue = game.draws.iter().map(|draw| draw.blue).max().unwrap_or(1);
+
+                min_red * min_blue * min_green
+            })
+            .sum()
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use util::Solution;
 
 #[derive(Debug, Eq, PartialEq, Hash, Clone)]
 pub struct Point {
@@ -76,91 +77,99 @@ impl Schematic {
     }
 }
 
-pub fn parse<'a>(data: &'a str) -> Schematic {
-    let mut schematic = Schematic::new();
-    let mut cur_item: Option<Item> = None;
+pub struct Day;
 
-    let maybe_add_and_reset_cur =
-        |schematic: &mut Schematic, cur_item: &mut Option<Item>| match &cur_item {
-            None => (),
-            Some(item) => {
-                schematic.add_item(item.clone());
-                *cur_item = None;
-            }
-        };
+impl Solution for Day {
+    type Input<'a> = Schematic;
+    type Output1 = usize;
+    type Output2 = usize;
 
-    for (y, line) in data.lines().enumerate() {
-        maybe_add_and_reset_cur(&mut schematic, &mut cur_item);
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let mut schematic = Schematic::new();
+        let mut cur_item: Option<Item> = None;
 
-        for (x, char) in line.chars().enumerate() {
-            match char {
-                '.' => {
-                    maybe_add_and_reset_cur(&mut schematic, &mut cur_item);
+        let maybe_add_and_reset_cur =
+            |schematic: &mut Schematic, cur_item: &mut Option<Item>| match &cur_item {
+                None => (),
+                Some(item) => {
+                    schematic.add_item(item.clone());
+                    *cur_item = None;
                 }
-                '0'..='9' => {
-                    let dig = char.to_digit(10).unwrap();
-                    match &mut cur_item {
-                        None => {
-                            let mut new_item = Item::new(x, y);
-                            new_item.add_dig(dig as usize);
-                            cur_item = Some(new_item);
-                        }
-                        Some(item) => {
-                            item.add_dig(dig as usize);
+            };
+
+        for (y, line) in data.lines().enumerate() {
+            maybe_add_and_reset_cur(&mut schematic, &mut cur_item);
+
+            for (x, char) in line.chars().enumerate() {
+                match char {
+                    '.' => {
+                        maybe_add_and_reset_cur(&mut schematic, &mut cur_item);
+                    }
+                    '0'..='9' => {
+                        let dig = char.to_digit(10).unwrap();
+                        match &mut cur_item {
+                            None => {
+                                let mut new_item = Item::new(x, y);
+                                new_item.add_dig(dig as usize);
+                                cur_item = Some(new_item);
+                            }
+                            Some(item) => {
+                                item.add_dig(dig as usize);
+                            }
                         }
                     }
-                }
-                symbol => {
-                    maybe_add_and_reset_cur(&mut schematic, &mut cur_item);
-                    schematic.add_symbol(x, y, symbol)
+                    symbol => {
+                        maybe_add_and_reset_cur(&mut schematic, &mut cur_item);
+                        schematic.add_symbol(x, y, symbol)
+                    }
                 }
             }
         }
-    }
 
-    schematic
-}
+        schematic
+    }
 
-pub fn part1<'a>(input: Schematic) -> usize {
-    let mut total = 0;
-    for item in input.items {
-        for (symbol_point, _symbol) in input.symbols.iter() {
-            if item.adjacent(&symbol_point) {
-                match item.num {
-                    None => (),
-                    Some(num) => {
-                        total += num;
+    fn part1<'a>(input: Self::Input<'a>) -> Self::Output1 {
+        let mut total = 0;
+        for item in input.items {
+            for (symbol_point, _symbol) in input.symbols.iter() {
+                if item.adjacent(&symbol_point) {
+                    match item.num {
+                        None => (),
+                        Some(num) => {
+                            total += num;
+                        }
                     }
+                    break;
                 }
-                break;
             }
         }
+
+        total
     }
 
-    total
-}
+    fn part2<'a>(input: Self::Input<'a>) -> Self::Output2 {
+        let mut total = 0;
+        for (symbol_point, symbol) in input.symbols.iter() {
+            if *symbol != '*' {
+                continue;
+            }
+            let geared: Vec<Item> = input
+                .items
+                .iter()
+                .filter(|item| item.adjacent(&symbol_point))
+                .cloned()
+                .collect();
+
+            if geared.len() != 2 {
+                continue;
+            }
 
-pub fn part2<'a>(input: Schematic) -> usize {
-    let mut total = 0;
-    for (symbol_point, symbol) in input.symbols.iter() {
-        if *symbol != '*' {
-            continue;
-        }
-        let geared: Vec<Item> = input
-            .items
-            .iter()
-            .filter(|item| item.adjacent(&symbol_point))
-            .cloned()
-            .collect();
-
-        if geared.len() != 2 {
-            continue;
+            total += geared.first().unwrap().num.unwrap() * geared.last().unwrap().num.unwrap();
         }
 
-        total += geared.first().unwrap().num.unwrap() * geared.last().unwrap().num.unwrap();
+        total
     }
-
-    total
 }
 
 #[cfg(test)]
@@ -179,8 +188,8 @@ mod tests {
 ......755.
 ...$.*....
 .664.598.."#;
-        let data = parse(&input);
-        assert_eq!(part1(data), 4361);
+        let data = Day::parse(&input);
+        assert_eq!(Day::part1(data), 4361);
     }
 
     generate_test! { 2023, 3, 1, 519444}
@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use util::Solution;
 
 use nom::{
     bytes::complete::tag,
@@ -36,45 +37,53 @@ fn parse_card(s: &str) -> IResult<&str, Card> {
     Ok((rest, Card { winners, nums }))
 }
 
-pub fn parse<'a>(data: &'a str) -> impl Iterator<Item = Card> {
-    let (_rest, cards) = separated_list1(newline, parse_card)(data).unwrap();
-    cards.into_iter()
-}
+pub struct Day;
 
-pub fn part1<'a>(input: impl Iterator<Item = Card>) -> usize {
-    input
-        .map(|card| {
-            let intersection = card.nums.intersection(&card.winners);
-            let count = intersection.count();
-            match count {
-                0 => 0,
-                count => 2_usize.pow((count - 1) as u32),
-            }
-        })
-        .sum()
-}
+impl Solution for Day {
+    type Input<'a> = std::vec::IntoIter<Card>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        let (_rest, cards) = separated_list1(newline, parse_card)(data).unwrap();
+        cards.into_iter()
+    }
 
-pub fn part2<'a>(input: impl Iterator<Item = Card>) -> usize {
-    let (_, cards) = input.fold(
-        (Vec::new(), 0),
-        |(mut bonuses, cards): (Vec<usize>, usize), card| {
-            let intersection = card.nums.intersection(&card.winners);
-            let count = intersection.count();
-            let mut num_card = 1;
-            bonuses.retain_mut(|bonus| {
-                num_card += 1;
-                *bonus -= 1;
-                *bonus != 0
-            });
-            if count > 0 {
-                for _ in 0..num_card {
-                    bonuses.push(count);
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        input
+            .map(|card| {
+                let intersection = card.nums.intersection(&card.winners);
+                let count = intersection.count();
+                match count {
+                    0 => 0,
+                    count => 2_usize.pow((count - 1) as u32),
                 }
-            }
-            (bonuses, cards + num_card)
-        },
-    );
-    cards
+            })
+            .sum()
+    }
+
+    fn part2(input: Self::Input<'_>) -> Self::Output2 {
+        let (_, cards) = input.fold(
+            (Vec::new(), 0),
+            |(mut bonuses, cards): (Vec<usize>, usize), card| {
+                let intersection = card.nums.intersection(&card.winners);
+                let count = intersection.count();
+                let mut num_card = 1;
+                bonuses.retain_mut(|bonus| {
+                    num_card += 1;
+                    *bonus -= 1;
+                    *bonus != 0
+                });
+                if count > 0 {
+                    for _ in 0..num_card {
+                        bonuses.push(count);
+                    }
+                }
+                (bonuses, cards + num_card)
+            },
+        );
+        cards
+    }
 }
 
 #[cfg(test)]
@@ -90,14 +99,14 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#;
 
     #[test]
     fn test_sample1() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part1(data), 13);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part1(data), 13);
     }
 
     #[test]
     fn test_sample2() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part2(data), 30);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part2(data), 30);
     }
 
     generate_test! { 2023, 4, 1, 15205}
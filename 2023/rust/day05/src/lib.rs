@@ -1,4 +1,5 @@
 use std::{collections::HashMap, ops::Range};
+use util::Solution;
 
 use itertools::Itertools;
 use nom::{
@@ -171,10 +172,53 @@ fn parse_seed_map(s: &str) -> IResult<&str, SeedMap> {
     Ok((rest, seed_map))
 }
 
-pub fn parse<'a>(data: &'a str) -> SeedMap {
-    let (rest, seed_map) = parse_seed_map(data).unwrap();
-    assert_eq!(rest.trim(), "");
-    seed_map
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = SeedMap<'a>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let (rest, seed_map) = parse_seed_map(data).unwrap();
+        assert_eq!(rest.trim(), "");
+        seed_map
+    }
+
+    fn part1<'a>(input: Self::Input<'a>) -> Self::Output1 {
+        input
+            .seeds
+            .iter()
+            .map(|seed| {
+                TRANSFORMS.iter().fold(*seed, |acc, transform| {
+                    input.maps.get(transform).unwrap().map_seed(&acc)
+                })
+            })
+            .min()
+            .unwrap()
+    }
+
+    fn part2<'a>(input: Self::Input<'a>) -> Self::Output2 {
+        let mut ranges: Vec<Range<i64>> = Vec::new();
+
+        for mut chunk in &input.seeds.iter().chunks(2) {
+            let start = chunk.next().unwrap();
+            let len = chunk.next().unwrap();
+            ranges.push(*start..(*start + len));
+        }
+
+        let mut current = ranges.to_vec();
+        for t_name in TRANSFORMS.iter() {
+            let t = input.maps.get(t_name).unwrap();
+
+            current = current
+                .iter()
+                .flat_map(|r| t.map_range(r.clone()))
+                .collect();
+        }
+
+        current.iter().min_by_key(|r| r.start).unwrap().start
+    }
 }
 
 const TRANSFORMS: [(&str, &str); 7] = [
@@ -187,41 +231,6 @@ const TRANSFORMS: [(&str, &str); 7] = [
     ("humidity", "location"),
 ];
 
-pub fn part1<'a>(input: SeedMap) -> i64 {
-    input
-        .seeds
-        .iter()
-        .map(|seed| {
-            TRANSFORMS.iter().fold(*seed, |acc, transform| {
-                input.maps.get(transform).unwrap().map_seed(&acc)
-            })
-        })
-        .min()
-        .unwrap()
-}
-
-pub fn part2<'a>(input: SeedMap) -> i64 {
-    let mut ranges: Vec<Range<i64>> = Vec::new();
-
-    for mut chunk in &input.seeds.iter().chunks(2) {
-        let start = chunk.next().unwrap();
-        let len = chunk.next().unwrap();
-        ranges.push(*start..(*start + len));
-    }
-
-    let mut current = ranges.to_vec();
-    for t_name in TRANSFORMS.iter() {
-        let t = input.maps.get(t_name).unwrap();
-
-        current = current
-            .iter()
-            .flat_map(|r| t.map_range(r.clone()))
-            .collect();
-    }
-
-    current.iter().min_by_key(|r| r.start).unwrap().start
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -263,14 +272,14 @@ humidity-to-location map:
 
     #[test]
     fn test_sample1() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part1(data), 35);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part1(data), 35);
     }
 
     #[test]
     fn test_sample2() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part2(data), 46);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part2(data), 46);
     }
 
     #[test]
@@ -1,4 +1,5 @@
 use itertools::Itertools;
+use util::Solution;
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum Card {
@@ -138,73 +139,81 @@ impl From<(&str, &str)> for Hand {
     }
 }
 
-pub fn parse<'a>(data: &'a str) -> Hands {
-    data.lines().fold(Hands::new(), |mut acc, line| {
-        let mut hands = line.split_whitespace();
-        let cards = hands.next().unwrap();
-        let wager = hands.next().unwrap();
-        let hand: Hand = (cards, wager).into();
-        acc.0.push(hand);
-        acc
-    })
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Hands;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        data.lines().fold(Hands::new(), |mut acc, line| {
+            let mut hands = line.split_whitespace();
+            let cards = hands.next().unwrap();
+            let wager = hands.next().unwrap();
+            let hand: Hand = (cards, wager).into();
+            acc.0.push(hand);
+            acc
+        })
+    }
 
-pub fn part1<'a>(input: Hands) -> i32 {
-    input
-        .0
-        .iter()
-        .sorted()
-        .enumerate()
-        .map(|(idx, hand)| ((idx + 1) as i32) * hand.wager)
-        .sum()
-}
+    fn part1<'a>(input: Self::Input<'a>) -> Self::Output1 {
+        input
+            .0
+            .iter()
+            .sorted()
+            .enumerate()
+            .map(|(idx, hand)| ((idx + 1) as i32) * hand.wager)
+            .sum()
+    }
 
-pub fn part2<'a>(input: Hands) -> i32 {
-    input
-        .0
-        .into_iter()
-        .map(|hand| {
-            if !hand.cards.contains(&Card::J) {
-                return hand;
-            }
+    fn part2<'a>(input: Self::Input<'a>) -> Self::Output2 {
+        input
+            .0
+            .into_iter()
+            .map(|hand| {
+                if !hand.cards.contains(&Card::J) {
+                    return hand;
+                }
 
-            let counts = hand.cards.iter().filter(|card| card != &&Card::J).counts();
+                let counts = hand.cards.iter().filter(|card| card != &&Card::J).counts();
+
+                if counts.is_empty() {
+                    return Hand {
+                        cards: [
+                            Card::Joker(Box::new(Card::A)),
+                            Card::Joker(Box::new(Card::A)),
+                            Card::Joker(Box::new(Card::A)),
+                            Card::Joker(Box::new(Card::A)),
+                            Card::Joker(Box::new(Card::A)),
+                        ],
+                        wager: hand.wager,
+                    };
+                }
+
+                let (_, most_common) = counts
+                    .into_iter()
+                    .map(|(card, count)| (count, card))
+                    .max()
+                    .unwrap();
+
+                let mut new_cards = hand.cards.clone();
+                for card in new_cards.iter_mut() {
+                    if *card == Card::J {
+                        *card = Card::Joker(Box::new(most_common.clone()));
+                    }
+                }
 
-            if counts.is_empty() {
                 return Hand {
-                    cards: [
-                        Card::Joker(Box::new(Card::A)),
-                        Card::Joker(Box::new(Card::A)),
-                        Card::Joker(Box::new(Card::A)),
-                        Card::Joker(Box::new(Card::A)),
-                        Card::Joker(Box::new(Card::A)),
-                    ],
+                    cards: new_cards,
                     wager: hand.wager,
                 };
-            }
-
-            let (_, most_common) = counts
-                .into_iter()
-                .map(|(card, count)| (count, card))
-                .max()
-                .unwrap();
-
-            let mut new_cards = hand.cards.clone();
-            for card in new_cards.iter_mut() {
-                if *card == Card::J {
-                    *card = Card::Joker(Box::new(most_common.clone()));
-                }
-            }
-
-            return Hand {
-                cards: new_cards,
-                wager: hand.wager,
-            };
-        })
-        .sorted()
-        .enumerate()
-        .map(|(idx, hand)| ((idx + 1) as i32) * hand.wager)
-        .sum()
+            })
+            .sorted()
+            .enumerate()
+            .map(|(idx, hand)| ((idx + 1) as i32) * hand.wager)
+            .sum()
+    }
 }
 
 #[cfg(test)]
@@ -219,14 +228,14 @@ QQQJA 483"#;
 
     #[test]
     fn test_sample1() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part1(data), 6440);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part1(data), 6440);
     }
 
     #[test]
     fn test_sample2() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part2(data), 5905);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part2(data), 5905);
     }
 
     const BETTER_SAMPLE_INPUT: &str = r#"2345A 1
@@ -251,23 +260,23 @@ JJJJ2 41"#;
 
     #[test]
     fn test_better_sample1() {
-        let data = parse(&BETTER_SAMPLE_INPUT);
-        assert_eq!(part1(data), 6592);
+        let data = Day::parse(&BETTER_SAMPLE_INPUT);
+        assert_eq!(Day::part1(data), 6592);
     }
 
     #[test]
     fn test_better_sample2() {
-        let data = parse(&BETTER_SAMPLE_INPUT);
-        assert_eq!(part2(data), 6839);
+        let data = Day::parse(&BETTER_SAMPLE_INPUT);
+        assert_eq!(Day::part2(data), 6839);
     }
 
     #[test]
     fn test_part2_joker_sort() {
-        let data = parse(
+        let data = Day::parse(
             r#"JJJJJ 37
 JJJJ2 41"#,
         );
-        assert_eq!(part2(data), 119);
+        assert_eq!(Day::part2(data), 119);
     }
 
     #[test]
@@ -1,4 +1,5 @@
 use std::{collections::HashMap, str::FromStr};
+use util::Solution;
 
 use itertools::{
     FoldWhile::{Continue, Done},
@@ -104,22 +105,30 @@ impl FromStr for Map {
     }
 }
 
-pub fn parse<'a>(data: &'a str) -> Map {
-    data.parse().unwrap()
-}
+pub struct Day;
 
-pub fn part1<'a>(input: Map) -> usize {
-    let end: NodeId = "ZZZ".into();
-    count_steps(&input, &"AAA".into(), &|node_id| node_id == &end)
-}
+impl Solution for Day {
+    type Input<'a> = Map;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        data.parse().unwrap()
+    }
 
-pub fn part2<'a>(input: Map) -> usize {
-    lcm(&input
-        .nodes
-        .keys()
-        .filter(|id| id.is_start())
-        .map(|start| count_steps(&input, start, &|node_id| node_id.is_end()))
-        .collect_vec())
+    fn part1<'a>(input: Self::Input<'a>) -> Self::Output1 {
+        let end: NodeId = "ZZZ".into();
+        count_steps(&input, &"AAA".into(), &|node_id| node_id == &end)
+    }
+
+    fn part2<'a>(input: Self::Input<'a>) -> Self::Output2 {
+        lcm(&input
+            .nodes
+            .keys()
+            .filter(|id| id.is_start())
+            .map(|start| count_steps(&input, start, &|node_id| node_id.is_end()))
+            .collect_vec())
+    }
 }
 
 fn count_steps(map: &Map, start: &NodeId, is_end: &dyn Fn(&NodeId) -> bool) -> usize {
@@ -173,8 +182,8 @@ ZZZ = (ZZZ, ZZZ)"#;
 
     #[test]
     fn test_sample1_1() {
-        let data = parse(&SAMPLE_INPUT1);
-        assert_eq!(part1(data), 2);
+        let data = Day::parse(&SAMPLE_INPUT1);
+        assert_eq!(Day::part1(data), 2);
     }
 
     const SAMPLE_INPUT2: &str = r#"LLR
@@ -185,8 +194,8 @@ ZZZ = (ZZZ, ZZZ)"#;
 
     #[test]
     fn test_sample2_1() {
-        let data = parse(&SAMPLE_INPUT2);
-        assert_eq!(part1(data), 6);
+        let data = Day::parse(&SAMPLE_INPUT2);
+        assert_eq!(Day::part1(data), 6);
     }
 
     const SAMPLE_INPUT3: &str = r#"LR
@@ -202,8 +211,8 @@ XXX = (XXX, XXX)"#;
 
     #[test]
     fn test_sample3_1() {
-        let data = parse(&SAMPLE_INPUT3);
-        assert_eq!(part2(data), 6);
+        let data = Day::parse(&SAMPLE_INPUT3);
+        assert_eq!(Day::part2(data), 6);
     }
 
     generate_test! { 2023, 8, 1, 20513}
@@ -1,21 +1,30 @@
 use itertools::Itertools;
+use util::Solution;
 
-pub fn parse<'a>(data: &'a str) -> Vec<Vec<i32>> {
-    data.lines()
-        .map(|l| {
-            l.split_whitespace()
-                .map(|n| n.parse().unwrap())
-                .collect_vec()
-        })
-        .collect_vec()
-}
+pub struct Day;
 
-pub fn part1<'a>(histories: Vec<Vec<i32>>) -> i32 {
-    fold_histories(histories, 0, &|acc, history| acc + history.last().unwrap())
-}
+impl Solution for Day {
+    type Input<'a> = Vec<Vec<i32>>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        data.lines()
+            .map(|l| {
+                l.split_whitespace()
+                    .map(|n| n.parse().unwrap())
+                    .collect_vec()
+            })
+            .collect_vec()
+    }
 
-pub fn part2<'a>(histories: Vec<Vec<i32>>) -> i32 {
-    fold_histories(histories, 0, &|acc, history| history.first().unwrap() - acc)
+    fn part1<'a>(histories: Self::Input<'a>) -> Self::Output1 {
+        fold_histories(histories, 0, &|acc, history| acc + history.last().unwrap())
+    }
+
+    fn part2<'a>(histories: Self::Input<'a>) -> Self::Output2 {
+        fold_histories(histories, 0, &|acc, history| history.first().unwrap() - acc)
+    }
 }
 
 fn fold_histories(histories: Vec<Vec<i32>>, init: i32, f: &dyn Fn(i32, &Vec<i32>) -> i32) -> i32 {
@@ -56,14 +65,14 @@ mod tests {
 
     #[test]
     fn test_sample_1() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part1(data), 114);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part1(data), 114);
     }
 
     #[test]
     fn test_sample_2() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part2(data), 2);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part2(data), 2);
     }
 
     generate_test! { 2023, 9, 1, 1681758908}
@@ -1,4 +1,5 @@
 use std::{collections::HashMap, fmt::Debug};
+use util::Solution;
 
 use itertools::Itertools;
 
@@ -179,100 +180,108 @@ impl PipeSketch {
     }
 }
 
-pub fn parse<'a>(data: &'a str) -> PipeSketch {
-    let mut pipes = HashMap::new();
-    let mut start: Option<Pos> = None;
-    let mut max = Pos::new(0, 0);
+pub struct Day;
 
-    for (y, line) in data.lines().enumerate() {
-        for (x, char) in line.chars().enumerate() {
-            let pos = Pos::new(x.try_into().unwrap(), y.try_into().unwrap());
-            max = max.max(pos.clone());
+impl Solution for Day {
+    type Input<'a> = PipeSketch;
+    type Output1 = i32;
+    type Output2 = usize;
 
-            if char == '.' {
-                continue;
-            }
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let mut pipes = HashMap::new();
+        let mut start: Option<Pos> = None;
+        let mut max = Pos::new(0, 0);
+
+        for (y, line) in data.lines().enumerate() {
+            for (x, char) in line.chars().enumerate() {
+                let pos = Pos::new(x.try_into().unwrap(), y.try_into().unwrap());
+                max = max.max(pos.clone());
 
-            if char == 'S' {
-                start = Some(pos);
-                continue;
+                if char == '.' {
+                    continue;
+                }
+
+                if char == 'S' {
+                    start = Some(pos);
+                    continue;
+                }
+
+                pipes.insert(pos.clone(), (char, pos).into());
             }
+        }
 
-            pipes.insert(pos.clone(), (char, pos).into());
+        PipeSketch {
+            pipes,
+            pipe_loop: HashMap::new(),
+            start: start.clone().unwrap(),
+            cur: start.clone().unwrap(),
+            min: Pos::new(0, 0),
+            max,
+            last: None,
         }
     }
 
-    PipeSketch {
-        pipes,
-        pipe_loop: HashMap::new(),
-        start: start.clone().unwrap(),
-        cur: start.clone().unwrap(),
-        min: Pos::new(0, 0),
-        max,
-        last: None,
-    }
-}
+    fn part1<'a>(mut sketch: Self::Input<'a>) -> Self::Output1 {
+        sketch.find_loop().unwrap();
 
-pub fn part1<'a>(mut sketch: PipeSketch) -> i32 {
-    sketch.find_loop().unwrap();
-
-    (sketch.pipe_loop.len() / 2).try_into().unwrap()
-}
+        (sketch.pipe_loop.len() / 2).try_into().unwrap()
+    }
 
-pub fn part2<'a>(mut sketch: PipeSketch) -> usize {
-    sketch.find_loop().unwrap();
-
-    let inside = (sketch.min.x..=sketch.max.x)
-        .cartesian_product(sketch.min.y..=sketch.max.y)
-        .map(|(x, y)| Pos::new(x, y))
-        .filter(|pos| {
-            let empty = !sketch.pipe_loop.contains_key(pos);
-
-            let crosses = (sketch.min.x..pos.x)
-                .fold((0, 0, 0, 0, 0), |(ne, sw, se, nw, vert), x| {
-                    let pos = Pos::new(x, pos.y);
-                    match sketch.pipe_loop.get(&pos) {
-                        Some(Pipe::Vertical(_)) => (ne, sw, se, nw, vert + 1),
-                        Some(Pipe::NE(_)) => {
-                            if sw > 0 {
-                                (ne, sw - 1, se, nw, vert + 1)
-                            } else {
-                                (ne + 1, sw, se, nw, vert)
+    fn part2<'a>(mut sketch: Self::Input<'a>) -> Self::Output2 {
+        sketch.find_loop().unwrap();
+
+        let inside = (sketch.min.x..=sketch.max.x)
+            .cartesian_product(sketch.min.y..=sketch.max.y)
+            .map(|(x, y)| Pos::new(x, y))
+            .filter(|pos| {
+                let empty = !sketch.pipe_loop.contains_key(pos);
+
+                let crosses = (sketch.min.x..pos.x)
+                    .fold((0, 0, 0, 0, 0), |(ne, sw, se, nw, vert), x| {
+                        let pos = Pos::new(x, pos.y);
+                        match sketch.pipe_loop.get(&pos) {
+                            Some(Pipe::Vertical(_)) => (ne, sw, se, nw, vert + 1),
+                            Some(Pipe::NE(_)) => {
+                                if sw > 0 {
+                                    (ne, sw - 1, se, nw, vert + 1)
+                                } else {
+                                    (ne + 1, sw, se, nw, vert)
+                                }
                             }
-                        }
-                        Some(Pipe::SW(_)) => {
-                            if ne > 0 {
-                                (ne - 1, sw, se, nw, vert + 1)
-                            } else {
-                                (ne, sw + 1, se, nw, vert)
+                            Some(Pipe::SW(_)) => {
+                                if ne > 0 {
+                                    (ne - 1, sw, se, nw, vert + 1)
+                                } else {
+                                    (ne, sw + 1, se, nw, vert)
+                                }
                             }
-                        }
-                        Some(Pipe::NW(_)) => {
-                            if se > 0 {
-                                (ne, sw, se - 1, nw, vert + 1)
-                            } else {
-                                (ne, sw, se, nw + 1, vert)
+                            Some(Pipe::NW(_)) => {
+                                if se > 0 {
+                                    (ne, sw, se - 1, nw, vert + 1)
+                                } else {
+                                    (ne, sw, se, nw + 1, vert)
+                                }
                             }
-                        }
-                        Some(Pipe::SE(_)) => {
-                            if nw > 0 {
-                                (ne, sw, se, nw - 1, vert + 1)
-                            } else {
-                                (ne, sw, se + 1, nw, vert)
+                            Some(Pipe::SE(_)) => {
+                                if nw > 0 {
+                                    (ne, sw, se, nw - 1, vert + 1)
+                                } else {
+                                    (ne, sw, se + 1, nw, vert)
+                                }
                             }
+                            Some(_) => (ne, sw, se, nw, vert),
+                            None => (ne, sw, se, nw, vert),
                         }
-                        Some(_) => (ne, sw, se, nw, vert),
-                        None => (ne, sw, se, nw, vert),
-                    }
-                })
-                .4;
+                    })
+                    .4;
 
-            let even_crosses = crosses % 2 == 0;
-            empty && !even_crosses
-        })
-        .collect_vec();
+                let even_crosses = crosses % 2 == 0;
+                empty && !even_crosses
+            })
+            .collect_vec();
 
-    inside.len()
+        inside.len()
+    }
 }
 
 #[cfg(test)]
@@ -287,8 +296,8 @@ L|-JF"#;
 
     #[test]
     fn test_sample_1_1() {
-        let data = parse(&SAMPLE_INPUT1);
-        assert_eq!(part1(data), 4);
+        let data = Day::parse(&SAMPLE_INPUT1);
+        assert_eq!(Day::part1(data), 4);
     }
 
     const SAMPLE_INPUT2: &str = r#"7-F7-
@@ -299,8 +308,8 @@ LJ.LJ"#;
 
     #[test]
     fn test_sample_1_2() {
-        let data = parse(&SAMPLE_INPUT2);
-        assert_eq!(part1(data), 8);
+        let data = Day::parse(&SAMPLE_INPUT2);
+        assert_eq!(Day::part1(data), 8);
     }
 
     const SAMPLE_INPUT3: &str = r#"...........
@@ -315,8 +324,8 @@ LJ.LJ"#;
 
     #[test]
     fn test_sample_2_1() {
-        let data = parse(&SAMPLE_INPUT3);
-        assert_eq!(part2(data), 4);
+        let data = Day::parse(&SAMPLE_INPUT3);
+        assert_eq!(Day::part2(data), 4);
     }
 
     const SAMPLE_INPUT4: &str = r#"..........
@@ -331,8 +340,8 @@ LJ.LJ"#;
 
     #[test]
     fn test_sample_2_2() {
-        let data = parse(&SAMPLE_INPUT4);
-        assert_eq!(part2(data), 4);
+        let data = Day::parse(&SAMPLE_INPUT4);
+        assert_eq!(Day::part2(data), 4);
     }
 
     const SAMPLE_INPUT5: &str = r#".F----7F7F7F7F-7....
@@ -348,8 +357,8 @@ L--J.L7...LJS7F-7L7.
 
     #[test]
     fn test_sample_2_3() {
-        let data = parse(&SAMPLE_INPUT5);
-        assert_eq!(part2(data), 8);
+        let data = Day::parse(&SAMPLE_INPUT5);
+        assert_eq!(Day::part2(data), 8);
     }
 
     const SAMPLE_INPUT6: &str = r#"FF7FSF7F7F7F7F7F---7
@@ -365,8 +374,8 @@ L7JLJL-JLJLJL--JLJ.L"#;
 
     #[test]
     fn test_sample_2_4() {
-        let data = parse(&SAMPLE_INPUT6);
-        assert_eq!(part2(data), 10);
+        let data = Day::parse(&SAMPLE_INPUT6);
+        assert_eq!(Day::part2(data), 10);
     }
 
     generate_test! { 2023, 10, 1, 7066}
@@ -1,7 +1,7 @@
 use itertools::Itertools;
 use rayon::prelude::*;
 use std::collections::HashSet;
-use util::Pos;
+use util::{Pos, Solution};
 
 pub struct Observations {
     galaxies: HashSet<Pos<i64>>,
@@ -61,34 +61,42 @@ impl Observations {
     }
 }
 
-pub fn parse<'a>(data: &'a str) -> Observations {
-    let mut galaxies = HashSet::new();
-    let mut min = Pos::new(i64::MAX, i64::MAX);
-    let mut max = Pos::new(i64::MIN, i64::MIN);
+pub struct Day;
 
-    for (y, line) in data.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            let pos = Pos::new(x as i64, y as i64);
-            min = min.min(pos.clone());
-            max = max.max(pos.clone());
+impl Solution for Day {
+    type Input<'a> = Observations;
+    type Output1 = i64;
+    type Output2 = i64;
 
-            if '#' == c {
-                galaxies.insert(pos);
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let mut galaxies = HashSet::new();
+        let mut min = Pos::new(i64::MAX, i64::MAX);
+        let mut max = Pos::new(i64::MIN, i64::MIN);
+
+        for (y, line) in data.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let pos = Pos::new(x as i64, y as i64);
+                min = min.min(pos.clone());
+                max = max.max(pos.clone());
+
+                if '#' == c {
+                    galaxies.insert(pos);
+                }
             }
         }
-    }
 
-    Observations { galaxies, min, max }
-}
+        Observations { galaxies, min, max }
+    }
 
-pub fn part1<'a>(mut observations: Observations) -> i64 {
-    observations.expand_empty(2);
-    sum_distances(observations)
-}
+    fn part1<'a>(mut observations: Self::Input<'a>) -> Self::Output1 {
+        observations.expand_empty(2);
+        sum_distances(observations)
+    }
 
-pub fn part2<'a>(mut observations: Observations) -> i64 {
-    observations.expand_empty(1000000);
-    sum_distances(observations)
+    fn part2<'a>(mut observations: Self::Input<'a>) -> Self::Output2 {
+        observations.expand_empty(1000000);
+        sum_distances(observations)
+    }
 }
 
 pub fn sum_distances(observations: Observations) -> i64 {
@@ -122,20 +130,20 @@ mod tests {
 
     #[test]
     fn test_sample_1() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part1(data), 374);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part1(data), 374);
     }
 
     #[test]
     fn test_sample_2() {
-        let mut data = parse(&SAMPLE_INPUT);
+        let mut data = Day::parse(&SAMPLE_INPUT);
         data.expand_empty(10);
         assert_eq!(sum_distances(data), 1030);
     }
 
     #[test]
     fn test_sample_3() {
-        let mut data = parse(&SAMPLE_INPUT);
+        let mut data = Day::parse(&SAMPLE_INPUT);
         data.expand_empty(100);
         assert_eq!(sum_distances(data), 8410);
     }
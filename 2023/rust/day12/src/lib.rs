@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use util::Solution;
 
 use itertools::Itertools;
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
@@ -173,41 +174,49 @@ impl Rows {
     }
 }
 
-pub fn parse<'a>(data: &'a str) -> Rows {
-    let mut rows = Vec::new();
-
-    for (id, line) in data.lines().enumerate() {
-        let mut springs = Vec::new();
-        let (spring_str, groups) = line.split_whitespace().collect_tuple().unwrap();
-        for c in spring_str.chars() {
-            let spring = match c {
-                '.' => Spring::Operational,
-                '#' => Spring::Damaged,
-                '?' => Spring::Unknown,
-                _ => unimplemented!(),
-            };
-
-            springs.push(spring);
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Rows;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let mut rows = Vec::new();
+
+        for (id, line) in data.lines().enumerate() {
+            let mut springs = Vec::new();
+            let (spring_str, groups) = line.split_whitespace().collect_tuple().unwrap();
+            for c in spring_str.chars() {
+                let spring = match c {
+                    '.' => Spring::Operational,
+                    '#' => Spring::Damaged,
+                    '?' => Spring::Unknown,
+                    _ => unimplemented!(),
+                };
+
+                springs.push(spring);
+            }
+
+            let groups: Vec<u32> = groups.split(",").map(|s| s.parse().unwrap()).collect_vec();
+
+            rows.push(Row {
+                id,
+                springs,
+                groups,
+            });
         }
 
-        let groups: Vec<u32> = groups.split(",").map(|s| s.parse().unwrap()).collect_vec();
-
-        rows.push(Row {
-            id,
-            springs,
-            groups,
-        });
+        Rows { rows }
     }
 
-    Rows { rows }
-}
-
-pub fn part1<'a>(rows: Rows) -> usize {
-    rows.count_ways()
-}
+    fn part1<'a>(rows: Self::Input<'a>) -> Self::Output1 {
+        rows.count_ways()
+    }
 
-pub fn part2<'a>(rows: Rows) -> usize {
-    rows.unfold().count_ways()
+    fn part2<'a>(rows: Self::Input<'a>) -> Self::Output2 {
+        rows.unfold().count_ways()
+    }
 }
 
 #[cfg(test)]
@@ -223,7 +232,7 @@ mod tests {
 
     #[test]
     fn test_sample_1a() {
-        let data = parse("???.### 1,1,3");
+        let data = Day::parse("???.### 1,1,3");
         let x = data.rows.first().unwrap();
         let count = count_ways(&x.springs, &x.groups);
         assert_eq!(count, 1);
@@ -231,7 +240,7 @@ mod tests {
 
     #[test]
     fn test_sample_1b() {
-        let data = parse(".??..??...?##. 1,1,3");
+        let data = Day::parse(".??..??...?##. 1,1,3");
         let x = data.rows.first().unwrap();
         let count = count_ways(&x.springs, &x.groups);
         assert_eq!(count, 4);
@@ -239,7 +248,7 @@ mod tests {
 
     #[test]
     fn test_sample_1g() {
-        let data = parse("?.????##??.?#???. 2,3");
+        let data = Day::parse("?.????##??.?#???. 2,3");
         let x = data.rows.first().unwrap();
         let count = count_ways(&x.springs, &x.groups);
         assert_eq!(count, 2);
@@ -247,7 +256,7 @@ mod tests {
 
     #[test]
     fn test_sample_1h() {
-        let data = parse("?#.# 2,1");
+        let data = Day::parse("?#.# 2,1");
         let x = data.rows.first().unwrap();
         let count = count_ways(&x.springs, &x.groups);
         assert_eq!(count, 1);
@@ -255,7 +264,7 @@ mod tests {
 
     #[test]
     fn test_sample_1i() {
-        let data = parse("#??# 2");
+        let data = Day::parse("#??# 2");
         let x = data.rows.first().unwrap();
         let count = count_ways(&x.springs, &x.groups);
         assert_eq!(count, 0);
@@ -263,7 +272,7 @@ mod tests {
 
     #[test]
     fn test_sample_1j() {
-        let data = parse(".??#?#. 3");
+        let data = Day::parse(".??#?#. 3");
         let x = data.rows.first().unwrap();
         let count = count_ways(&x.springs, &x.groups);
         assert_eq!(count, 1);
@@ -271,7 +280,7 @@ mod tests {
 
     #[test]
     fn test_sample_1k() {
-        let data = parse("???# 1");
+        let data = Day::parse("???# 1");
         let x = data.rows.first().unwrap();
         let count = count_ways(&x.springs, &x.groups);
         assert_eq!(count, 1);
@@ -279,19 +288,19 @@ mod tests {
 
     #[test]
     fn test_sample_1() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part1(data), 21);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part1(data), 21);
     }
 
     #[test]
     fn test_sample_2() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part2(data), 525152);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part2(data), 525152);
     }
 
     #[test]
     fn test_sample_2a() {
-        let data = parse("???.### 1,1,3").unfold();
+        let data = Day::parse("???.### 1,1,3").unfold();
         let x = data.rows.first().unwrap();
         let count = count_ways(&x.springs, &x.groups);
         assert_eq!(count, 1);
@@ -299,7 +308,7 @@ mod tests {
 
     #[test]
     fn test_sample_2b() {
-        let data = parse(".??..??...?##. 1,1,3").unfold();
+        let data = Day::parse(".??..??...?##. 1,1,3").unfold();
         let x = data.rows.first().unwrap();
         let count = count_ways(&x.springs, &x.groups);
         assert_eq!(count, 16384);
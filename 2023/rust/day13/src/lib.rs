@@ -1,37 +1,55 @@
+use util::Solution;
+
 #[derive(Debug)]
 pub struct Valley {
     cols: Vec<u32>,
     rows: Vec<u32>,
 }
 
-pub fn parse<'a>(data: &'a str) -> Vec<Valley> {
-    let mut valleys = Vec::new();
-
-    for valley in data.split("\n\n") {
-        let num_cols = valley.lines().next().unwrap().len();
-        let num_rows = valley.lines().count();
-        let mut cols: Vec<u32> = vec![0; num_cols];
-        let mut rows: Vec<u32> = vec![0; num_rows];
-
-        for (y, line) in valley.lines().enumerate() {
-            for (x, c) in line.chars().enumerate() {
-                match c {
-                    '#' => {
-                        let row = rows.get_mut(y).unwrap();
-                        let col = cols.get_mut(x).unwrap();
-
-                        *row |= 1 << x;
-                        *col |= 1 << y;
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Vec<Valley>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let mut valleys = Vec::new();
+
+        for valley in data.split("\n\n") {
+            let num_cols = valley.lines().next().unwrap().len();
+            let num_rows = valley.lines().count();
+            let mut cols: Vec<u32> = vec![0; num_cols];
+            let mut rows: Vec<u32> = vec![0; num_rows];
+
+            for (y, line) in valley.lines().enumerate() {
+                for (x, c) in line.chars().enumerate() {
+                    match c {
+                        '#' => {
+                            let row = rows.get_mut(y).unwrap();
+                            let col = cols.get_mut(x).unwrap();
+
+                            *row |= 1 << x;
+                            *col |= 1 << y;
+                        }
+                        _ => (),
                     }
-                    _ => (),
                 }
             }
+
+            valleys.push(Valley { rows, cols })
         }
 
-        valleys.push(Valley { rows, cols })
+        valleys
     }
 
-    valleys
+    fn part1<'a>(valleys: Self::Input<'a>) -> Self::Output1 {
+        sum_mirrors(valleys, false)
+    }
+
+    fn part2<'a>(valleys: Self::Input<'a>) -> Self::Output2 {
+        sum_mirrors(valleys, true)
+    }
 }
 
 fn check_reflection(rows: &Vec<u32>, idx: usize) -> bool {
@@ -92,14 +110,6 @@ fn one_bit_off(a: u32, b: u32) -> bool {
     val & (val - 1) == 0
 }
 
-pub fn part1<'a>(valleys: Vec<Valley>) -> usize {
-    sum_mirrors(valleys, false)
-}
-
-pub fn part2<'a>(valleys: Vec<Valley>) -> usize {
-    sum_mirrors(valleys, true)
-}
-
 #[cfg(test)]
 mod tests {
     use util::generate_test;
@@ -122,14 +132,14 @@ mod tests {
 
     #[test]
     fn test_sample_1() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part1(data), 405);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part1(data), 405);
     }
 
     #[test]
     fn test_sample_2() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part2(data), 400);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part2(data), 400);
     }
 
     generate_test! { 2023, 13, 1, 29846}
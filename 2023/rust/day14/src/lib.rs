@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use util::Solution;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum Rocks {
@@ -186,52 +187,60 @@ impl std::fmt::Debug for Table {
     }
 }
 
-pub fn parse<'a>(data: &'a str) -> Table {
-    let width = data.lines().next().unwrap().len();
-    let height = data.lines().count();
-    let mut table = Table::new(width, height);
+pub struct Day;
 
-    for (y, line) in data.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            match c {
-                '#' => {
-                    table.set(x, y, Rocks::Cube);
-                }
-                'O' => {
-                    table.set(x, y, Rocks::Round);
+impl Solution for Day {
+    type Input<'a> = Table;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let width = data.lines().next().unwrap().len();
+        let height = data.lines().count();
+        let mut table = Table::new(width, height);
+
+        for (y, line) in data.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '#' => {
+                        table.set(x, y, Rocks::Cube);
+                    }
+                    'O' => {
+                        table.set(x, y, Rocks::Round);
+                    }
+                    '.' => (),
+                    x => unimplemented!("Unknown Char: {}", x),
                 }
-                '.' => (),
-                x => unimplemented!("Unknown Char: {}", x),
+                table.max_x = table.max_x.max(x);
+                table.max_y = table.max_y.max(y);
             }
-            table.max_x = table.max_x.max(x);
-            table.max_y = table.max_y.max(y);
         }
-    }
 
-    table
-}
+        table
+    }
 
-pub fn part1<'a>(mut table: Table) -> usize {
-    table.tilt(&Direction::N);
-    table.grade()
-}
+    fn part1<'a>(mut table: Self::Input<'a>) -> Self::Output1 {
+        table.tilt(&Direction::N);
+        table.grade()
+    }
 
-pub fn part2<'a>(mut table: Table) -> usize {
-    const NUM_TILTS: usize = 1000000000;
-    let mut tilted: HashMap<Table, usize> = HashMap::new();
+    fn part2<'a>(mut table: Self::Input<'a>) -> Self::Output2 {
+        const NUM_TILTS: usize = 1000000000;
+        let mut tilted: HashMap<Table, usize> = HashMap::new();
 
-    for i in 1..NUM_TILTS {
-        for direction in vec![Direction::N, Direction::W, Direction::S, Direction::E] {
-            table.tilt(&direction);
-        }
-        if let Some(tilted_at) = tilted.insert(table.clone(), i) {
-            if (NUM_TILTS - i) % (i - tilted_at) == 0 {
-                break;
+        for i in 1..NUM_TILTS {
+            for direction in vec![Direction::N, Direction::W, Direction::S, Direction::E] {
+                table.tilt(&direction);
+            }
+            if let Some(tilted_at) = tilted.insert(table.clone(), i) {
+                if (NUM_TILTS - i) % (i - tilted_at) == 0 {
+                    break;
+                }
             }
         }
-    }
 
-    table.grade()
+        table.grade()
+    }
 }
 
 #[cfg(test)]
@@ -251,33 +260,33 @@ O.#..O.#.#
 
     #[test]
     fn test_sample_1() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part1(data), 136);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part1(data), 136);
     }
 
     #[test]
     fn test_sample_2() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part2(data), 64);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part2(data), 64);
     }
 
     #[test]
     fn parse0() {
-        let data = parse(SAMPLE_INPUT);
+        let data = Day::parse(SAMPLE_INPUT);
         assert_eq!(format!("{:?}", data).trim(), SAMPLE_INPUT);
     }
 
     #[test]
     fn parse1() {
         let input = "O.O.O.#.#..O";
-        let data = parse(input);
+        let data = Day::parse(input);
         assert_eq!(format!("{:?}", data).trim(), input);
     }
 
     #[test]
     fn parse2() {
         let input = "O\n.\nO\n.\nO\n.\n#\n.\n#\n.\n.\nO";
-        let data = parse(input);
+        let data = Day::parse(input);
         assert_eq!(format!("{:?}", data).trim(), input);
     }
 
@@ -1,47 +1,56 @@
 use indexmap::IndexMap;
+use util::Solution;
 
-pub fn parse<'a>(data: &'a str) -> impl Iterator<Item = &'a str> {
-    data.trim_end_matches('\n').split(',')
-}
+pub struct Day;
 
-fn hash(s: &str) -> usize {
-    s.chars()
-        .fold(0, |acc, c| (((acc + (c as usize)) * 17) % 256))
-}
+impl Solution for Day {
+    type Input<'a> = std::str::Split<'a, char>;
+    type Output1 = usize;
+    type Output2 = usize;
 
-pub fn part1<'a>(input: impl Iterator<Item = &'a str>) -> usize {
-    input.map(|s| hash(s)).sum()
-}
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        data.trim_end_matches('\n').split(',')
+    }
 
-pub fn part2<'a>(input: impl Iterator<Item = &'a str>) -> usize {
-    let boxes: [IndexMap<String, usize>; 256] = core::array::from_fn(|_| IndexMap::new());
+    fn part1<'a>(input: Self::Input<'a>) -> Self::Output1 {
+        input.map(|s| hash(s)).sum()
+    }
 
-    input
-        .fold(boxes, |mut boxes, item| {
-            if item.ends_with("-") {
-                let label = item.trim_end_matches("-");
+    fn part2<'a>(input: Self::Input<'a>) -> Self::Output2 {
+        let boxes: [IndexMap<String, usize>; 256] = core::array::from_fn(|_| IndexMap::new());
+
+        input
+            .fold(boxes, |mut boxes, item| {
+                if item.ends_with("-") {
+                    let label = item.trim_end_matches("-");
+                    let label_hash = hash(label);
+                    boxes[label_hash].shift_remove(label);
+                    return boxes;
+                }
+
+                let (label, focal_length) = item.split_once("=").unwrap();
                 let label_hash = hash(label);
-                boxes[label_hash].shift_remove(label);
-                return boxes;
-            }
+                let focal_length: usize = focal_length.parse().unwrap();
+                boxes[label_hash].insert(label.to_string(), focal_length);
 
-            let (label, focal_length) = item.split_once("=").unwrap();
-            let label_hash = hash(label);
-            let focal_length: usize = focal_length.parse().unwrap();
-            boxes[label_hash].insert(label.to_string(), focal_length);
+                boxes
+            })
+            .iter()
+            .enumerate()
+            .map(|(idx, lens_box)| {
+                lens_box
+                    .iter()
+                    .enumerate()
+                    .map(|(b_idx, (_, focal_length))| (idx + 1) * (b_idx + 1) * focal_length)
+                    .sum::<usize>()
+            })
+            .sum()
+    }
+}
 
-            boxes
-        })
-        .iter()
-        .enumerate()
-        .map(|(idx, lens_box)| {
-            lens_box
-                .iter()
-                .enumerate()
-                .map(|(b_idx, (_, focal_length))| (idx + 1) * (b_idx + 1) * focal_length)
-                .sum::<usize>()
-        })
-        .sum()
+fn hash(s: &str) -> usize {
+    s.chars()
+        .fold(0, |acc, c| (((acc + (c as usize)) * 17) % 256))
 }
 
 #[cfg(test)]
@@ -52,14 +61,14 @@ mod tests {
 
     #[test]
     fn test_sample_1() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part1(data), 1320);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part1(data), 1320);
     }
 
     #[test]
     fn test_sample_2() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part2(data), 145);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part2(data), 145);
     }
 
     generate_test! { 2023, 15, 1, 514025}
@@ -1,7 +1,7 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
 use itertools::Itertools;
-use util::Pos;
+use util::{Pos, Solution};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Direction {
@@ -155,104 +155,114 @@ impl Contraption {
     }
 }
 
-pub fn parse<'a>(data: &'a str) -> Contraption {
-    let mut h = HashMap::new();
-    let mut max = Pos::new_unsigned(0, 0);
+pub struct Day;
 
-    for (y, line) in data.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            let p = Pos::new_unsigned(x, y);
-            max = Pos::new_unsigned(max.x.max(x), max.y.max(y));
-            if c == '.' {
-                continue;
+impl Solution for Day {
+    type Input<'a> = Contraption;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let mut h = HashMap::new();
+        let mut max = Pos::new_unsigned(0, 0);
+
+        for (y, line) in data.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let p = Pos::new_unsigned(x, y);
+                max = Pos::new_unsigned(max.x.max(x), max.y.max(y));
+                if c == '.' {
+                    continue;
+                }
+                match c {
+                    '/' => h.insert(p, MirrorsAndSplitters::FS),
+                    '\\' => h.insert(p, MirrorsAndSplitters::BS),
+                    '-' => h.insert(p, MirrorsAndSplitters::H),
+                    '|' => h.insert(p, MirrorsAndSplitters::V),
+                    x => unimplemented!("'{}'", x),
+                };
             }
-            match c {
-                '/' => h.insert(p, MirrorsAndSplitters::FS),
-                '\\' => h.insert(p, MirrorsAndSplitters::BS),
-                '-' => h.insert(p, MirrorsAndSplitters::H),
-                '|' => h.insert(p, MirrorsAndSplitters::V),
-                x => unimplemented!("'{}'", x),
-            };
+        }
+
+        Contraption {
+            mirrors_and_splitters: h,
+            beams: VecDeque::new(),
+            visited: HashSet::new(),
+            visited_with_direction: HashSet::new(),
+            max,
         }
     }
 
-    Contraption {
-        mirrors_and_splitters: h,
-        beams: VecDeque::new(),
-        visited: HashSet::new(),
-        visited_with_direction: HashSet::new(),
-        max,
+    fn part1<'a>(mut contraption: Self::Input<'a>) -> Self::Output1 {
+        contraption
+            .beams
+            .push_front(Beam::new(Pos::new_unsigned(0, 0), Direction::E));
+        contraption.fill();
+        contraption.visited.len()
     }
-}
 
-pub fn part1<'a>(mut contraption: Contraption) -> usize {
-    contraption
-        .beams
-        .push_front(Beam::new(Pos::new_unsigned(0, 0), Direction::E));
-    contraption.fill();
-    contraption.visited.len()
-}
+    fn part2<'a>(contraption: Self::Input<'a>) -> Self::Output2 {
+        let scenarios = (0..=contraption.max.x)
+            .cartesian_product(0..=contraption.max.y)
+            .filter(|(x, y)| {
+                *x == 0 || *y == 0 || *x == contraption.max.x || *y == contraption.max.y
+            })
+            .map(|(x, y)| Pos::new_unsigned(x, y))
+            .flat_map(|pos| {
+                if pos.x == 0 && pos.y == 0 {
+                    return vec![
+                        Beam::new(pos.clone(), Direction::E),
+                        Beam::new(pos, Direction::S),
+                    ];
+                }
+                if pos.x == contraption.max.x && pos.y == 0 {
+                    return vec![
+                        Beam::new(pos.clone(), Direction::W),
+                        Beam::new(pos, Direction::S),
+                    ];
+                }
 
-pub fn part2<'a>(contraption: Contraption) -> usize {
-    let scenarios = (0..=contraption.max.x)
-        .cartesian_product(0..=contraption.max.y)
-        .filter(|(x, y)| *x == 0 || *y == 0 || *x == contraption.max.x || *y == contraption.max.y)
-        .map(|(x, y)| Pos::new_unsigned(x, y))
-        .flat_map(|pos| {
-            if pos.x == 0 && pos.y == 0 {
-                return vec![
-                    Beam::new(pos.clone(), Direction::E),
-                    Beam::new(pos, Direction::S),
-                ];
-            }
-            if pos.x == contraption.max.x && pos.y == 0 {
-                return vec![
-                    Beam::new(pos.clone(), Direction::W),
-                    Beam::new(pos, Direction::S),
-                ];
-            }
+                if pos.x == 0 && pos.y == contraption.max.y {
+                    return vec![
+                        Beam::new(pos.clone(), Direction::E),
+                        Beam::new(pos, Direction::N),
+                    ];
+                }
+                if pos.x == contraption.max.x && pos.y == contraption.max.y {
+                    return vec![
+                        Beam::new(pos.clone(), Direction::W),
+                        Beam::new(pos, Direction::N),
+                    ];
+                }
 
-            if pos.x == 0 && pos.y == contraption.max.y {
-                return vec![
-                    Beam::new(pos.clone(), Direction::E),
-                    Beam::new(pos, Direction::N),
-                ];
-            }
-            if pos.x == contraption.max.x && pos.y == contraption.max.y {
-                return vec![
-                    Beam::new(pos.clone(), Direction::W),
-                    Beam::new(pos, Direction::N),
-                ];
-            }
+                if pos.x == 0 {
+                    return vec![Beam::new(pos, Direction::E)];
+                }
 
-            if pos.x == 0 {
-                return vec![Beam::new(pos, Direction::E)];
-            }
+                if pos.x == contraption.max.x {
+                    return vec![Beam::new(pos, Direction::W)];
+                }
 
-            if pos.x == contraption.max.x {
-                return vec![Beam::new(pos, Direction::W)];
-            }
+                if pos.y == 0 {
+                    return vec![Beam::new(pos, Direction::S)];
+                }
 
-            if pos.y == 0 {
-                return vec![Beam::new(pos, Direction::S)];
-            }
+                if pos.y == contraption.max.y {
+                    return vec![Beam::new(pos, Direction::N)];
+                }
 
-            if pos.y == contraption.max.y {
-                return vec![Beam::new(pos, Direction::N)];
-            }
+                unreachable!()
+            });
 
-            unreachable!()
-        });
-
-    scenarios
-        .map(|start| {
-            let mut contraption = contraption.clone();
-            contraption.beams.push_front(start);
-            contraption.fill();
-            contraption.visited.len()
-        })
-        .max()
-        .unwrap()
+        scenarios
+            .map(|start| {
+                let mut contraption = contraption.clone();
+                contraption.beams.push_front(start);
+                contraption.fill();
+                contraption.visited.len()
+            })
+            .max()
+            .unwrap()
+    }
 }
 
 #[cfg(test)]
@@ -272,14 +282,14 @@ mod tests {
 
     #[test]
     fn test_sample_1() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part1(data), 46);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part1(data), 46);
     }
 
     #[test]
     fn test_sample_2() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part2(data), 51);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part2(data), 51);
     }
 
     generate_test! { 2023, 16, 1, 6855}
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use itertools::Itertools;
-use util::{Direction, Pos};
+use util::{Direction, Pos, Solution};
 
 pub struct CityMap {
     blocks: HashMap<Pos<i32>, u32>,
@@ -75,66 +75,74 @@ impl Crucible {
     }
 }
 
-pub fn parse<'a>(data: &'a str) -> CityMap {
-    let mut blocks = HashMap::new();
-    let mut max = Pos::new(0, 0);
+pub struct Day;
 
-    for (y, line) in data.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            max.x = max.x.max(x as i32);
-            max.y = max.y.max(y as i32);
+impl Solution for Day {
+    type Input<'a> = CityMap;
+    type Output1 = u32;
+    type Output2 = u32;
 
-            blocks.insert(Pos::new(x as i32, y as i32), c.to_digit(10).unwrap());
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let mut blocks = HashMap::new();
+        let mut max = Pos::new(0, 0);
+
+        for (y, line) in data.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                max.x = max.x.max(x as i32);
+                max.y = max.y.max(y as i32);
+
+                blocks.insert(Pos::new(x as i32, y as i32), c.to_digit(10).unwrap());
+            }
         }
-    }
 
-    CityMap {
-        blocks,
-        max,
-        min: Pos::new(0, 0),
+        CityMap {
+            blocks,
+            max,
+            min: Pos::new(0, 0),
+        }
     }
-}
 
-pub fn part1<'a>(map: CityMap) -> u32 {
-    let start = Crucible {
-        pos: Pos::new(0, 0),
-        in_a_row: 0,
-        facing: None,
-    };
-
-    pathfinding::directed::dijkstra::dijkstra(
-        &start,
-        |crucible| {
-            crucible
-                .next(&map.min, &map.max, 0, 3)
-                .into_iter()
-                .map(|crucible| (crucible.clone(), *map.blocks.get(&crucible.pos).unwrap()))
-        },
-        |crucible| crucible.pos == map.max,
-    )
-    .unwrap()
-    .1
-}
+    fn part1<'a>(map: Self::Input<'a>) -> Self::Output1 {
+        let start = Crucible {
+            pos: Pos::new(0, 0),
+            in_a_row: 0,
+            facing: None,
+        };
+
+        pathfinding::directed::dijkstra::dijkstra(
+            &start,
+            |crucible| {
+                crucible
+                    .next(&map.min, &map.max, 0, 3)
+                    .into_iter()
+                    .map(|crucible| (crucible.clone(), *map.blocks.get(&crucible.pos).unwrap()))
+            },
+            |crucible| crucible.pos == map.max,
+        )
+        .unwrap()
+        .1
+    }
 
-pub fn part2<'a>(map: CityMap) -> u32 {
-    let start = Crucible {
-        pos: Pos::new(0, 0),
-        in_a_row: 0,
-        facing: None,
-    };
-
-    pathfinding::directed::dijkstra::dijkstra(
-        &start,
-        |crucible| {
-            crucible
-                .next(&map.min, &map.max, 4, 10)
-                .into_iter()
-                .map(|crucible| (crucible.clone(), *map.blocks.get(&crucible.pos).unwrap()))
-        },
-        |crucible| crucible.pos == map.max && crucible.in_a_row >= 4,
-    )
-    .unwrap()
-    .1
+    fn part2<'a>(map: Self::Input<'a>) -> Self::Output2 {
+        let start = Crucible {
+            pos: Pos::new(0, 0),
+            in_a_row: 0,
+            facing: None,
+        };
+
+        pathfinding::directed::dijkstra::dijkstra(
+            &start,
+            |crucible| {
+                crucible
+                    .next(&map.min, &map.max, 4, 10)
+                    .into_iter()
+                    .map(|crucible| (crucible.clone(), *map.blocks.get(&crucible.pos).unwrap()))
+            },
+            |crucible| crucible.pos == map.max && crucible.in_a_row >= 4,
+        )
+        .unwrap()
+        .1
+    }
 }
 
 #[cfg(test)]
@@ -157,26 +165,26 @@ mod tests {
 
     #[test]
     fn test_sample_1() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part1(data), 102);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part1(data), 102);
     }
 
     #[test]
     fn test_sample_2() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part2(data), 94);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part2(data), 94);
     }
 
     #[test]
     fn test_sample_3() {
-        let data = parse(
+        let data = Day::parse(
             &r#"111111111111
 999999999991
 999999999991
 999999999991
 999999999991"#,
         );
-        assert_eq!(part2(data), 71);
+        assert_eq!(Day::part2(data), 71);
     }
 
     generate_test! { 2023, 17, 1, 635}
@@ -1,5 +1,5 @@
 use itertools::Itertools;
-use util::{Direction, Pos};
+use util::{Direction, Pos, Solution};
 
 pub struct Trench {
     direction: Direction,
@@ -39,78 +39,86 @@ impl DigPlan {
     }
 }
 
-pub fn parse<'a>(data: &'a str) -> DigPlan {
-    let mut trenches = Vec::new();
-
-    for line in data.lines() {
-        let mut pieces = line.split_whitespace();
-        let direction = match pieces.next().unwrap() {
-            "D" => Direction::S,
-            "L" => Direction::W,
-            "R" => Direction::E,
-            "U" => Direction::N,
-            d => unimplemented!("{:?}", d),
-        };
-
-        let num: usize = pieces.next().unwrap().parse().unwrap();
+pub struct Day;
 
-        let color = pieces
-            .next()
-            .unwrap()
-            .trim_start_matches("(#")
-            .trim_end_matches(")")
-            .to_string();
-
-        trenches.push(Trench {
-            direction,
-            num,
-            color,
-        })
-    }
+impl Solution for Day {
+    type Input<'a> = DigPlan;
+    type Output1 = i64;
+    type Output2 = i64;
 
-    DigPlan { trenches }
-}
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let mut trenches = Vec::new();
 
-pub fn part1<'a>(plan: DigPlan) -> i64 {
-    let area = plan.area();
-    let perimeter = plan.perimeter();
-    let interior: i64 = area - (perimeter / 2) + 1;
+        for line in data.lines() {
+            let mut pieces = line.split_whitespace();
+            let direction = match pieces.next().unwrap() {
+                "D" => Direction::S,
+                "L" => Direction::W,
+                "R" => Direction::E,
+                "U" => Direction::N,
+                d => unimplemented!("{:?}", d),
+            };
 
-    interior + perimeter
-}
+            let num: usize = pieces.next().unwrap().parse().unwrap();
 
-pub fn part2<'a>(plan: DigPlan) -> i64 {
-    let fixed_trenches = plan
-        .trenches
-        .iter()
-        .map(|trench| {
-            let (num, dir) = trench.color.split_at(5);
-            let num = usize::from_str_radix(num, 16).unwrap();
-            let direction = match dir {
-                "0" => Direction::E,
-                "1" => Direction::S,
-                "2" => Direction::W,
-                "3" => Direction::N,
-                x => unimplemented!("{}", x),
-            };
+            let color = pieces
+                .next()
+                .unwrap()
+                .trim_start_matches("(#")
+                .trim_end_matches(")")
+                .to_string();
 
-            Trench {
+            trenches.push(Trench {
                 direction,
                 num,
-                color: "".to_string(),
-            }
-        })
-        .collect_vec();
+                color,
+            })
+        }
 
-    let fixed_plan = DigPlan {
-        trenches: fixed_trenches,
-    };
+        DigPlan { trenches }
+    }
+
+    fn part1<'a>(plan: Self::Input<'a>) -> Self::Output1 {
+        let area = plan.area();
+        let perimeter = plan.perimeter();
+        let interior: i64 = area - (perimeter / 2) + 1;
+
+        interior + perimeter
+    }
 
-    let area = fixed_plan.area();
-    let perimeter = fixed_plan.perimeter();
-    let interior: i64 = area - (perimeter / 2) + 1;
+    fn part2<'a>(plan: Self::Input<'a>) -> Self::Output2 {
+        let fixed_trenches = plan
+            .trenches
+            .iter()
+            .map(|trench| {
+                let (num, dir) = trench.color.split_at(5);
+                let num = usize::from_str_radix(num, 16).unwrap();
+                let direction = match dir {
+                    "0" => Direction::E,
+                    "1" => Direction::S,
+                    "2" => Direction::W,
+                    "3" => Direction::N,
+                    x => unimplemented!("{}", x),
+                };
+
+                Trench {
+                    direction,
+                    num,
+                    color: "".to_string(),
+                }
+            })
+            .collect_vec();
+
+        let fixed_plan = DigPlan {
+            trenches: fixed_trenches,
+        };
 
-    interior + perimeter
+        let area = fixed_plan.area();
+        let perimeter = fixed_plan.perimeter();
+        let interior: i64 = area - (perimeter / 2) + 1;
+
+        interior + perimeter
+    }
 }
 
 #[cfg(test)]
@@ -134,14 +142,14 @@ U 2 (#7a21e3)"#;
 
     #[test]
     fn test_sample_1() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part1(data), 62);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part1(data), 62);
     }
 
     #[test]
     fn test_sample_2() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part2(data), 952408144115);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part2(data), 952408144115);
     }
 
     generate_test! { 2023, 18, 1, 26857}
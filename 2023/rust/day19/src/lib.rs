@@ -2,6 +2,7 @@ use std::{
     collections::{HashMap, VecDeque},
     ops::RangeInclusive,
 };
+use util::Solution;
 
 use nom::{
     branch::alt,
@@ -320,21 +321,29 @@ fn parse_ratings(s: &str) -> IResult<&str, Vec<Rating>> {
     separated_list1(newline, parse_rating)(s)
 }
 
-pub fn parse<'a>(data: &'a str) -> PartHeap {
-    let (rest, workflows) = terminated(parse_workflows, many1(newline))(data).unwrap();
-    let (rest, ratings) = parse_ratings(rest).unwrap();
+pub struct Day;
 
-    assert_eq!(rest.trim(), "");
+impl Solution for Day {
+    type Input<'a> = PartHeap;
+    type Output1 = i64;
+    type Output2 = usize;
 
-    PartHeap { workflows, ratings }
-}
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let (rest, workflows) = terminated(parse_workflows, many1(newline))(data).unwrap();
+        let (rest, ratings) = parse_ratings(rest).unwrap();
 
-pub fn part1<'a>(part_heap: PartHeap) -> i64 {
-    part_heap.apply()
-}
+        assert_eq!(rest.trim(), "");
+
+        PartHeap { workflows, ratings }
+    }
 
-pub fn part2<'a>(part_heap: PartHeap) -> usize {
-    part_heap.count_possible()
+    fn part1<'a>(part_heap: Self::Input<'a>) -> Self::Output1 {
+        part_heap.apply()
+    }
+
+    fn part2<'a>(part_heap: Self::Input<'a>) -> Self::Output2 {
+        part_heap.count_possible()
+    }
 }
 
 #[cfg(test)]
@@ -361,14 +370,14 @@ hdj{m>838:A,pv}
 
     #[test]
     fn test_sample_1() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part1(data), 19114);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part1(data), 19114);
     }
 
     #[test]
     fn test_sample_2() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part2(data), 167409079868000);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part2(data), 167409079868000);
     }
 
     generate_test! { 2023, 19, 1, 353046}
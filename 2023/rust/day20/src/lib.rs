@@ -1,4 +1,5 @@
 use std::collections::{HashMap, HashSet, VecDeque};
+use util::Solution;
 
 use enum_dispatch::enum_dispatch;
 use itertools::Itertools;
@@ -310,79 +311,87 @@ fn parse_module(s: &str) -> IResult<&str, Module> {
     alt((parse_flip_flop, parse_conjunction, parse_broadcast))(s)
 }
 
-pub fn parse<'a>(data: &'a str) -> ModuleConfiguration {
-    let (rest, modules): (&str, Vec<Module>) =
-        separated_list1(newline, parse_module)(data).unwrap();
+pub struct Day;
 
-    let con_module_names = modules
-        .iter()
-        .filter(|module| module.is_conj())
-        .map(|module| module.id())
-        .collect_vec();
+impl Solution for Day {
+    type Input<'a> = ModuleConfiguration;
+    type Output1 = i32;
+    type Output2 = usize;
 
-    let mut modules: HashMap<String, Module> =
-        modules.into_iter().map(|m| (m.id().clone(), m)).collect();
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let (rest, modules): (&str, Vec<Module>) =
+            separated_list1(newline, parse_module)(data).unwrap();
 
-    let mut connections: Vec<(String, String)> = Vec::new();
+        let con_module_names = modules
+            .iter()
+            .filter(|module| module.is_conj())
+            .map(|module| module.id())
+            .collect_vec();
+
+        let mut modules: HashMap<String, Module> =
+            modules.into_iter().map(|m| (m.id().clone(), m)).collect();
 
-    for (name, module) in modules.iter() {
-        for connection in module.connections() {
-            if con_module_names.contains(&connection) {
-                connections.push((name.clone(), connection));
+        let mut connections: Vec<(String, String)> = Vec::new();
+
+        for (name, module) in modules.iter() {
+            for connection in module.connections() {
+                if con_module_names.contains(&connection) {
+                    connections.push((name.clone(), connection));
+                }
             }
         }
-    }
-
-    for (from, to) in connections {
-        modules.get_mut(&to).unwrap().insert_upstream(from);
-    }
 
-    assert!(rest.trim().is_empty());
+        for (from, to) in connections {
+            modules.get_mut(&to).unwrap().insert_upstream(from);
+        }
 
-    ModuleConfiguration { modules }
-}
+        assert!(rest.trim().is_empty());
 
-pub fn part1<'a>(mut config: ModuleConfiguration) -> i32 {
-    let mut high_count = 0;
-    let mut low_count = 0;
+        ModuleConfiguration { modules }
+    }
 
-    for _ in 0..1000 {
-        let (pulses, _) = config.push_button();
-        for (_, pulse) in pulses {
-            if pulse == Pulse::High {
-                high_count += 1;
-            } else {
-                low_count += 1;
+    fn part1<'a>(mut config: Self::Input<'a>) -> Self::Output1 {
+        let mut high_count = 0;
+        let mut low_count = 0;
+
+        for _ in 0..1000 {
+            let (pulses, _) = config.push_button();
+            for (_, pulse) in pulses {
+                if pulse == Pulse::High {
+                    high_count += 1;
+                } else {
+                    low_count += 1;
+                }
             }
         }
-    }
 
-    high_count * low_count
-}
+        high_count * low_count
+    }
 
-pub fn part2<'a>(config: ModuleConfiguration) -> usize {
-    let rx_parents = config.parents("rx");
-    let rx_source = rx_parents.iter().next().unwrap();
+    fn part2<'a>(config: Self::Input<'a>) -> Self::Output2 {
+        let rx_parents = config.parents("rx");
+        let rx_source = rx_parents.iter().next().unwrap();
 
-    let rx_source_sources = config.parents(rx_source);
+        let rx_source_sources = config.parents(rx_source);
 
-    let cycles = rx_source_sources
-        .iter()
-        .map(|name| {
-            let mut config = config.clone();
-            config.prune_to(name);
+        let cycles = rx_source_sources
+            .iter()
+            .map(|name| {
+                let mut config = config.clone();
+                config.prune_to(name);
 
-            let cycle =
-                pathfinding::directed::cycle_detection::floyd(config.clone(), |mut config| {
-                    let (_, config) = config.push_button();
-                    config.clone()
-                });
+                let cycle =
+                    pathfinding::directed::cycle_detection::floyd(config.clone(), |mut config| {
+                        let (_, config) = config.push_button();
+                        config.clone()
+                    });
 
-            (name, cycle)
-        })
-        .collect_vec();
+                (name, cycle)
+            })
+            .collect_vec();
 
-    cycles.iter().map(|(_, (size, _elem, _idx))| size).product()
+        cycles.iter().map(|(_, (size, _elem, _idx))| size).product()
+    }
 }
 
 #[cfg(test)]
@@ -403,14 +412,14 @@ mod tests {
 
     #[test]
     fn test_sample_1_1() {
-        let data = parse(&SAMPLE_INPUT1);
-        assert_eq!(part1(data), 32000000);
+        let data = Day::parse(&SAMPLE_INPUT1);
+        assert_eq!(Day::part1(data), 32000000);
     }
 
     #[test]
     fn test_sample_1_2() {
-        let data = parse(&SAMPLE_INPUT2);
-        assert_eq!(part1(data), 11687500);
+        let data = Day::parse(&SAMPLE_INPUT2);
+        assert_eq!(Day::part1(data), 11687500);
     }
 
     generate_test! { 2023, 20, 1, 739960225}
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use itertools::Itertools;
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
-use util::{Cube, Direction3D, Point3};
+use util::{Cube, Direction3D, Point3, Solution};
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Brick {
@@ -193,50 +193,58 @@ impl std::fmt::Debug for Stack {
     }
 }
 
-pub fn parse<'a>(data: &'a str) -> Stack {
-    let mut cubes = Vec::new();
-
-    for line in data.lines() {
-        let (front, back) = line.split("~").collect_tuple().unwrap();
-        let (fx, fy, fz) = front
-            .split(",")
-            .map(|f| f.parse().unwrap())
-            .collect_tuple()
-            .unwrap();
-        let (bx, by, bz) = back
-            .split(",")
-            .map(|b| b.parse().unwrap())
-            .collect_tuple()
-            .unwrap();
-
-        let cube = Cube::new(Point3::new(fx, fy, fz), Point3::new(bx, by, bz));
-
-        cubes.push(cube);
-    }
+pub struct Day;
 
-    cubes.sort_by_key(|cube| (cube.min_z(), cube.min_x(), cube.min_y()));
+impl Solution for Day {
+    type Input<'a> = Stack;
+    type Output1 = usize;
+    type Output2 = usize;
 
-    let mut id = 0;
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        let mut cubes = Vec::new();
 
-    let bricks = cubes
-        .into_iter()
-        .map(|cube| {
-            id += 1;
-            Brick { id, cube }
-        })
-        .collect_vec();
+        for line in data.lines() {
+            let (front, back) = line.split("~").collect_tuple().unwrap();
+            let (fx, fy, fz) = front
+                .split(",")
+                .map(|f| f.parse().unwrap())
+                .collect_tuple()
+                .unwrap();
+            let (bx, by, bz) = back
+                .split(",")
+                .map(|b| b.parse().unwrap())
+                .collect_tuple()
+                .unwrap();
 
-    Stack { bricks }
-}
+            let cube = Cube::new(Point3::new(fx, fy, fz), Point3::new(bx, by, bz));
 
-pub fn part1<'a>(mut stack: Stack) -> usize {
-    stack.drop();
-    stack.could_disintegrate().0
-}
+            cubes.push(cube);
+        }
 
-pub fn part2<'a>(mut stack: Stack) -> usize {
-    stack.drop();
-    stack.could_disintegrate().1
+        cubes.sort_by_key(|cube| (cube.min_z(), cube.min_x(), cube.min_y()));
+
+        let mut id = 0;
+
+        let bricks = cubes
+            .into_iter()
+            .map(|cube| {
+                id += 1;
+                Brick { id, cube }
+            })
+            .collect_vec();
+
+        Stack { bricks }
+    }
+
+    fn part1<'a>(mut stack: Self::Input<'a>) -> Self::Output1 {
+        stack.drop();
+        stack.could_disintegrate().0
+    }
+
+    fn part2<'a>(mut stack: Self::Input<'a>) -> Self::Output2 {
+        stack.drop();
+        stack.could_disintegrate().1
+    }
 }
 
 #[cfg(test)]
@@ -253,14 +261,14 @@ mod tests {
 
     #[test]
     fn test_sample_1() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part1(data), 5);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part1(data), 5);
     }
 
     #[test]
     fn test_sample_2() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part2(data), 7);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part2(data), 7);
     }
 
     generate_test! { 2023, 22, 1, 409}
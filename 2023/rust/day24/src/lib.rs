@@ -1,15 +1,24 @@
 use std::str::Lines;
+use util::Solution;
 
-pub fn parse<'a>(data: &'a str) -> Lines<'a> {
-    data.lines()
-}
+pub struct Day;
 
-pub fn part1<'a>(_input: impl Iterator<Item = &'a str>) -> i32 {
-    1
-}
+impl Solution for Day {
+    type Input<'a> = Lines<'a>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse<'a>(data: &'a str) -> Self::Input<'a> {
+        data.lines()
+    }
 
-pub fn part2<'a>(_input: impl Iterator<Item = &'a str>) -> i32 {
-    1
+    fn part1<'a>(_input: Self::Input<'a>) -> Self::Output1 {
+        1
+    }
+
+    fn part2<'a>(_input: Self::Input<'a>) -> Self::Output2 {
+        1
+    }
 }
 
 #[cfg(test)]
@@ -20,14 +29,14 @@ mod tests {
 
     #[test]
     fn test_sample_1() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part1(data), 0);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part1(data), 0);
     }
 
     #[test]
     fn test_sample_2() {
-        let data = parse(&SAMPLE_INPUT);
-        assert_eq!(part2(data), 0);
+        let data = Day::parse(&SAMPLE_INPUT);
+        assert_eq!(Day::part2(data), 0);
     }
 
     generate_test! { 2023, 24, 1, 0}
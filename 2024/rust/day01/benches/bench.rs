@@ -0,0 +1,3 @@
+use day01::*;
+
+util::generate_bench!(2024, 1);
@@ -1,49 +1,27 @@
 use itertools::Itertools;
+use util::Solution;
 
-pub fn parse(data: &str) -> impl Iterator<Item = Vec<i32>> + '_ {
-    data.lines().map(|l| {
-        l.split_whitespace()
-            .map(|n| n.parse::<i32>().unwrap())
-            .collect()
-    })
-}
-
-pub fn part1(input: impl Iterator<Item = Vec<i32>>) -> usize {
-    input
-        .filter(|row| {
-            row.iter()
-                .tuple_windows()
-                .map(|(a, b)| a - b)
-                .try_fold(0i8, |direction, diff| {
-                    if !good_diff(&diff) {
-                        return Err(false);
-                    }
+pub struct Day;
 
-                    match (direction, diff) {
-                        (0, 0) => Ok(0),
-                        (0, x) if x.is_positive() => Ok(1),
-                        (0, x) if x.is_negative() => Ok(-1),
-                        (1, x) if x.is_positive() => Ok(1),
-                        (-1, x) if x.is_negative() => Ok(-1),
-                        _ => Err(false),
-                    }
-                })
-                .is_ok()
-        })
-        .count()
-}
+impl Solution for Day {
+    type Input<'a> = std::vec::IntoIter<Vec<i32>>;
+    type Output1 = usize;
+    type Output2 = usize;
 
-fn good_diff(i: &i32) -> bool {
-    (1..=3).contains(&i.abs())
-}
-
-pub fn part2(input: impl Iterator<Item = Vec<i32>>) -> usize {
-    input
-        .filter(|row| {
-            (0..row.len()).any(|i| {
-                let mut row = row.clone();
-                row.remove(i);
+    fn parse(data: &str) -> Self::Input<'_> {
+        data.lines()
+            .map(|l| {
+                l.split_whitespace()
+                    .map(|n| n.parse::<i32>().unwrap())
+                    .collect()
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
 
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        input
+            .filter(|row| {
                 row.iter()
                     .tuple_windows()
                     .map(|(a, b)| a - b)
@@ -63,8 +41,42 @@ pub fn part2(input: impl Iterator<Item = Vec<i32>>) -> usize {
                     })
                     .is_ok()
             })
-        })
-        .count()
+            .count()
+    }
+
+    fn part2(input: Self::Input<'_>) -> Self::Output2 {
+        input
+            .filter(|row| {
+                (0..row.len()).any(|i| {
+                    let mut row = row.clone();
+                    row.remove(i);
+
+                    row.iter()
+                        .tuple_windows()
+                        .map(|(a, b)| a - b)
+                        .try_fold(0i8, |direction, diff| {
+                            if !good_diff(&diff) {
+                                return Err(false);
+                            }
+
+                            match (direction, diff) {
+                                (0, 0) => Ok(0),
+                                (0, x) if x.is_positive() => Ok(1),
+                                (0, x) if x.is_negative() => Ok(-1),
+                                (1, x) if x.is_positive() => Ok(1),
+                                (-1, x) if x.is_negative() => Ok(-1),
+                                _ => Err(false),
+                            }
+                        })
+                        .is_ok()
+                })
+            })
+            .count()
+    }
+}
+
+fn good_diff(i: &i32) -> bool {
+    (1..=3).contains(&i.abs())
 }
 
 #[cfg(test)]
@@ -80,8 +92,8 @@ mod tests {
 8 6 4 4 1
 1 3 6 7 9"#;
 
-        let data = parse(input);
-        assert_eq!(part1(data), 2);
+        let data = Day::parse(input);
+        assert_eq!(Day::part1(data), 2);
     }
 
     #[test]
@@ -93,8 +105,8 @@ mod tests {
 8 6 4 4 1
 1 3 6 7 9"#;
 
-        let data = parse(input);
-        assert_eq!(part2(data), 4);
+        let data = Day::parse(input);
+        assert_eq!(Day::part2(data), 4);
     }
 
     generate_test! { 2024, 2, 1, 483}
@@ -8,6 +8,7 @@ use nom::{
     IResult,
 };
 use parser::FromDig;
+use util::Solution;
 
 type RowType = Op;
 type OutType = i32;
@@ -48,29 +49,37 @@ fn next_parse_op(s: &str) -> IResult<&str, Op> {
     map(many_till(anychar, parse_op), |(_, op)| op)(s)
 }
 
-pub fn parse(data: &str) -> impl Iterator<Item = RowType> + '_ {
-    many0(next_parse_op)(data).unwrap().1.into_iter()
-}
+pub struct Day;
 
-pub fn part1(input: impl Iterator<Item = RowType>) -> OutType {
-    input
-        .map(|op| match op {
-            Op::Do => 0,
-            Op::Dont => 0,
-            Op::Mul(a, b) => a * b,
-        })
-        .sum()
-}
+impl Solution for Day {
+    type Input<'a> = std::vec::IntoIter<RowType>;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        many0(next_parse_op)(data).unwrap().1.into_iter()
+    }
+
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        input
+            .map(|op| match op {
+                Op::Do => 0,
+                Op::Dont => 0,
+                Op::Mul(a, b) => a * b,
+            })
+            .sum()
+    }
 
-pub fn part2(input: impl Iterator<Item = RowType>) -> OutType {
-    input
-        .fold((true, 0), |(emit, acc), op| match (emit, op) {
-            (_, Op::Do) => (true, acc),
-            (_, Op::Dont) => (false, acc),
-            (true, Op::Mul(a, b)) => (true, (a * b) + acc),
-            (false, _) => (false, acc),
-        })
-        .1
+    fn part2(input: Self::Input<'_>) -> Self::Output2 {
+        input
+            .fold((true, 0), |(emit, acc), op| match (emit, op) {
+                (_, Op::Do) => (true, acc),
+                (_, Op::Dont) => (false, acc),
+                (true, Op::Mul(a, b)) => (true, (a * b) + acc),
+                (false, _) => (false, acc),
+            })
+            .1
+    }
 }
 
 #[cfg(test)]
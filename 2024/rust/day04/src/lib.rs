@@ -1,20 +1,40 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
-use util::{Direction8, Pos};
+use util::{Direction8, Pos, Solution};
 
 type DataType = HashMap<Pos<i32>, char>;
 type OutType = usize;
 
-pub fn parse(data: &str) -> DataType {
-    let mut points = HashMap::new();
+pub struct Day;
 
-    for (y, line) in data.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            points.insert(Pos::new(x as i32, y as i32), c);
+impl Solution for Day {
+    type Input<'a> = DataType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        let mut points = HashMap::new();
+
+        for (y, line) in data.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                points.insert(Pos::new(x as i32, y as i32), c);
+            }
         }
+
+        points
+    }
+
+    fn part1(grid: Self::Input<'_>) -> Self::Output1 {
+        let xmas: VecDeque<char> = vec!['X', 'M', 'A', 'S'].into();
+        grid.keys()
+            .flat_map(|p| Direction8::all().map(move |d| (p, d)))
+            .filter(|(p, d)| search_xmas(&grid, p, d, xmas.clone()))
+            .count()
     }
 
-    points
+    fn part2(grid: Self::Input<'_>) -> Self::Output2 {
+        grid.keys().filter(|p| search_mas(&grid, p)).count()
+    }
 }
 
 pub fn search_xmas(
@@ -64,18 +84,6 @@ fn search_mas(grid: &DataType, point: &Pos<i32>) -> bool {
     diag1.len() == 3 && diag2.len() == 3
 }
 
-pub fn part1(grid: DataType) -> OutType {
-    let xmas: VecDeque<char> = vec!['X', 'M', 'A', 'S'].into();
-    grid.keys()
-        .flat_map(|p| Direction8::all().map(move |d| (p, d)))
-        .filter(|(p, d)| search_xmas(&grid, p, d, xmas.clone()))
-        .count()
-}
-
-pub fn part2(grid: DataType) -> OutType {
-    grid.keys().filter(|p| search_mas(&grid, p)).count()
-}
-
 #[cfg(test)]
 mod tests {
     use util::generate_test;
@@ -2,6 +2,7 @@ use std::{
     cmp::Ordering,
     collections::{HashMap, HashSet},
 };
+use util::Solution;
 
 use itertools::Itertools;
 use nom::{
@@ -104,10 +105,39 @@ fn parse_input(s: &str) -> IResult<&str, InputType> {
     Ok((rest, (page_order_rules, page_updates)))
 }
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    let (_rest, x) = parse_input(data).unwrap();
-    x
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        let (_rest, x) = parse_input(data).unwrap();
+        x
+    }
+
+    #[allow(unused_variables)]
+    fn part1((page_order_rules, page_updates): Self::Input<'_>) -> Self::Output1 {
+        page_updates
+            .iter()
+            .filter(|page_update| is_correct_order(&page_order_rules, page_update))
+            .map(|page_update| page_update.get(page_update.len() / 2).unwrap())
+            .sum()
+    }
+
+    #[allow(unused_variables)]
+    fn part2((page_order_rules, page_updates): Self::Input<'_>) -> Self::Output2 {
+        let por_ref = &page_order_rules;
+
+        page_updates
+            .into_iter()
+            .filter(|page_update| !is_correct_order(por_ref, page_update))
+            .update(|page_update| correct_order(por_ref, page_update))
+            .map(|page_update| *page_update.get(page_update.len() / 2).unwrap())
+            .sum()
+    }
 }
 
 fn is_correct_order(page_order_rules: &PageOrderRules, pages: &[usize]) -> bool {
@@ -117,31 +147,10 @@ fn is_correct_order(page_order_rules: &PageOrderRules, pages: &[usize]) -> bool
     })
 }
 
-#[allow(unused_variables)]
-pub fn part1((page_order_rules, page_updates): InputType) -> OutType {
-    page_updates
-        .iter()
-        .filter(|page_update| is_correct_order(&page_order_rules, page_update))
-        .map(|page_update| page_update.get(page_update.len() / 2).unwrap())
-        .sum()
-}
-
 fn correct_order(page_order_rules: &PageOrderRules, page_update: &mut [usize]) {
     page_update.sort_by(|a, b| page_order_rules.cmp(a, b))
 }
 
-#[allow(unused_variables)]
-pub fn part2((page_order_rules, page_updates): InputType) -> OutType {
-    let por_ref = &page_order_rules;
-
-    page_updates
-        .into_iter()
-        .filter(|page_update| !is_correct_order(por_ref, page_update))
-        .update(|page_update| correct_order(por_ref, page_update))
-        .map(|page_update| *page_update.get(page_update.len() / 2).unwrap())
-        .sum()
-}
-
 #[cfg(test)]
 mod tests {
     use util::generate_test;
@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
-use util::{Direction, Pos};
+use util::{Direction, Pos, Solution};
 
 type InputType = Map;
 type OutType = usize;
@@ -42,40 +42,84 @@ enum Next {
     OutOfBounds,
 }
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    let mut obstructions = HashSet::new();
-    let mut cur = None;
-    let mut max_x = 0;
-    let mut max_y = 0;
-
-    for (y, line) in data.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            match c {
-                '#' => {
-                    obstructions.insert(Pos::new(x as i32, y as i32));
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        let mut obstructions = HashSet::new();
+        let mut cur = None;
+        let mut max_x = 0;
+        let mut max_y = 0;
+
+        for (y, line) in data.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '#' => {
+                        obstructions.insert(Pos::new(x as i32, y as i32));
+                    }
+                    '^' => {
+                        cur = Some(Pos::new(x as i32, y as i32));
+                    }
+                    '.' => (),
+                    _ => unimplemented!(),
                 }
-                '^' => {
-                    cur = Some(Pos::new(x as i32, y as i32));
+                if x > max_x {
+                    max_x = x;
                 }
-                '.' => (),
-                _ => unimplemented!(),
             }
-            if x > max_x {
-                max_x = x;
+            if y > max_y {
+                max_y = y;
             }
         }
-        if y > max_y {
-            max_y = y;
+
+        Map {
+            obstructions,
+            cur: cur.unwrap(),
+            cur_direction: Direction::N,
+            top_right: Pos::new(max_x as i32, max_y as i32),
+            bottom_left: Pos::new(0, 0),
         }
     }
 
-    Map {
-        obstructions,
-        cur: cur.unwrap(),
-        cur_direction: Direction::N,
-        top_right: Pos::new(max_x as i32, max_y as i32),
-        bottom_left: Pos::new(0, 0),
+    #[allow(unused_variables)]
+    fn part1(map: Self::Input<'_>) -> Self::Output1 {
+        patrol(map).len()
+    }
+
+    #[allow(unused_variables)]
+    fn part2(map: Self::Input<'_>) -> Self::Output2 {
+        let mut possible_new_obstructions = patrol(map.clone());
+        possible_new_obstructions.remove(&map.cur);
+
+        possible_new_obstructions
+            .into_par_iter()
+            .filter(|new_obstruction| {
+                let mut map = map.clone();
+                map.obstructions.insert(new_obstruction.clone());
+
+                let mut visited: HashSet<(P, Direction)> = HashSet::new();
+
+                loop {
+                    let cur_pd = (map.cur.clone(), map.cur_direction.clone());
+
+                    if !visited.insert(cur_pd.clone()) {
+                        return true;
+                    };
+
+                    match map.next() {
+                        Next::Ok(pos) => map.cur = pos,
+                        Next::OutOfBounds => {
+                            return false;
+                        }
+                    }
+                }
+            })
+            .count()
     }
 }
 
@@ -106,42 +150,6 @@ pub fn patrol(mut map: Map) -> HashSet<P> {
     visited
 }
 
-#[allow(unused_variables)]
-pub fn part1(map: InputType) -> OutType {
-    patrol(map).len()
-}
-
-#[allow(unused_variables)]
-pub fn part2(map: InputType) -> OutType {
-    let mut possible_new_obstructions = patrol(map.clone());
-    possible_new_obstructions.remove(&map.cur);
-
-    possible_new_obstructions
-        .into_par_iter()
-        .filter(|new_obstruction| {
-            let mut map = map.clone();
-            map.obstructions.insert(new_obstruction.clone());
-
-            let mut visited: HashSet<(P, Direction)> = HashSet::new();
-
-            loop {
-                let cur_pd = (map.cur.clone(), map.cur_direction.clone());
-
-                if !visited.insert(cur_pd.clone()) {
-                    return true;
-                };
-
-                match map.next() {
-                    Next::Ok(pos) => map.cur = pos,
-                    Next::OutOfBounds => {
-                        return false;
-                    }
-                }
-            }
-        })
-        .count()
-}
-
 #[cfg(test)]
 mod tests {
     use util::generate_test;
@@ -1,22 +1,52 @@
+use util::Solution;
+
 type InputType = Vec<(i64, Vec<i64>)>;
 type OutType = i64;
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    data.lines()
-        .map(|l| {
-            let mut parts = l.split(":");
-            let result = parts.next().unwrap().trim().parse().unwrap();
-            let values = parts
-                .next()
-                .unwrap()
-                .trim()
-                .split(" ")
-                .map(|v| v.trim().parse().unwrap())
-                .collect();
-            (result, values)
-        })
-        .collect()
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        data.lines()
+            .map(|l| {
+                let mut parts = l.split(":");
+                let result = parts.next().unwrap().trim().parse().unwrap();
+                let values = parts
+                    .next()
+                    .unwrap()
+                    .trim()
+                    .split(" ")
+                    .map(|v| v.trim().parse().unwrap())
+                    .collect();
+                (result, values)
+            })
+            .collect()
+    }
+
+    #[allow(unused_variables)]
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        const OPS: [Op; 2] = [Op::Add, Op::Mul];
+        input
+            .iter()
+            .filter(|(result, values)| possibly_true(result, values[0], &values[1..], &OPS))
+            .map(|(result, values)| result)
+            .sum()
+    }
+
+    #[allow(unused_variables)]
+    fn part2(input: Self::Input<'_>) -> Self::Output2 {
+        const OPS: [Op; 3] = [Op::Add, Op::Mul, Op::Concat];
+        input
+            .iter()
+            .filter(|(result, values)| possibly_true(result, values[0], &values[1..], &OPS))
+            .map(|(result, values)| result)
+            .sum()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -59,26 +89,6 @@ fn possibly_true(result: &i64, total: i64, remaining: &[i64], ops: &[Op]) -> boo
     false
 }
 
-#[allow(unused_variables)]
-pub fn part1(input: InputType) -> OutType {
-    const OPS: [Op; 2] = [Op::Add, Op::Mul];
-    input
-        .iter()
-        .filter(|(result, values)| possibly_true(result, values[0], &values[1..], &OPS))
-        .map(|(result, values)| result)
-        .sum()
-}
-
-#[allow(unused_variables)]
-pub fn part2(input: InputType) -> OutType {
-    const OPS: [Op; 3] = [Op::Add, Op::Mul, Op::Concat];
-    input
-        .iter()
-        .filter(|(result, values)| possibly_true(result, values[0], &values[1..], &OPS))
-        .map(|(result, values)| result)
-        .sum()
-}
-
 #[cfg(test)]
 mod tests {
     use util::generate_test;
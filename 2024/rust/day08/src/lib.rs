@@ -1,7 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
-use util::Pos;
+use util::{Pos, Solution};
 
 type OutType = usize;
 
@@ -12,39 +12,93 @@ pub struct Map {
     max_y: i8,
 }
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> Map {
-    let mut antennae: HashMap<char, HashSet<Pos<i8>>> = HashMap::new();
-    let mut max_x = 0;
-    let mut max_y = 0;
-
-    for (y, line) in data.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            if x > max_x {
-                max_x = x;
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = Map;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        let mut antennae: HashMap<char, HashSet<Pos<i8>>> = HashMap::new();
+        let mut max_x = 0;
+        let mut max_y = 0;
+
+        for (y, line) in data.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if x > max_x {
+                    max_x = x;
+                }
+                if c == '.' {
+                    continue;
+                }
+
+                let point = Pos::new(x as i8, y as i8);
+
+                antennae
+                    .entry(c)
+                    .and_modify(|antennae| {
+                        antennae.insert(point.clone());
+                    })
+                    .or_insert([point].into());
             }
-            if c == '.' {
-                continue;
+            if y > max_y {
+                max_y = y;
             }
-
-            let point = Pos::new(x as i8, y as i8);
-
-            antennae
-                .entry(c)
-                .and_modify(|antennae| {
-                    antennae.insert(point.clone());
-                })
-                .or_insert([point].into());
         }
-        if y > max_y {
-            max_y = y;
+
+        Map {
+            antennae,
+            max_x: max_x as i8,
+            max_y: max_y as i8,
         }
     }
 
-    Map {
-        antennae,
-        max_x: max_x as i8,
-        max_y: max_y as i8,
+    #[allow(unused_variables)]
+    fn part1(map: Self::Input<'_>) -> Self::Output1 {
+        map.antennae
+            .iter()
+            .flat_map(|(c, locations)| locations.iter().combinations(2))
+            .fold(HashSet::new(), |mut antinodes, points| {
+                let diff_x = points[0].x - points[1].x;
+                let diff_y = points[0].y - points[1].y;
+
+                if let Some(new) = translate(points[0], diff_x, diff_y, map.max_x, map.max_y) {
+                    antinodes.insert(new);
+                }
+                if let Some(new) = translate(points[1], -diff_x, -diff_y, map.max_x, map.max_y) {
+                    antinodes.insert(new);
+                }
+                antinodes
+            })
+            .len()
+    }
+
+    #[allow(unused_variables)]
+    fn part2(map: Self::Input<'_>) -> Self::Output2 {
+        map.antennae
+            .iter()
+            .flat_map(|(c, locations)| locations.iter().combinations(2))
+            .fold(HashSet::new(), |mut antinodes, points| {
+                let diff_x = points[0].x - points[1].x;
+                let diff_y = points[0].y - points[1].y;
+
+                let mut a_point = Some(points[0].clone());
+                while let Some(pos) = a_point {
+                    antinodes.insert(pos.clone());
+                    a_point = translate(&pos, diff_x, diff_y, map.max_x, map.max_y)
+                }
+
+                let mut b_point = Some(points[1].clone());
+                while let Some(pos) = b_point {
+                    antinodes.insert(pos.clone());
+                    b_point = translate(&pos, -diff_x, -diff_y, map.max_x, map.max_y)
+                }
+
+                antinodes
+            })
+            .len()
     }
 }
 
@@ -60,52 +114,6 @@ fn translate(p: &Pos<i8>, x: i8, y: i8, max_x: i8, max_y: i8) -> Option<Pos<i8>>
     None
 }
 
-#[allow(unused_variables)]
-pub fn part1(map: Map) -> OutType {
-    map.antennae
-        .iter()
-        .flat_map(|(c, locations)| locations.iter().combinations(2))
-        .fold(HashSet::new(), |mut antinodes, points| {
-            let diff_x = points[0].x - points[1].x;
-            let diff_y = points[0].y - points[1].y;
-
-            if let Some(new) = translate(points[0], diff_x, diff_y, map.max_x, map.max_y) {
-                antinodes.insert(new);
-            }
-            if let Some(new) = translate(points[1], -diff_x, -diff_y, map.max_x, map.max_y) {
-                antinodes.insert(new);
-            }
-            antinodes
-        })
-        .len()
-}
-
-#[allow(unused_variables)]
-pub fn part2(map: Map) -> OutType {
-    map.antennae
-        .iter()
-        .flat_map(|(c, locations)| locations.iter().combinations(2))
-        .fold(HashSet::new(), |mut antinodes, points| {
-            let diff_x = points[0].x - points[1].x;
-            let diff_y = points[0].y - points[1].y;
-
-            let mut a_point = Some(points[0].clone());
-            while let Some(pos) = a_point {
-                antinodes.insert(pos.clone());
-                a_point = translate(&pos, diff_x, diff_y, map.max_x, map.max_y)
-            }
-
-            let mut b_point = Some(points[1].clone());
-            while let Some(pos) = b_point {
-                antinodes.insert(pos.clone());
-                b_point = translate(&pos, -diff_x, -diff_y, map.max_x, map.max_y)
-            }
-
-            antinodes
-        })
-        .len()
-}
-
 #[cfg(test)]
 mod tests {
     use util::generate_test;
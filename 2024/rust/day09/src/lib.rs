@@ -2,6 +2,7 @@ use std::{
     iter,
     ops::{Index, IndexMut},
 };
+use util::Solution;
 
 type InputType = Disk;
 type OutType = usize;
@@ -30,20 +31,92 @@ impl FromIterator<Block> for Disk {
     }
 }
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    data.trim_end()
-        .chars()
-        .enumerate()
-        .map(|(idx, c)| {
-            if idx % 2 == 0 {
-                let n = idx / 2;
-                Block::Data(n, c.to_string().parse().unwrap())
-            } else {
-                Block::Free(c.to_string().parse().unwrap())
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        data.trim_end()
+            .chars()
+            .enumerate()
+            .map(|(idx, c)| {
+                if idx % 2 == 0 {
+                    let n = idx / 2;
+                    Block::Data(n, c.to_string().parse().unwrap())
+                } else {
+                    Block::Free(c.to_string().parse().unwrap())
+                }
+            })
+            .collect()
+    }
+
+    #[allow(unused_variables)]
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        let mut disk: Disk = input.0.iter().flat_map(|block| block.explode()).collect();
+
+        let mut s = 0;
+        let mut e = disk.len() - 1;
+
+        while s < e {
+            match disk[s] {
+                Block::Data(_, _) => (),
+                Block::Free(_) => loop {
+                    match disk[e] {
+                        Block::Free(_) => (),
+                        Block::Data(_, _) => {
+                            disk.swap(s, e);
+                            break;
+                        }
+                    }
+                    e -= 1;
+                },
             }
-        })
-        .collect()
+            s += 1;
+        }
+
+        disk.checksum()
+    }
+
+    #[allow(unused_variables)]
+    fn part2(mut disk: Self::Input<'_>) -> Self::Output2 {
+        let mut e = disk.len() - 1;
+
+        while e > 0 {
+            match disk[e] {
+                Block::Free(_) => (),
+                Block::Data(i, width) => {
+                    let empty_idx = (0..e).find_map(|idx| {
+                        if let Block::Free(n) = disk[idx] {
+                            if n >= width {
+                                return Some((idx, n));
+                            }
+                        }
+                        None
+                    });
+
+                    match empty_idx {
+                        Some((idx, free_width)) if free_width == width => {
+                            disk.swap(idx, e);
+                        }
+                        Some((idx, free_width)) if free_width > width => {
+                            disk[idx] = disk[e].clone();
+                            disk[e] = Block::Free(width);
+                            disk.0.insert(idx + 1, Block::Free(free_width - width));
+                            e += 1
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            e -= 1;
+        }
+
+        disk.checksum()
+    }
 }
 
 impl Index<usize> for Disk {
@@ -82,78 +155,14 @@ impl Disk {
     }
 }
 
-#[allow(unused_variables)]
-pub fn part1(input: InputType) -> OutType {
-    let mut disk: Disk = input.0.iter().flat_map(|block| block.explode()).collect();
-
-    let mut s = 0;
-    let mut e = disk.len() - 1;
-
-    while s < e {
-        match disk[s] {
-            Block::Data(_, _) => (),
-            Block::Free(_) => loop {
-                match disk[e] {
-                    Block::Free(_) => (),
-                    Block::Data(_, _) => {
-                        disk.swap(s, e);
-                        break;
-                    }
-                }
-                e -= 1;
-            },
-        }
-        s += 1;
-    }
-
-    disk.checksum()
-}
-
-#[allow(unused_variables)]
-pub fn part2(mut disk: InputType) -> OutType {
-    let mut e = disk.len() - 1;
-
-    while e > 0 {
-        match disk[e] {
-            Block::Free(_) => (),
-            Block::Data(i, width) => {
-                let empty_idx = (0..e).find_map(|idx| {
-                    if let Block::Free(n) = disk[idx] {
-                        if n >= width {
-                            return Some((idx, n));
-                        }
-                    }
-                    None
-                });
-
-                match empty_idx {
-                    Some((idx, free_width)) if free_width == width => {
-                        disk.swap(idx, e);
-                    }
-                    Some((idx, free_width)) if free_width > width => {
-                        disk[idx] = disk[e].clone();
-                        disk[e] = Block::Free(width);
-                        disk.0.insert(idx + 1, Block::Free(free_width - width));
-                        e += 1
-                    }
-                    _ => (),
-                }
-            }
-        }
-        e -= 1;
-    }
-
-    disk.checksum()
-}
-
 #[cfg(test)]
 mod tests {
     use util::generate_test;
 
     #[test]
     fn example_0() {
-        let data = parse(r#"12345"#);
-        assert_eq!(part1(data), 62)
+        let data = Day::parse(r#"12345"#);
+        assert_eq!(Day::part1(data), 62)
     }
 
     generate_test!(r#"2333133121414131402"#, 1, 1928);
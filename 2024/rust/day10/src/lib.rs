@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use itertools::Itertools;
-use util::{BitMap, Pos};
+use util::{BitMap, Pos, Solution};
 
 type InputType = Map;
 type OutType = usize;
@@ -14,42 +14,68 @@ pub struct Map {
     max_y: u8,
 }
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    let mut points = HashMap::new();
-    let width = data.lines().next().unwrap().len();
-    let height = data.lines().count();
-    let mut trailheads = BitMap::new(width, height);
-    let mut peaks = BitMap::new(width, height);
-    let mut max_x: u8 = 0;
-    let mut max_y: u8 = 0;
-
-    for (y, line) in data.lines().enumerate() {
-        for (x, char) in line.chars().enumerate() {
-            let pos = Pos::new(x as u8, y as u8);
-            let height: usize = char.to_digit(10).unwrap() as usize;
-            points.insert(pos.clone(), height);
-            if height == 0 {
-                trailheads.insert(&pos);
-            }
-            if height == 9 {
-                peaks.insert(&pos);
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        let mut points = HashMap::new();
+        let width = data.lines().next().unwrap().len();
+        let height = data.lines().count();
+        let mut trailheads = BitMap::new(width, height);
+        let mut peaks = BitMap::new(width, height);
+        let mut max_x: u8 = 0;
+        let mut max_y: u8 = 0;
+
+        for (y, line) in data.lines().enumerate() {
+            for (x, char) in line.chars().enumerate() {
+                let pos = Pos::new(x as u8, y as u8);
+                let height: usize = char.to_digit(10).unwrap() as usize;
+                points.insert(pos.clone(), height);
+                if height == 0 {
+                    trailheads.insert(&pos);
+                }
+                if height == 9 {
+                    peaks.insert(&pos);
+                }
+                if x as u8 > max_x {
+                    max_x = x as u8;
+                }
             }
-            if x as u8 > max_x {
-                max_x = x as u8;
+            if y as u8 > max_y {
+                max_y = y as u8;
             }
         }
-        if y as u8 > max_y {
-            max_y = y as u8;
+
+        Map {
+            points,
+            trailheads,
+            peaks,
+            max_x,
+            max_y,
         }
     }
 
-    Map {
-        points,
-        trailheads,
-        peaks,
-        max_x,
-        max_y,
+    #[allow(unused_variables)]
+    fn part1(map: Self::Input<'_>) -> Self::Output1 {
+        map.trailheads
+            .iter()
+            .cartesian_product(map.peaks.iter())
+            .filter(|(trail, peak)| num_paths(&map, trail, peak, None) > 0)
+            .count()
+    }
+
+    #[allow(unused_variables)]
+    fn part2(map: Self::Input<'_>) -> Self::Output2 {
+        map.trailheads
+            .iter()
+            .cartesian_product(map.peaks.iter())
+            .map(|(trail, peak)| num_paths(&map, &trail, &peak, None))
+            .sum()
     }
 }
 
@@ -84,37 +110,19 @@ fn num_paths(map: &Map, cur: &Pos<u8>, peak: &Pos<u8>, last: Option<&Pos<u8>>) -
         .sum()
 }
 
-#[allow(unused_variables)]
-pub fn part1(map: InputType) -> OutType {
-    map.trailheads
-        .iter()
-        .cartesian_product(map.peaks.iter())
-        .filter(|(trail, peak)| num_paths(&map, trail, peak, None) > 0)
-        .count()
-}
-
-#[allow(unused_variables)]
-pub fn part2(map: InputType) -> OutType {
-    map.trailheads
-        .iter()
-        .cartesian_product(map.peaks.iter())
-        .map(|(trail, peak)| num_paths(&map, &trail, &peak, None))
-        .sum()
-}
-
 #[cfg(test)]
 mod tests {
     use util::generate_test;
 
     #[test]
     fn example_0() {
-        let data = parse(
+        let data = Day::parse(
             r#"0123
 1234
 8765
 9876"#,
         );
-        assert_eq!(part1(data), 1)
+        assert_eq!(Day::part1(data), 1)
     }
 
     generate_test!(
@@ -1,14 +1,41 @@
 use std::{collections::HashMap, ops::Rem};
+use util::Solution;
 
 type InputType = Vec<u64>;
 type OutType = usize;
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    data.trim()
-        .split(" ")
-        .map(|s| s.trim().parse().unwrap())
-        .collect()
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        data.trim()
+            .split(" ")
+            .map(|s| s.trim().parse().unwrap())
+            .collect()
+    }
+
+    #[allow(unused_variables)]
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        let mut cache = HashMap::new();
+        input
+            .into_iter()
+            .map(|x| how_many_stones(x, 25, &mut cache))
+            .sum()
+    }
+
+    #[allow(unused_variables)]
+    fn part2(input: Self::Input<'_>) -> Self::Output2 {
+        let mut cache = HashMap::new();
+        input
+            .into_iter()
+            .map(|x| how_many_stones(x, 75, &mut cache))
+            .sum()
+    }
 }
 
 fn how_many_stones(x: u64, times: usize, cache: &mut HashMap<(u64, usize), usize>) -> usize {
@@ -36,24 +63,6 @@ fn how_many_stones(x: u64, times: usize, cache: &mut HashMap<(u64, usize), usize
     res
 }
 
-#[allow(unused_variables)]
-pub fn part1(input: InputType) -> OutType {
-    let mut cache = HashMap::new();
-    input
-        .into_iter()
-        .map(|x| how_many_stones(x, 25, &mut cache))
-        .sum()
-}
-
-#[allow(unused_variables)]
-pub fn part2(input: InputType) -> OutType {
-    let mut cache = HashMap::new();
-    input
-        .into_iter()
-        .map(|x| how_many_stones(x, 75, &mut cache))
-        .sum()
-}
-
 #[cfg(test)]
 mod tests {
     use util::generate_test;
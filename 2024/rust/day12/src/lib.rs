@@ -1,27 +1,45 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
-use util::{Direction, Pos};
+use util::{Direction, Pos, Solution};
 
 type InputType = HashMap<char, HashSet<Pos<usize>>>;
 type OutType = usize;
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    let mut maps: InputType = HashMap::new();
-    for (y, line) in data.lines().enumerate() {
-        for (x, char) in line.chars().enumerate() {
-            maps.entry(char)
-                .and_modify(|c| {
-                    c.insert(Pos::new(x, y));
-                })
-                .or_insert_with(|| {
-                    let mut map = HashSet::new();
-                    map.insert(Pos::new(x, y));
-                    map
-                });
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        let mut maps: InputType = HashMap::new();
+        for (y, line) in data.lines().enumerate() {
+            for (x, char) in line.chars().enumerate() {
+                maps.entry(char)
+                    .and_modify(|c| {
+                        c.insert(Pos::new(x, y));
+                    })
+                    .or_insert_with(|| {
+                        let mut map = HashSet::new();
+                        map.insert(Pos::new(x, y));
+                        map
+                    });
+            }
         }
+        maps
+    }
+
+    #[allow(unused_variables)]
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        input.into_values().map(needed_fence).sum()
+    }
+
+    #[allow(unused_variables)]
+    fn part2(input: Self::Input<'_>) -> Self::Output2 {
+        input.into_values().map(needed_fence_sides).sum()
     }
-    maps
 }
 
 fn needed_fence(mut map: HashSet<Pos<usize>>) -> usize {
@@ -135,16 +153,6 @@ fn needed_fence_sides(mut map: HashSet<Pos<usize>>) -> usize {
     total
 }
 
-#[allow(unused_variables)]
-pub fn part1(input: InputType) -> OutType {
-    input.into_values().map(needed_fence).sum()
-}
-
-#[allow(unused_variables)]
-pub fn part2(input: InputType) -> OutType {
-    input.into_values().map(needed_fence_sides).sum()
-}
-
 #[cfg(test)]
 mod tests {
     use util::generate_test;
@@ -166,19 +174,19 @@ MMMISSJEEE"#,
 
     #[test]
     fn example_21() {
-        let data = parse(
+        let data = Day::parse(
             r#"EEEEE
 EXXXX
 EEEEE
 EXXXX
 EEEEE"#,
         );
-        assert_eq!(part2(data), 236)
+        assert_eq!(Day::part2(data), 236)
     }
 
     #[test]
     fn example_22() {
-        let data = parse(
+        let data = Day::parse(
             r#"AAAAAA
 AAABBA
 AAABBA
@@ -186,7 +194,7 @@ ABBAAA
 ABBAAA
 AAAAAA"#,
         );
-        assert_eq!(part2(data), 368)
+        assert_eq!(Day::part2(data), 368)
     }
 
     generate_test!(
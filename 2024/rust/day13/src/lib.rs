@@ -6,7 +6,7 @@ use nom::{
     sequence::tuple,
 };
 use parser::FromDig;
-use util::Pos;
+use util::{Pos, Solution};
 
 type InputType = Vec<Machine>;
 type OutType = i64;
@@ -18,81 +18,89 @@ pub struct Machine {
     prize: Pos<i64>,
 }
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    let (rest, machines) = separated_list1(
-        many1(newline),
-        map(
-            tuple((
-                tag("Button A: X+"),
-                <i64 as FromDig>::from_dig,
-                tag(", Y+"),
-                <i64 as FromDig>::from_dig,
-                newline,
-                tag("Button B: X+"),
-                <i64 as FromDig>::from_dig,
-                tag(", Y+"),
-                <i64 as FromDig>::from_dig,
-                newline,
-                tag("Prize: X="),
-                <i64 as FromDig>::from_dig,
-                tag(", Y="),
-                <i64 as FromDig>::from_dig,
-            )),
-            |(_, ax, _, ay, _, _, bx, _, by, _, _, px, _, py)| Machine {
-                buttona: (ax, ay),
-                buttonb: (bx, by),
-                prize: Pos::new(px, py),
-            },
-        ),
-    )(data)
-    .unwrap();
-    assert_eq!("", rest.trim());
-    machines
-}
+pub struct Day;
 
-#[allow(unused_variables)]
-pub fn part1(input: InputType) -> OutType {
-    input
-        .iter()
-        .map(|machine| {
-            let x1 = machine.buttona.0;
-            let x2 = machine.buttona.1;
-            let y1 = machine.buttonb.0;
-            let y2 = machine.buttonb.1;
-            let z1 = machine.prize.x;
-            let z2 = machine.prize.y;
-
-            let b = (z2 * x1 - z1 * x2) / (y2 * x1 - y1 * x2);
-            let a = (z1 - b * y1) / x1;
-            if (x1 * a + y1 * b, x2 * a + y2 * b) != (z1, z2) {
-                return 0;
-            }
-            a * 3 + b
-        })
-        .sum()
-}
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        let (rest, machines) = separated_list1(
+            many1(newline),
+            map(
+                tuple((
+                    tag("Button A: X+"),
+                    <i64 as FromDig>::from_dig,
+                    tag(", Y+"),
+                    <i64 as FromDig>::from_dig,
+                    newline,
+                    tag("Button B: X+"),
+                    <i64 as FromDig>::from_dig,
+                    tag(", Y+"),
+                    <i64 as FromDig>::from_dig,
+                    newline,
+                    tag("Prize: X="),
+                    <i64 as FromDig>::from_dig,
+                    tag(", Y="),
+                    <i64 as FromDig>::from_dig,
+                )),
+                |(_, ax, _, ay, _, _, bx, _, by, _, _, px, _, py)| Machine {
+                    buttona: (ax, ay),
+                    buttonb: (bx, by),
+                    prize: Pos::new(px, py),
+                },
+            ),
+        )(data)
+        .unwrap();
+        assert_eq!("", rest.trim());
+        machines
+    }
+
+    #[allow(unused_variables)]
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        input
+            .iter()
+            .map(|machine| {
+                let x1 = machine.buttona.0;
+                let x2 = machine.buttona.1;
+                let y1 = machine.buttonb.0;
+                let y2 = machine.buttonb.1;
+                let z1 = machine.prize.x;
+                let z2 = machine.prize.y;
 
-#[allow(unused_variables)]
-pub fn part2(input: InputType) -> OutType {
-    input
-        .iter()
-        .map(|machine| {
-            let x1 = machine.buttona.0;
-            let x2 = machine.buttona.1;
-            let y1 = machine.buttonb.0;
-            let y2 = machine.buttonb.1;
-            let z1 = machine.prize.x + 10000000000000;
-            let z2 = machine.prize.y + 10000000000000;
-
-            let b = (z2 * x1 - z1 * x2) / (y2 * x1 - y1 * x2);
-            let a = (z1 - b * y1) / x1;
-            if (x1 * a + y1 * b, x2 * a + y2 * b) != (z1, z2) {
-                return 0;
-            }
-            a * 3 + b
-        })
-        .sum()
+                let b = (z2 * x1 - z1 * x2) / (y2 * x1 - y1 * x2);
+                let a = (z1 - b * y1) / x1;
+                if (x1 * a + y1 * b, x2 * a + y2 * b) != (z1, z2) {
+                    return 0;
+                }
+                a * 3 + b
+            })
+            .sum()
+    }
+
+    #[allow(unused_variables)]
+    fn part2(input: Self::Input<'_>) -> Self::Output2 {
+        input
+            .iter()
+            .map(|machine| {
+                let x1 = machine.buttona.0;
+                let x2 = machine.buttona.1;
+                let y1 = machine.buttonb.0;
+                let y2 = machine.buttonb.1;
+                let z1 = machine.prize.x + 10000000000000;
+                let z2 = machine.prize.y + 10000000000000;
+
+                let b = (z2 * x1 - z1 * x2) / (y2 * x1 - y1 * x2);
+                let a = (z1 - b * y1) / x1;
+                if (x1 * a + y1 * b, x2 * a + y2 * b) != (z1, z2) {
+                    return 0;
+                }
+                a * 3 + b
+            })
+            .sum()
+    }
 }
 
 #[cfg(test)]
@@ -101,12 +109,12 @@ mod tests {
 
     #[test]
     fn example_0() {
-        let data = parse(
+        let data = Day::parse(
             r#"Button A: X+94, Y+34
 Button B: X+22, Y+67
 Prize: X=8400, Y=5400"#,
         );
-        assert_eq!(part1(data), 280)
+        assert_eq!(Day::part1(data), 280)
     }
 
     generate_test!(
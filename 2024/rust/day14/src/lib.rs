@@ -5,7 +5,7 @@ use nom::{
     sequence::tuple,
 };
 use parser::{signed_dig_pair, signed_point};
-use util::Pos;
+use util::{Pos, Solution};
 
 type InputType = Robots;
 type OutType = usize;
@@ -75,66 +75,74 @@ impl Robots {
     }
 }
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    let (rest, bots) = separated_list1(
-        newline,
-        map(
-            tuple((
-                tag("p="),
-                signed_point(","),
-                tag(" v="),
-                signed_dig_pair(","),
-            )),
-            |(_, p, _, v)| Robot { p, v },
-        ),
-    )(data)
-    .unwrap();
-
-    assert_eq!("", rest.trim());
-
-    Robots {
-        width: 101,
-        height: 103,
-        bots,
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        let (rest, bots) = separated_list1(
+            newline,
+            map(
+                tuple((
+                    tag("p="),
+                    signed_point(","),
+                    tag(" v="),
+                    signed_dig_pair(","),
+                )),
+                |(_, p, _, v)| Robot { p, v },
+            ),
+        )(data)
+        .unwrap();
+
+        assert_eq!("", rest.trim());
+
+        Robots {
+            width: 101,
+            height: 103,
+            bots,
+        }
     }
-}
 
-#[allow(unused_variables)]
-pub fn part1(mut robots: InputType) -> OutType {
-    robots.move_n(100);
-    robots.safety_score()
-}
+    #[allow(unused_variables)]
+    fn part1(mut robots: Self::Input<'_>) -> Self::Output1 {
+        robots.move_n(100);
+        robots.safety_score()
+    }
 
-#[allow(unused_variables)]
-pub fn part2(mut robots: InputType) -> OutType {
-    let mut iter = 0;
-    let mut lowest_safety_score = usize::MAX;
-    let mut lowest_safety_score_iter = 0;
-    let mut since_last_lowest = 0;
+    #[allow(unused_variables)]
+    fn part2(mut robots: Self::Input<'_>) -> Self::Output2 {
+        let mut iter = 0;
+        let mut lowest_safety_score = usize::MAX;
+        let mut lowest_safety_score_iter = 0;
+        let mut since_last_lowest = 0;
 
-    loop {
-        iter += 1;
-        since_last_lowest += 1;
+        loop {
+            iter += 1;
+            since_last_lowest += 1;
 
-        robots.move_n(1);
+            robots.move_n(1);
 
-        let safety_score = robots.safety_score();
-        if safety_score < lowest_safety_score {
-            lowest_safety_score = safety_score;
-            lowest_safety_score_iter = iter;
+            let safety_score = robots.safety_score();
+            if safety_score < lowest_safety_score {
+                lowest_safety_score = safety_score;
+                lowest_safety_score_iter = iter;
 
-            // println!("\nIter: {iter}\n");
-            // println!("{:?}", robots);
-            // println!();
-        }
+                // println!("\nIter: {iter}\n");
+                // println!("{:?}", robots);
+                // println!();
+            }
 
-        if since_last_lowest >= 10000 {
-            break;
+            if since_last_lowest >= 10000 {
+                break;
+            }
         }
-    }
 
-    lowest_safety_score_iter
+        lowest_safety_score_iter
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use util::{BitMap, Direction, Pos};
+use util::{BitMap, Direction, Pos, Solution};
 
 type InputType = Map;
 type OutType = usize;
@@ -176,42 +176,119 @@ impl Map {
     }
 }
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    let width = data.lines().next().unwrap().len();
-    let height = data
-        .lines()
-        .filter(|l| !l.is_empty() && l.starts_with('#'))
-        .count();
-    let mut robot = None;
-    let mut boxesl = BitMap::new(width, height);
-    let mut walls = BitMap::new(width, height);
-    let mut moves = Vec::new();
-
-    for (y, line) in data.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            match c {
-                '.' => (),
-                '#' => walls.insert(&Pos::new(x, y)),
-                'O' => boxesl.insert(&Pos::new(x, y)),
-                '@' => robot = Some(Pos::new(x, y)),
-                '<' => moves.push(Direction::W),
-                '>' => moves.push(Direction::E),
-                '^' => moves.push(Direction::N),
-                'v' => moves.push(Direction::S),
-                _ => unimplemented!(),
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        let width = data.lines().next().unwrap().len();
+        let height = data
+            .lines()
+            .filter(|l| !l.is_empty() && l.starts_with('#'))
+            .count();
+        let mut robot = None;
+        let mut boxesl = BitMap::new(width, height);
+        let mut walls = BitMap::new(width, height);
+        let mut moves = Vec::new();
+
+        for (y, line) in data.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '.' => (),
+                    '#' => walls.insert(&Pos::new(x, y)),
+                    'O' => boxesl.insert(&Pos::new(x, y)),
+                    '@' => robot = Some(Pos::new(x, y)),
+                    '<' => moves.push(Direction::W),
+                    '>' => moves.push(Direction::E),
+                    '^' => moves.push(Direction::N),
+                    'v' => moves.push(Direction::S),
+                    _ => unimplemented!(),
+                }
             }
         }
+
+        Map {
+            robot: robot.unwrap(),
+            boxesl,
+            boxesr: BitMap::new(width, height),
+            walls,
+            moves,
+            width,
+            height,
+        }
     }
 
-    Map {
-        robot: robot.unwrap(),
-        boxesl,
-        boxesr: BitMap::new(width, height),
-        walls,
-        moves,
-        width,
-        height,
+    #[allow(unused_variables)]
+    fn part1(mut map: Self::Input<'_>) -> Self::Output1 {
+        for mv in map.moves.clone() {
+            let next = map.robot.translate(&mv).unwrap();
+
+            if map.walls.contains(&next) {
+                continue;
+            }
+
+            if map.boxesl.contains(&next) {
+                if maybe_move_box(&next, &mv, &mut map) {
+                    map.robot = next;
+                }
+                continue;
+            }
+
+            map.robot = next;
+        }
+
+        map.boxesl.iter().map(|p| 100 * p.y + p.x).sum()
+    }
+
+    #[allow(unused_variables)]
+    fn part2(
+        Map {
+            robot,
+            boxesl,
+            boxesr,
+            walls,
+            moves,
+            width,
+            height,
+        }: Self::Input<'_>,
+    ) -> Self::Output2 {
+        let new_robot = Pos::new(robot.x * 2, robot.y);
+        let mut new_boxesl = BitMap::new(width * 2, height);
+        let mut new_boxesr = BitMap::new(width * 2, height);
+        for p in boxesl.iter() {
+            new_boxesl.insert(&Pos::new(p.x * 2, p.y));
+            new_boxesr.insert(&Pos::new(p.x * 2 + 1, p.y));
+        }
+        let mut new_walls = BitMap::new(width * 2, height);
+        for p in walls.iter() {
+            new_walls.insert(&Pos::new(p.x * 2, p.y));
+            new_walls.insert(&Pos::new(p.x * 2 + 1, p.y));
+        }
+        let mut map = Map {
+            robot: new_robot,
+            boxesl: new_boxesl,
+            boxesr: new_boxesr,
+            walls: new_walls,
+            width: width * 2,
+            height,
+            moves,
+        };
+
+        for mv in map.moves.clone() {
+            if let Some(moves) = map.maybe_move(&map.robot, &mv) {
+                let backup = map.clone();
+
+                for f in moves {
+                    f(&mut map);
+                }
+            }
+        }
+
+        map.boxesl.iter().map(|p| 100 * p.y + p.x).sum()
     }
 }
 
@@ -236,75 +313,6 @@ fn maybe_move_box(p: &Pos<usize>, d: &Direction, map: &mut Map) -> bool {
     true
 }
 
-#[allow(unused_variables)]
-pub fn part1(mut map: InputType) -> OutType {
-    for mv in map.moves.clone() {
-        let next = map.robot.translate(&mv).unwrap();
-
-        if map.walls.contains(&next) {
-            continue;
-        }
-
-        if map.boxesl.contains(&next) {
-            if maybe_move_box(&next, &mv, &mut map) {
-                map.robot = next;
-            }
-            continue;
-        }
-
-        map.robot = next;
-    }
-
-    map.boxesl.iter().map(|p| 100 * p.y + p.x).sum()
-}
-
-#[allow(unused_variables)]
-pub fn part2(
-    Map {
-        robot,
-        boxesl,
-        boxesr,
-        walls,
-        moves,
-        width,
-        height,
-    }: InputType,
-) -> OutType {
-    let new_robot = Pos::new(robot.x * 2, robot.y);
-    let mut new_boxesl = BitMap::new(width * 2, height);
-    let mut new_boxesr = BitMap::new(width * 2, height);
-    for p in boxesl.iter() {
-        new_boxesl.insert(&Pos::new(p.x * 2, p.y));
-        new_boxesr.insert(&Pos::new(p.x * 2 + 1, p.y));
-    }
-    let mut new_walls = BitMap::new(width * 2, height);
-    for p in walls.iter() {
-        new_walls.insert(&Pos::new(p.x * 2, p.y));
-        new_walls.insert(&Pos::new(p.x * 2 + 1, p.y));
-    }
-    let mut map = Map {
-        robot: new_robot,
-        boxesl: new_boxesl,
-        boxesr: new_boxesr,
-        walls: new_walls,
-        width: width * 2,
-        height,
-        moves,
-    };
-
-    for mv in map.moves.clone() {
-        if let Some(moves) = map.maybe_move(&map.robot, &mv) {
-            let backup = map.clone();
-
-            for f in moves {
-                f(&mut map);
-            }
-        }
-    }
-
-    map.boxesl.iter().map(|p| 100 * p.y + p.x).sum()
-}
-
 #[cfg(test)]
 mod tests {
     use util::generate_test;
@@ -328,7 +336,7 @@ mod tests {
 
     #[test]
     fn example_2_0() {
-        let data = parse(
+        let data = Day::parse(
             r#"#######
 #...#.#
 #.....#
@@ -339,12 +347,12 @@ mod tests {
 
 <vv<<^^<<^^"#,
         );
-        assert_eq!(part2(data), 618)
+        assert_eq!(Day::part2(data), 618)
     }
 
     #[test]
     fn example_x1() {
-        let data = parse(
+        let data = Day::parse(
             r#"##########
 #........#
 #....O#..#
@@ -357,7 +365,7 @@ mod tests {
 
 <vv<<<^"#,
         );
-        assert_eq!(part2(data), 2580)
+        assert_eq!(Day::part2(data), 2580)
     }
 
     generate_test!(
@@ -1,5 +1,5 @@
 use itertools::Itertools;
-use util::{BitMap, Direction, Pos};
+use util::{BitMap, Direction, Pos, Solution};
 
 type InputType = Maze;
 type OutType = usize;
@@ -12,34 +12,69 @@ pub struct Maze {
     facing: Direction,
 }
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    let width = data.lines().next().unwrap().len();
-    let height = data
-        .lines()
-        .filter(|l| !l.is_empty() && l.starts_with('#'))
-        .count();
-    let mut p = None;
-    let mut e = None;
-    let mut walls = BitMap::new(width, height);
-
-    for (y, line) in data.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            match c {
-                '.' => (),
-                '#' => walls.insert(&Pos::new(x, y)),
-                'S' => p = Some(Pos::new(x, y)),
-                'E' => e = Some(Pos::new(x, y)),
-                _ => unimplemented!(),
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        let width = data.lines().next().unwrap().len();
+        let height = data
+            .lines()
+            .filter(|l| !l.is_empty() && l.starts_with('#'))
+            .count();
+        let mut p = None;
+        let mut e = None;
+        let mut walls = BitMap::new(width, height);
+
+        for (y, line) in data.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '.' => (),
+                    '#' => walls.insert(&Pos::new(x, y)),
+                    'S' => p = Some(Pos::new(x, y)),
+                    'E' => e = Some(Pos::new(x, y)),
+                    _ => unimplemented!(),
+                }
             }
         }
+
+        Maze {
+            walls,
+            p: p.unwrap(),
+            e: e.unwrap(),
+            facing: Direction::E,
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn part1(maze: Self::Input<'_>) -> Self::Output1 {
+        pathfinding::directed::astar::astar(
+            &(maze.p.clone(), maze.facing.clone()),
+            |(p, facing): &(Pos<usize>, Direction)| successors(&maze, p, facing),
+            |(p, _d)| p.x.abs_diff(maze.e.x) + p.y.abs_diff(maze.e.y),
+            |(p, _d)| *p == maze.e,
+        )
+        .unwrap()
+        .1
     }
 
-    Maze {
-        walls,
-        p: p.unwrap(),
-        e: e.unwrap(),
-        facing: Direction::E,
+    #[allow(unused_variables)]
+    fn part2(maze: Self::Input<'_>) -> Self::Output2 {
+        pathfinding::directed::astar::astar_bag(
+            &(maze.p.clone(), maze.facing.clone()),
+            |(p, facing): &(Pos<usize>, Direction)| successors(&maze, p, facing),
+            |(p, _d)| p.x.abs_diff(maze.e.x) + p.y.abs_diff(maze.e.y),
+            |(p, _d)| *p == maze.e,
+        )
+        .unwrap()
+        .0
+        .flat_map(|paths| paths.into_iter().map(|(point, _)| point))
+        .unique()
+        .count()
     }
 }
 
@@ -70,33 +105,6 @@ fn successors(
     possible_moves
 }
 
-#[allow(unused_variables)]
-pub fn part1(maze: InputType) -> OutType {
-    pathfinding::directed::astar::astar(
-        &(maze.p.clone(), maze.facing.clone()),
-        |(p, facing): &(Pos<usize>, Direction)| successors(&maze, p, facing),
-        |(p, _d)| p.x.abs_diff(maze.e.x) + p.y.abs_diff(maze.e.y),
-        |(p, _d)| *p == maze.e,
-    )
-    .unwrap()
-    .1
-}
-
-#[allow(unused_variables)]
-pub fn part2(maze: InputType) -> OutType {
-    pathfinding::directed::astar::astar_bag(
-        &(maze.p.clone(), maze.facing.clone()),
-        |(p, facing): &(Pos<usize>, Direction)| successors(&maze, p, facing),
-        |(p, _d)| p.x.abs_diff(maze.e.x) + p.y.abs_diff(maze.e.y),
-        |(p, _d)| *p == maze.e,
-    )
-    .unwrap()
-    .0
-    .flat_map(|paths| paths.into_iter().map(|(point, _)| point))
-    .unique()
-    .count()
-}
-
 #[cfg(test)]
 mod tests {
     use util::generate_test;
@@ -5,6 +5,7 @@ use nom::{
     sequence::{preceded, tuple},
 };
 use parser::{separated_digits, FromDig};
+use util::Solution;
 
 type InputType = Computer;
 type OutType = String;
@@ -83,70 +84,78 @@ impl Computer {
     }
 }
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    let (rest, computer) = map(
-        tuple((
-            preceded(tag("Register A: "), <i64 as FromDig>::from_dig),
-            newline,
-            preceded(tag("Register B: "), <i64 as FromDig>::from_dig),
-            newline,
-            preceded(tag("Register C: "), <i64 as FromDig>::from_dig),
-            newline,
-            newline,
-            preceded(tag("Program: "), separated_digits(",")),
-        )),
-        |(a, _, b, _, c, _, _, program)| Computer {
-            register_a: a,
-            register_b: b,
-            register_c: c,
-            program,
-            instruction_pointer: 0,
-        },
-    )(data)
-    .unwrap();
-    assert_eq!("", rest.trim());
-    computer
-}
-
-#[allow(unused_variables)]
-pub fn part1(mut computer: InputType) -> OutType {
-    computer
-        .run()
-        .iter()
-        .map(|n| n.to_string())
-        .collect::<Vec<_>>()
-        .join(",")
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = i64;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        let (rest, computer) = map(
+            tuple((
+                preceded(tag("Register A: "), <i64 as FromDig>::from_dig),
+                newline,
+                preceded(tag("Register B: "), <i64 as FromDig>::from_dig),
+                newline,
+                preceded(tag("Register C: "), <i64 as FromDig>::from_dig),
+                newline,
+                newline,
+                preceded(tag("Program: "), separated_digits(",")),
+            )),
+            |(a, _, b, _, c, _, _, program)| Computer {
+                register_a: a,
+                register_b: b,
+                register_c: c,
+                program,
+                instruction_pointer: 0,
+            },
+        )(data)
+        .unwrap();
+        assert_eq!("", rest.trim());
+        computer
+    }
 
-#[allow(unused_variables)]
-pub fn part2(computer: InputType) -> i64 {
-    let orig_computer = computer.clone();
+    #[allow(unused_variables)]
+    fn part1(mut computer: Self::Input<'_>) -> Self::Output1 {
+        computer
+            .run()
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
 
-    let mut factors = vec![0; orig_computer.program.len()];
+    #[allow(unused_variables)]
+    fn part2(computer: Self::Input<'_>) -> Self::Output2 {
+        let orig_computer = computer.clone();
 
-    loop {
-        let mut init_a = 0;
-        for (i, f) in factors.iter().enumerate() {
-            init_a += 8u64.pow(i as u32) * f
-        }
+        let mut factors = vec![0; orig_computer.program.len()];
 
-        let mut computer = computer.clone();
-        computer.register_a = init_a as i64;
+        loop {
+            let mut init_a = 0;
+            for (i, f) in factors.iter().enumerate() {
+                init_a += 8u64.pow(i as u32) * f
+            }
 
-        let output = computer.run();
-        if output == computer.program {
-            return init_a as i64;
-        }
+            let mut computer = computer.clone();
+            computer.register_a = init_a as i64;
 
-        for i in (0..computer.program.len()).rev() {
-            if output.len() < i {
-                factors[i] += 1;
-                break;
+            let output = computer.run();
+            if output == computer.program {
+                return init_a as i64;
             }
-            if output[i] != computer.program[i] {
-                factors[i] += 1;
-                break;
+
+            for i in (0..computer.program.len()).rev() {
+                if output.len() < i {
+                    factors[i] += 1;
+                    break;
+                }
+                if output[i] != computer.program[i] {
+                    factors[i] += 1;
+                    break;
+                }
             }
         }
     }
@@ -1,22 +1,49 @@
 use std::collections::{HashMap, HashSet};
+use util::Solution;
 
 type InputType<'a> = (HashSet<&'a str>, Vec<&'a str>);
 type OutType = usize;
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    let mut data = data.lines();
-    let available = data
-        .next()
-        .unwrap()
-        .split(",")
-        .map(|a| a.trim())
-        .collect::<HashSet<_>>();
-    let needed = data
-        .map(|x| x.trim())
-        .filter(|x| !x.is_empty())
-        .collect::<Vec<_>>();
-    (available, needed)
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType<'a>;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        let mut data = data.lines();
+        let available = data
+            .next()
+            .unwrap()
+            .split(",")
+            .map(|a| a.trim())
+            .collect::<HashSet<_>>();
+        let needed = data
+            .map(|x| x.trim())
+            .filter(|x| !x.is_empty())
+            .collect::<Vec<_>>();
+        (available, needed)
+    }
+
+    #[allow(unused_variables)]
+    fn part1((available, needed): Self::Input<'_>) -> Self::Output1 {
+        let mut cache: HashMap<&str, usize> = HashMap::new();
+        needed
+            .iter()
+            .filter(|n| can_construct(&available, &mut cache, n) > 0)
+            .count()
+    }
+
+    #[allow(unused_variables)]
+    fn part2((available, needed): Self::Input<'_>) -> Self::Output2 {
+        let mut cache: HashMap<&str, usize> = HashMap::new();
+        needed
+            .iter()
+            .map(|n| can_construct(&available, &mut cache, n))
+            .sum()
+    }
 }
 
 fn can_construct<'a>(
@@ -42,24 +69,6 @@ fn can_construct<'a>(
     n
 }
 
-#[allow(unused_variables)]
-pub fn part1((available, needed): InputType) -> OutType {
-    let mut cache: HashMap<&str, usize> = HashMap::new();
-    needed
-        .iter()
-        .filter(|n| can_construct(&available, &mut cache, n) > 0)
-        .count()
-}
-
-#[allow(unused_variables)]
-pub fn part2((available, needed): InputType) -> OutType {
-    let mut cache: HashMap<&str, usize> = HashMap::new();
-    needed
-        .iter()
-        .map(|n| can_construct(&available, &mut cache, n))
-        .sum()
-}
-
 #[cfg(test)]
 mod tests {
     use util::generate_test;
@@ -1,4 +1,4 @@
-use util::{BitMap, Pos};
+use util::{BitMap, Pos, Solution};
 
 type InputType = Map;
 type OutType = usize;
@@ -9,32 +9,50 @@ pub struct Map {
     end: Pos<usize>,
 }
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    let height = data.lines().count();
-    let width = data.lines().next().unwrap().len();
-    let mut start = None;
-    let mut end = None;
-
-    let mut walls = BitMap::new(width, height);
-    for (y, line) in data.lines().enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            let p = Pos::new(x, y);
-
-            match c {
-                '.' => (),
-                '#' => walls.insert(&p),
-                'S' => start = Some(p),
-                'E' => end = Some(p),
-                c => unimplemented!("{c}"),
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        let height = data.lines().count();
+        let width = data.lines().next().unwrap().len();
+        let mut start = None;
+        let mut end = None;
+
+        let mut walls = BitMap::new(width, height);
+        for (y, line) in data.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let p = Pos::new(x, y);
+
+                match c {
+                    '.' => (),
+                    '#' => walls.insert(&p),
+                    'S' => start = Some(p),
+                    'E' => end = Some(p),
+                    c => unimplemented!("{c}"),
+                }
             }
         }
+
+        Map {
+            walls,
+            start: start.unwrap(),
+            end: end.unwrap(),
+        }
+    }
+
+    #[allow(unused_variables)]
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        count_cheats(&input, 100, 2)
     }
 
-    Map {
-        walls,
-        start: start.unwrap(),
-        end: end.unwrap(),
+    #[allow(unused_variables)]
+    fn part2(input: Self::Input<'_>) -> Self::Output2 {
+        count_cheats(&input, 100, 20)
     }
 }
 
@@ -73,16 +91,6 @@ fn count_cheats(map: &Map, min_saved: usize, max_cheat: usize) -> usize {
         .count()
 }
 
-#[allow(unused_variables)]
-pub fn part1(input: InputType) -> OutType {
-    count_cheats(&input, 100, 2)
-}
-
-#[allow(unused_variables)]
-pub fn part2(input: InputType) -> OutType {
-    count_cheats(&input, 100, 20)
-}
-
 #[cfg(test)]
 mod tests {
     use util::generate_test;
@@ -105,7 +113,7 @@ mod tests {
 
     #[test]
     fn ex1() {
-        let map = parse(SAMPLE);
+        let map = Day::parse(SAMPLE);
         assert_eq!(1, count_cheats(&map, 64, 2));
         assert_eq!(2, count_cheats(&map, 40, 2));
         assert_eq!(3, count_cheats(&map, 38, 2));
@@ -121,7 +129,7 @@ mod tests {
 
     #[test]
     fn ex2() {
-        let map = parse(SAMPLE);
+        let map = Day::parse(SAMPLE);
         assert_eq!(3, count_cheats(&map, 76, 20));
         assert_eq!(7, count_cheats(&map, 74, 20));
         assert_eq!(29, count_cheats(&map, 72, 20));
@@ -4,6 +4,7 @@ use std::{
     iter,
     sync::LazyLock,
 };
+use util::Solution;
 
 use itertools::Itertools;
 
@@ -102,9 +103,51 @@ fn find_shortest_paths(
     paths
 }
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    data.lines().map(|l| l.chars().collect()).collect()
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        data.lines().map(|l| l.chars().collect()).collect()
+    }
+
+    #[allow(unused_variables)]
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        let mut cache = HashMap::new();
+        input
+            .iter()
+            .map(|line| {
+                find_shortest_sequence(line.iter(), 2, true, &mut cache)
+                    * line
+                        .iter()
+                        .collect::<String>()
+                        .trim_end_matches('A')
+                        .parse::<usize>()
+                        .unwrap()
+            })
+            .sum()
+    }
+
+    #[allow(unused_variables)]
+    fn part2(input: Self::Input<'_>) -> Self::Output2 {
+        let mut cache = HashMap::new();
+        input
+            .iter()
+            .map(|line| {
+                find_shortest_sequence(line.iter(), 25, true, &mut cache)
+                    * line
+                        .iter()
+                        .collect::<String>()
+                        .trim_end_matches('A')
+                        .parse::<usize>()
+                        .unwrap()
+            })
+            .sum()
+    }
 }
 
 fn find_shortest_sequence<'a>(
@@ -154,40 +197,6 @@ fn find_shortest_sequence<'a>(
     res
 }
 
-#[allow(unused_variables)]
-pub fn part1(input: InputType) -> OutType {
-    let mut cache = HashMap::new();
-    input
-        .iter()
-        .map(|line| {
-            find_shortest_sequence(line.iter(), 2, true, &mut cache)
-                * line
-                    .iter()
-                    .collect::<String>()
-                    .trim_end_matches('A')
-                    .parse::<usize>()
-                    .unwrap()
-        })
-        .sum()
-}
-
-#[allow(unused_variables)]
-pub fn part2(input: InputType) -> OutType {
-    let mut cache = HashMap::new();
-    input
-        .iter()
-        .map(|line| {
-            find_shortest_sequence(line.iter(), 25, true, &mut cache)
-                * line
-                    .iter()
-                    .collect::<String>()
-                    .trim_end_matches('A')
-                    .parse::<usize>()
-                    .unwrap()
-        })
-        .sum()
-}
-
 #[cfg(test)]
 mod tests {
     use util::generate_test;
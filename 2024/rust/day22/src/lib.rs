@@ -1,4 +1,5 @@
 use std::{collections::HashMap, iter};
+use util::Solution;
 
 use itertools::iterate;
 use itertools::Itertools;
@@ -8,9 +9,42 @@ use rayon::iter::{ParallelBridge, ParallelIterator};
 type InputType<'a> = Box<dyn Iterator<Item = i64> + 'a + Send>;
 type OutType = i64;
 
-#[allow(unused_variables)]
-pub fn parse(data: &'_ str) -> InputType<'_> {
-    Box::new(data.lines().map(|l| l.parse().unwrap()))
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType<'a>;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        Box::new(data.lines().map(|l| l.parse().unwrap()))
+    }
+
+    #[allow(unused_variables)]
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        input.map(|p| iterate(p, step).nth(2000).unwrap()).sum()
+    }
+
+    #[allow(unused_variables)]
+    fn part2(input: Self::Input<'_>) -> Self::Output2 {
+        *input
+            .par_bridge()
+            .flat_map(|n| n_sequences(n, 2000))
+            .fold(HashMap::new, |mut counts, (k, v)| {
+                *counts.entry(k).or_insert(0) += v;
+                counts
+            })
+            .reduce(HashMap::new, |mut a, b| {
+                for (k, v) in b {
+                    *a.entry(k).or_insert(0) += v;
+                }
+                a
+            })
+            .values()
+            .max()
+            .unwrap()
+    }
 }
 
 fn step(secret: &i64) -> i64 {
@@ -19,31 +53,6 @@ fn step(secret: &i64) -> i64 {
     (secret ^ secret << 11) % 16777216
 }
 
-#[allow(unused_variables)]
-pub fn part1(input: InputType) -> OutType {
-    input.map(|p| iterate(p, step).nth(2000).unwrap()).sum()
-}
-
-#[allow(unused_variables)]
-pub fn part2(input: InputType) -> OutType {
-    *input
-        .par_bridge()
-        .flat_map(|n| n_sequences(n, 2000))
-        .fold(HashMap::new, |mut counts, (k, v)| {
-            *counts.entry(k).or_insert(0) += v;
-            counts
-        })
-        .reduce(HashMap::new, |mut a, b| {
-            for (k, v) in b {
-                *a.entry(k).or_insert(0) += v;
-            }
-            a
-        })
-        .values()
-        .max()
-        .unwrap()
-}
-
 fn n_sequences(secret: i64, n: i64) -> impl ParallelIterator<Item = ((i64, i64, i64, i64), i64)> {
     let mut state = secret;
 
@@ -2,82 +2,91 @@ use std::{
     collections::{HashMap, HashSet},
     iter,
 };
+use util::Solution;
 
 use itertools::Itertools;
 
 type InputType<'a> = Box<dyn Iterator<Item = (&'a str, &'a str)> + 'a>;
 type OutType = usize;
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType<'_> {
-    Box::new(data.lines().map(|l| {
-        let (left, right) = l.split_once("-").unwrap();
-        (left, right)
-    }))
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType<'a>;
+    type Output1 = OutType;
+    type Output2 = String;
 
-#[allow(unused_variables)]
-pub fn part1(input: InputType) -> OutType {
-    let mut connections: HashMap<&str, HashSet<&str>> = HashMap::new();
-    for (left, right) in input {
-        connections.entry(left).or_default().insert(right);
-        connections.entry(right).or_default().insert(left);
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        Box::new(data.lines().map(|l| {
+            let (left, right) = l.split_once("-").unwrap();
+            (left, right)
+        }))
     }
 
-    connections
-        .iter()
-        .filter(|(k, v)| k.starts_with("t"))
-        .flat_map(|(k, v)| {
-            iter::once(k)
-                .chain(v.iter())
-                .combinations_with_replacement(3)
-                .filter(|x| {
-                    let a = x[0];
-                    let b = x[1];
-                    let c = x[2];
+    #[allow(unused_variables)]
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        let mut connections: HashMap<&str, HashSet<&str>> = HashMap::new();
+        for (left, right) in input {
+            connections.entry(left).or_default().insert(right);
+            connections.entry(right).or_default().insert(left);
+        }
 
-                    let a_hs = connections.get(a).unwrap();
-                    let b_hs = connections.get(b).unwrap();
-                    let c_hs = connections.get(c).unwrap();
+        connections
+            .iter()
+            .filter(|(k, v)| k.starts_with("t"))
+            .flat_map(|(k, v)| {
+                iter::once(k)
+                    .chain(v.iter())
+                    .combinations_with_replacement(3)
+                    .filter(|x| {
+                        let a = x[0];
+                        let b = x[1];
+                        let c = x[2];
 
-                    a_hs.contains(b)
-                        && a_hs.contains(c)
-                        && b_hs.contains(a)
-                        && b_hs.contains(c)
-                        && c_hs.contains(a)
-                        && c_hs.contains(b)
-                })
-        })
-        .map(|v| v.into_iter().sorted().collect_vec())
-        .unique()
-        .count()
+                        let a_hs = connections.get(a).unwrap();
+                        let b_hs = connections.get(b).unwrap();
+                        let c_hs = connections.get(c).unwrap();
 
-    // connections
-    //     .keys()
-    //     .combinations_with_replacement(3)
-    //     .filter(|x| {
-    //         let a = x[0];
-    //         let b = x[1];
-    //         let c = x[2];
-    //
-    //         let a_hs = connections.get(a).unwrap();
-    //         let b_hs = connections.get(b).unwrap();
-    //         let c_hs = connections.get(c).unwrap();
-    //
-    //         (a_hs.contains(b)
-    //             && a_hs.contains(c)
-    //             && b_hs.contains(a)
-    //             && b_hs.contains(c)
-    //             && c_hs.contains(a)
-    //             && c_hs.contains(b))
-    //             && (a.starts_with("t") || b.starts_with("t") || c.starts_with("t"))
-    //     })
-    //     .count()
-}
+                        a_hs.contains(b)
+                            && a_hs.contains(c)
+                            && b_hs.contains(a)
+                            && b_hs.contains(c)
+                            && c_hs.contains(a)
+                            && c_hs.contains(b)
+                    })
+            })
+            .map(|v| v.into_iter().sorted().collect_vec())
+            .unique()
+            .count()
 
-#[allow(unused_variables)]
-pub fn part2(input: InputType) -> String {
-    String::from("")
+        // connections
+        //     .keys()
+        //     .combinations_with_replacement(3)
+        //     .filter(|x| {
+        //         let a = x[0];
+        //         let b = x[1];
+        //         let c = x[2];
+        //
+        //         let a_hs = connections.get(a).unwrap();
+        //         let b_hs = connections.get(b).unwrap();
+        //         let c_hs = connections.get(c).unwrap();
+        //
+        //         (a_hs.contains(b)
+        //             && a_hs.contains(c)
+        //             && b_hs.contains(a)
+        //             && b_hs.contains(c)
+        //             && c_hs.contains(a)
+        //             && c_hs.contains(b))
+        //             && (a.starts_with("t") || b.starts_with("t") || c.starts_with("t"))
+        //     })
+        //     .count()
+    }
+
+    #[allow(unused_variables)]
+    fn part2(input: Self::Input<'_>) -> Self::Output2 {
+        String::from("")
+    }
 }
 
 #[cfg(test)]
@@ -1,16 +1,9 @@
 use bitvec_simd::BitVec;
-use chrono::{DateTime, TimeZone, Utc};
-use chrono_tz::US::Eastern;
 use num_traits::AsPrimitive;
 use std::fmt::Write as _;
 use std::fmt::{Debug, Display};
-use std::fs;
-use std::fs::File;
-use std::io::Write as _;
 use std::marker::PhantomData;
 use std::ops::RangeBounds;
-use std::path::Path;
-use ureq::AgentBuilder;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Direction {
@@ -585,85 +578,67 @@ where
     }
 }
 
-pub fn read_input(year: i32, day: u32) -> String {
-    let utc_now: DateTime<Utc> = chrono::Utc::now();
-    let start = Eastern.with_ymd_and_hms(year, 12, day, 0, 0, 0).unwrap();
+/// Reads the day's input, downloading it first when it isn't cached yet.
+pub fn read_input(year: i32, day: u32) -> Result<String, InputError> {
+    runner::Inputs::new(year).read(day)
+}
 
-    if start >= utc_now {
-        panic!("It's not time yet, can't fetch: {}", day);
+/// The input for tests, or `None` when the day isn't out yet. Panics with the reason
+/// for anything else.
+pub fn test_input(year: i32, day: u32) -> Option<String> {
+    match read_input(year, day) {
+        Ok(input) => Some(input),
+        Err(e @ InputError::NotYetReleased(_)) => {
+            eprintln!("Skipping day {}: {}", day, e);
+            None
+        }
+        Err(e) => panic!("Day {}: {}", day, e),
     }
+}
 
-    let workspace_root = std::env!("CARGO_WORKSPACE_DIR");
-
-    let file = Path::new(&workspace_root)
-        .join(year.to_string())
-        .join(format!("inputs/day{:0>2}", day));
-
-    let cookie = Path::new(&workspace_root)
-        .join(year.to_string())
-        .join("cookie");
-
-    if !file.exists() {
-        let session_cookie = fs::read_to_string(cookie).expect("Cookie Not Found");
-
-        let url = format!("https://adventofcode.com/{:0>4}/day/{}/input", year, day);
-
-        let body = AgentBuilder::new()
-            .build()
-            .get(&url)
-            .set("COOKIE", &format!("session={}", session_cookie.trim()))
-            .call()
-            .unwrap()
-            .into_string()
-            .unwrap();
-
-        let mut writer = File::create(&file).unwrap();
-        write!(writer, "{}", body).unwrap();
-    }
+/// Where the day's input is cached.
+pub fn input_path(year: i32, day: u32) -> std::path::PathBuf {
+    runner::Inputs::new(year).path(day)
+}
 
-    std::fs::read_to_string(&file).unwrap()
+/// The input if it has already been downloaded, without fetching it.
+pub fn cached_input(year: i32, day: u32) -> Option<String> {
+    std::fs::read_to_string(input_path(year, day)).ok()
 }
 
 pub extern crate num_format;
+pub extern crate runner;
+pub use runner::InputError;
+pub use runner::Solution;
 
 #[macro_export]
 macro_rules! generate_main {
     ($($mod_name:ident)*) => {
         use util;
-        use std::time::{Duration, Instant};
-        use $crate::num_format::{Locale, ToFormattedString};
-
-        fn measure_time<T, F: Fn() -> T>(func: F) -> (T, Duration) {
-            let start = Instant::now();
-            let res = func();
-            let duration = start.elapsed();
-            (res, duration)
-        }
 
         $(
             use $mod_name;
         )*
 
-        fn main() {
+        // Only counts with --alloc.
+        #[global_allocator]
+        static ALLOC: $crate::runner::Counting = $crate::runner::Counting;
 
-            $(
-              let day_s = stringify!($mod_name).trim_start_matches("day");
-              let day = day_s.parse::<u32>().unwrap();
-
-              let (res, duration) = measure_time(|| {
-                let input = util::read_input(2024, day);
-                let parsed = $mod_name::parse(&input);
-                $mod_name::part1(parsed)
-              });
-              println!("Day{:0>2}-01 {: >10}μs:\t{}", day, duration.as_micros().to_formatted_string(&Locale::en), res);
-
-              let (res, duration) = measure_time(|| {
-                let input = util::read_input(2024, day);
-                let parsed = $mod_name::parse(&input);
-                $mod_name::part2(parsed)
-              });
-              println!("Day{:0>2}-02 {: >10}μs:\t{}", day, duration.as_micros().to_formatted_string(&Locale::en), res);
-            )*
+        fn main() {
+            $crate::runner::main(2024, &[
+                $(
+                  $crate::runner::Day::new(stringify!($mod_name), |ctx| {
+                    use $crate::runner::{ViaClone, ViaReparse};
+                    use $crate::Solution;
+
+                    let Some(input) = ctx.read(|day| util::read_input(2024, day)) else { return };
+                    let parse = || $mod_name::Day::parse(&input);
+                    let cloner = (&&$crate::runner::Cloner::of(&parse)).cloner();
+                    ctx.solve(parse, cloner, |data| $mod_name::Day::part1(data), |data| $mod_name::Day::part2(data));
+                  })
+                  .with_code(option_env!(concat!("AOC_CODE_", stringify!($mod_name)))),
+                )*
+            ]);
         }
     };
 }
@@ -678,8 +653,8 @@ macro_rules! generate_test {
 
             #[test]
             fn [<example_ $part>]() {
-                let data = parse($input);
-                assert_eq!([<part $part>](data), $result)
+                let data = <Day as $crate::Solution>::parse($input);
+                assert_eq!(<Day as $crate::Solution>::[<part $part>](data), $result)
             }
 
         }
@@ -690,10 +665,18 @@ macro_rules! generate_test {
             use super::*;
 
             #[test]
-            fn [<test_ $part>]() {
-                let input = util::read_input($year, $day);
-                let data = parse(&input);
-                assert_eq!([<part $part>](data), $result)
+            fn [<input_ $part>]() {
+                let Some(input) = util::test_input($year, $day) else { return };
+                let data = <Day as $crate::Solution>::parse(&input);
+                assert_eq!(<Day as $crate::Solution>::[<part $part>](data), $result)
+            }
+
+            #[test]
+            fn [<accounts_ $part>]() {
+                for (account, input, expected) in $crate::runner::Account::stored($year, $day, $part) {
+                    let data = <Day as $crate::Solution>::parse(&input);
+                    assert_eq!(<Day as $crate::Solution>::[<part $part>](data).to_string(), expected, "{}", account);
+                }
             }
 
         }
@@ -741,3 +724,44 @@ mod tests {
         }
     }
 }
+
+#[macro_export]
+macro_rules! generate_bench {
+    ($year:expr, $day:expr) => {
+        use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+        use std::hint::black_box;
+        use $crate::Solution;
+
+        fn bench(c: &mut Criterion) {
+            let Some(input) = util::cached_input($year, $day) else {
+                eprintln!(
+                    "Skipping day{:0>2} benchmarks, no cached input at {}",
+                    $day,
+                    util::input_path($year, $day).display()
+                );
+                return;
+            };
+
+            let mut group = c.benchmark_group(format!("day{:0>2}", $day));
+            group.bench_function("parse", |b| b.iter(|| Day::parse(black_box(&input))));
+            group.bench_function("part1", |b| {
+                b.iter_batched(
+                    || Day::parse(&input),
+                    |data| Day::part1(data),
+                    BatchSize::SmallInput,
+                )
+            });
+            group.bench_function("part2", |b| {
+                b.iter_batched(
+                    || Day::parse(&input),
+                    |data| Day::part2(data),
+                    BatchSize::SmallInput,
+                )
+            });
+            group.finish();
+        }
+
+        criterion_group!(benches, bench);
+        criterion_main!(benches);
+    };
+}
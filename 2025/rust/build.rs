@@ -0,0 +1,3 @@
+fn main() {
+    days::generate_main("util::generate_main");
+}
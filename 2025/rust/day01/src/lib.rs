@@ -1,3 +1,5 @@
+use util::Solution;
+
 type InputType = Vec<Turn>;
 type OutType = usize;
 
@@ -7,88 +9,96 @@ pub enum Turn {
     Right(i32),
 }
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    data.to_lowercase()
-        .lines()
-        .map(|line| {
-            let (l, r) = line.trim().split_at(1);
-            match l {
-                "l" => Turn::Left(r.parse().unwrap()),
-                "r" => Turn::Right(r.parse().unwrap()),
-                x => unimplemented!("Unimplemented: {x}"),
-            }
-        })
-        .collect()
-}
+pub struct Day;
 
-pub fn part1(input: InputType) -> OutType {
-    let mut cur: i32 = 50;
-    let mut z_count = 0;
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
 
-    for t in input {
-        match t {
-            Turn::Left(c) => {
-                cur -= c;
-                while cur < 0 {
-                    cur += 100;
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        data.to_lowercase()
+            .lines()
+            .map(|line| {
+                let (l, r) = line.trim().split_at(1);
+                match l {
+                    "l" => Turn::Left(r.parse().unwrap()),
+                    "r" => Turn::Right(r.parse().unwrap()),
+                    x => unimplemented!("Unimplemented: {x}"),
                 }
-            }
-            Turn::Right(c) => {
-                cur += c;
-                while cur > 99 {
-                    cur -= 100;
+            })
+            .collect()
+    }
+
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        let mut cur: i32 = 50;
+        let mut z_count = 0;
+
+        for t in input {
+            match t {
+                Turn::Left(c) => {
+                    cur -= c;
+                    while cur < 0 {
+                        cur += 100;
+                    }
+                }
+                Turn::Right(c) => {
+                    cur += c;
+                    while cur > 99 {
+                        cur -= 100;
+                    }
                 }
             }
-        }
 
-        if cur == 0 {
-            z_count += 1;
+            if cur == 0 {
+                z_count += 1;
+            }
         }
+
+        z_count
     }
 
-    z_count
-}
+    #[allow(unused_variables)]
+    fn part2(input: Self::Input<'_>) -> Self::Output2 {
+        let mut cur: i32 = 50;
+        let mut z_count = 0;
 
-#[allow(unused_variables)]
-pub fn part2(input: InputType) -> OutType {
-    let mut cur: i32 = 50;
-    let mut z_count = 0;
-
-    for t in input {
-        match (cur, t) {
-            (0, Turn::Left(c)) => {
-                cur -= c;
-                cur += 100;
-                while cur < 0 {
-                    z_count += 1;
-                    cur += 100;
-                }
-                if cur == 0 {
-                    z_count += 1;
-                }
-            }
-            (_, Turn::Left(c)) => {
-                cur -= c;
-                while cur < 0 {
-                    z_count += 1;
+        for t in input {
+            match (cur, t) {
+                (0, Turn::Left(c)) => {
+                    cur -= c;
                     cur += 100;
+                    while cur < 0 {
+                        z_count += 1;
+                        cur += 100;
+                    }
+                    if cur == 0 {
+                        z_count += 1;
+                    }
                 }
-                if cur == 0 {
-                    z_count += 1;
+                (_, Turn::Left(c)) => {
+                    cur -= c;
+                    while cur < 0 {
+                        z_count += 1;
+                        cur += 100;
+                    }
+                    if cur == 0 {
+                        z_count += 1;
+                    }
                 }
-            }
-            (_, Turn::Right(c)) => {
-                cur += c;
-                while cur > 99 {
-                    z_count += 1;
-                    cur -= 100;
+                (_, Turn::Right(c)) => {
+                    cur += c;
+                    while cur > 99 {
+                        z_count += 1;
+                        cur -= 100;
+                    }
                 }
             }
         }
-    }
 
-    z_count
+        z_count
+    }
 }
 
 #[cfg(test)]
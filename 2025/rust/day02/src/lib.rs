@@ -1,4 +1,5 @@
 use std::{collections::HashSet, ops::RangeInclusive};
+use util::Solution;
 
 use parser::dig_pair;
 use winnow::{Parser, combinator::separated};
@@ -6,68 +7,76 @@ use winnow::{Parser, combinator::separated};
 type InputType = Vec<RangeInclusive<usize>>;
 type OutType = usize;
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    separated(0.., dig_pair::<usize>("-").map(|(l, r)| l..=r), ",")
-        .parse(data.trim())
-        .unwrap()
-}
-
-#[allow(unused_variables)]
-pub fn part1(input: InputType) -> OutType {
-    input
-        .iter()
-        .map(|range| {
-            let mut invalid = 0;
-            for num in range.clone() {
-                let num_s = num.to_string();
-                let bytes = num_s.as_bytes();
-                let byte_len = bytes.len();
-
-                if byte_len.rem_euclid(2) != 0 {
-                    continue;
-                }
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        separated(0.., dig_pair::<usize>("-").map(|(l, r)| l..=r), ",")
+            .parse(data.trim())
+            .unwrap()
+    }
+
+    #[allow(unused_variables)]
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        input
+            .iter()
+            .map(|range| {
+                let mut invalid = 0;
+                for num in range.clone() {
+                    let num_s = num.to_string();
+                    let bytes = num_s.as_bytes();
+                    let byte_len = bytes.len();
+
+                    if byte_len.rem_euclid(2) != 0 {
+                        continue;
+                    }
 
-                let (lhs, rhs) = bytes.split_at(byte_len.div_euclid(2));
+                    let (lhs, rhs) = bytes.split_at(byte_len.div_euclid(2));
 
-                if lhs == rhs {
-                    invalid += num;
+                    if lhs == rhs {
+                        invalid += num;
+                    }
                 }
-            }
 
-            invalid
-        })
-        .sum()
-}
-
-#[allow(unused_variables)]
-pub fn part2(input: InputType) -> OutType {
-    input
-        .iter()
-        .map(|range| {
-            let mut invalid = HashSet::new();
-
-            for num in range.clone() {
-                let num_s = num.to_string();
-                let bytes = num_s.as_bytes();
-                let byte_len = bytes.len();
-
-                for len in 1..=byte_len.div_euclid(2) {
-                    if byte_len.rem_euclid(len) != 0 {
-                        continue;
-                    }
+                invalid
+            })
+            .sum()
+    }
+
+    #[allow(unused_variables)]
+    fn part2(input: Self::Input<'_>) -> Self::Output2 {
+        input
+            .iter()
+            .map(|range| {
+                let mut invalid = HashSet::new();
+
+                for num in range.clone() {
+                    let num_s = num.to_string();
+                    let bytes = num_s.as_bytes();
+                    let byte_len = bytes.len();
+
+                    for len in 1..=byte_len.div_euclid(2) {
+                        if byte_len.rem_euclid(len) != 0 {
+                            continue;
+                        }
 
-                    for chunk in bytes.windows(len) {
-                        if bytes == chunk.repeat(byte_len.div_euclid(len)) {
-                            invalid.insert(num);
+                        for chunk in bytes.windows(len) {
+                            if bytes == chunk.repeat(byte_len.div_euclid(len)) {
+                                invalid.insert(num);
+                            }
                         }
                     }
                 }
-            }
 
-            invalid.iter().sum::<usize>()
-        })
-        .sum()
+                invalid.iter().sum::<usize>()
+            })
+            .sum()
+    }
 }
 
 #[cfg(test)]
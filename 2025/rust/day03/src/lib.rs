@@ -1,24 +1,33 @@
 use itertools::Itertools;
+use util::Solution;
 
 type InputType = Vec<Vec<usize>>;
 type OutType = usize;
 
-pub fn parse(data: &str) -> InputType {
-    data.lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_digit(10).unwrap() as usize)
-                .collect()
-        })
-        .collect()
-}
+pub struct Day;
 
-pub fn part1(input: InputType) -> OutType {
-    window_max(input, 2)
-}
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        data.lines()
+            .map(|line| {
+                line.chars()
+                    .map(|c| c.to_digit(10).unwrap() as usize)
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        window_max(input, 2)
+    }
 
-pub fn part2(input: InputType) -> OutType {
-    window_max(input, 12)
+    fn part2(input: Self::Input<'_>) -> Self::Output2 {
+        window_max(input, 12)
+    }
 }
 
 fn vec2usize(v: &[usize]) -> usize {
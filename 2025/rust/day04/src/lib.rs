@@ -1,60 +1,68 @@
-use util::{BitMap, Pos};
+use util::{BitMap, Pos, Solution};
 
 type InputType = BitMap<usize>;
 type OutType = usize;
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    let height = data.lines().count();
-    let width = data.lines().next().unwrap().len();
-
-    BitMap::from_iter(
-        data.lines().enumerate().flat_map(|(y, line)| {
-            line.as_bytes()
-                .iter()
-                .enumerate()
-                .filter(|(x, byte)| *byte == &b'@')
-                .map(move |(x, _)| Pos::new(x, y))
-        }),
-        width,
-        height,
-    )
-}
+pub struct Day;
 
-#[allow(unused_variables)]
-pub fn part1(input: InputType) -> OutType {
-    input.iter().filter(|pos| can_take(pos, &input)).count()
-}
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
 
-fn can_take(p: &Pos<usize>, bm: &BitMap<usize>) -> bool {
-    p.successors_8_unsigned()
-        .iter()
-        .filter(|pos| bm.contains(pos))
-        .count()
-        < 4
-}
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        let height = data.lines().count();
+        let width = data.lines().next().unwrap().len();
 
-#[allow(unused_variables)]
-pub fn part2(mut input: InputType) -> OutType {
-    let mut total_removed = 0;
+        BitMap::from_iter(
+            data.lines().enumerate().flat_map(|(y, line)| {
+                line.as_bytes()
+                    .iter()
+                    .enumerate()
+                    .filter(|(x, byte)| *byte == &b'@')
+                    .map(move |(x, _)| Pos::new(x, y))
+            }),
+            width,
+            height,
+        )
+    }
+
+    #[allow(unused_variables)]
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        input.iter().filter(|pos| can_take(pos, &input)).count()
+    }
 
-    loop {
-        let mut exit = true;
+    #[allow(unused_variables)]
+    fn part2(mut input: Self::Input<'_>) -> Self::Output2 {
+        let mut total_removed = 0;
 
-        for pos in input.clone().iter() {
-            if can_take(&pos, &input) {
-                input.remove(&pos);
-                total_removed += 1;
-                exit = false;
+        loop {
+            let mut exit = true;
+
+            for pos in input.clone().iter() {
+                if can_take(&pos, &input) {
+                    input.remove(&pos);
+                    total_removed += 1;
+                    exit = false;
+                }
             }
-        }
 
-        if exit {
-            break;
+            if exit {
+                break;
+            }
         }
+
+        total_removed
     }
+}
 
-    total_removed
+fn can_take(p: &Pos<usize>, bm: &BitMap<usize>) -> bool {
+    p.successors_8_unsigned()
+        .iter()
+        .filter(|pos| bm.contains(pos))
+        .count()
+        < 4
 }
 
 #[cfg(test)]
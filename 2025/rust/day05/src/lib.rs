@@ -1,6 +1,7 @@
 use std::ops::Range;
 
 use parser::{dig_pair, FromDig};
+use util::Solution;
 use winnow::{ModalResult, Parser, ascii::newline, combinator::separated, token::rest};
 
 type InputType = (Vec<Range<usize>>, Vec<usize>);
@@ -69,27 +70,35 @@ fn parse_numbers(s: &mut &str) -> ModalResult<Vec<usize>> {
     separated(0.., <usize as FromDig>::from_dig, newline).parse_next(s)
 }
 
-pub fn parse(data: &str) -> InputType {
-    (
-        parse_ranges,
-        newline,
-        newline,
-        parse_numbers,
-        rest,
-    )
-        .map(|(fresh, _, _, available, _)| (fresh, available))
-        .parse(data)
-        .unwrap()
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        (
+            parse_ranges,
+            newline,
+            newline,
+            parse_numbers,
+            rest,
+        )
+            .map(|(fresh, _, _, available, _)| (fresh, available))
+            .parse(data)
+            .unwrap()
+    }
 
-pub fn part1((fresh, available): InputType) -> OutType {
-    let rs = collect_ranges(fresh);
+    fn part1((fresh, available): Self::Input<'_>) -> Self::Output1 {
+        let rs = collect_ranges(fresh);
 
-    available.iter().filter(|a| rs.contains(a)).count()
-}
+        available.iter().filter(|a| rs.contains(a)).count()
+    }
 
-pub fn part2((fresh, _): InputType) -> OutType {
-    collect_ranges(fresh).size()
+    fn part2((fresh, _): Self::Input<'_>) -> Self::Output2 {
+        collect_ranges(fresh).size()
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use parser::dig;
+use util::Solution;
 use winnow::{
     Parser,
     ascii::{line_ending, newline, space0, space1},
@@ -21,66 +22,74 @@ pub fn math_line(input: &mut &str) -> winnow::error::Result<Vec<char>> {
     separated(0.., one_of(['+', '*']), space1).parse_next(input)
 }
 
-pub fn parse(data: &'_ str) -> InputType<'_> {
-    data
-}
-
-pub fn part1(data: InputType) -> OutType {
-    let (rows, _, math, _): (Vec<Vec<usize>>, _, Vec<_>, _) =
-        (number_lines, line_ending, math_line, rest)
-            .parse(data)
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType<'a>;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        data
+    }
+
+    fn part1(data: Self::Input<'_>) -> Self::Output1 {
+        let (rows, _, math, _): (Vec<Vec<usize>>, _, Vec<_>, _) =
+            (number_lines, line_ending, math_line, rest)
+                .parse(data)
+                .unwrap();
+
+        (0..rows[0].len())
+            .map(|i| match math[i] {
+                '+' => rows.iter().map(|r| r[i]).sum::<usize>(),
+                '*' => rows.iter().map(|r| r[i]).product(),
+                _ => unimplemented!(),
+            })
+            .sum()
+    }
+
+    fn part2(data: Self::Input<'_>) -> Self::Output2 {
+        let lines: Vec<&str> = data.lines().collect();
+        let max_width = lines.iter().map(|l| l.len()).max().unwrap();
+
+        // Transpose and reverse in one step
+        let columns_rtl: Vec<String> = (0..max_width)
+            .rev()
+            .map(|col| {
+                lines
+                    .iter()
+                    .map(|line| line.chars().nth(col).unwrap_or(' '))
+                    .collect()
+            })
+            .collect();
+
+        let rtl = columns_rtl.join("\n");
+
+        let (problems, rest): (Vec<(Vec<usize>, char)>, _) = (
+            separated(
+                1..,
+                (
+                    separated(1.., delimited(space0, dig::<usize>, space0), newline),
+                    one_of(['*', '+']),
+                ),
+                (newline, space0, newline),
+            ),
+            rest,
+        )
+            .parse(&rtl)
             .unwrap();
 
-    (0..rows[0].len())
-        .map(|i| match math[i] {
-            '+' => rows.iter().map(|r| r[i]).sum::<usize>(),
-            '*' => rows.iter().map(|r| r[i]).product(),
-            _ => unimplemented!(),
-        })
-        .sum()
-}
-
-pub fn part2(data: InputType) -> OutType {
-    let lines: Vec<&str> = data.lines().collect();
-    let max_width = lines.iter().map(|l| l.len()).max().unwrap();
-
-    // Transpose and reverse in one step
-    let columns_rtl: Vec<String> = (0..max_width)
-        .rev()
-        .map(|col| {
-            lines
-                .iter()
-                .map(|line| line.chars().nth(col).unwrap_or(' '))
-                .collect()
-        })
-        .collect();
-
-    let rtl = columns_rtl.join("\n");
-
-    let (problems, rest): (Vec<(Vec<usize>, char)>, _) = (
-        separated(
-            1..,
-            (
-                separated(1.., delimited(space0, dig::<usize>, space0), newline),
-                one_of(['*', '+']),
-            ),
-            (newline, space0, newline),
-        ),
-        rest,
-    )
-        .parse(&rtl)
-        .unwrap();
-
-    assert_eq!(rest, "");
-
-    problems
-        .iter()
-        .map(|(nums, op)| match op {
-            '+' => nums.iter().sum::<usize>(),
-            '*' => nums.iter().product(),
-            _ => unimplemented!(),
-        })
-        .sum()
+        assert_eq!(rest, "");
+
+        problems
+            .iter()
+            .map(|(nums, op)| match op {
+                '+' => nums.iter().sum::<usize>(),
+                '*' => nums.iter().product(),
+                _ => unimplemented!(),
+            })
+            .sum()
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,6 @@
 use std::collections::{HashMap, VecDeque};
 
-use util::{BitMap, Direction, Pos};
+use util::{BitMap, Direction, Pos, Solution};
 
 type InputType = Manifold;
 type OutType = usize;
@@ -60,51 +60,59 @@ impl Manifold {
     }
 }
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    Manifold::new(data)
-}
+pub struct Day;
 
-#[allow(unused_variables)]
-pub fn part1(mut manifold: InputType) -> OutType {
-    let mut split_count = 0;
-    let mut last_beams = VecDeque::new();
-    last_beams.push_front(manifold.start.clone());
-
-    while let Some(beam) = last_beams.pop_front() {
-        match manifold.successors(&beam) {
-            Some(Successors::Straight(next)) => {
-                manifold.beams.insert(&next);
-                last_beams.push_front(next);
-                continue;
-            }
-            Some(Successors::Split(successors)) => {
-                let mut did_split = false;
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
 
-                for successor in successors {
-                    if manifold.beams.contains(&successor) {
-                        continue;
-                    }
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        Manifold::new(data)
+    }
 
-                    did_split = true;
-                    manifold.beams.insert(&successor);
-                    last_beams.push_front(successor);
+    #[allow(unused_variables)]
+    fn part1(mut manifold: Self::Input<'_>) -> Self::Output1 {
+        let mut split_count = 0;
+        let mut last_beams = VecDeque::new();
+        last_beams.push_front(manifold.start.clone());
+
+        while let Some(beam) = last_beams.pop_front() {
+            match manifold.successors(&beam) {
+                Some(Successors::Straight(next)) => {
+                    manifold.beams.insert(&next);
+                    last_beams.push_front(next);
+                    continue;
                 }
+                Some(Successors::Split(successors)) => {
+                    let mut did_split = false;
+
+                    for successor in successors {
+                        if manifold.beams.contains(&successor) {
+                            continue;
+                        }
+
+                        did_split = true;
+                        manifold.beams.insert(&successor);
+                        last_beams.push_front(successor);
+                    }
 
-                if did_split {
-                    split_count += 1;
+                    if did_split {
+                        split_count += 1;
+                    }
                 }
+                None => (),
             }
-            None => (),
         }
-    }
 
-    split_count
-}
+        split_count
+    }
 
-#[allow(unused_variables)]
-pub fn part2(manifold: InputType) -> OutType {
-    count_paths(&manifold, &manifold.start.clone(), &mut HashMap::new())
+    #[allow(unused_variables)]
+    fn part2(manifold: Self::Input<'_>) -> Self::Output2 {
+        count_paths(&manifold, &manifold.start.clone(), &mut HashMap::new())
+    }
 }
 
 fn count_paths(
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use itertools::Itertools;
 use parser::point3;
-use util::Point3;
+use util::{Point3, Solution};
 use winnow::{
     Parser,
     ascii::line_ending,
@@ -69,26 +69,48 @@ impl JunctionBoxes {
     }
 }
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    let (points, _): (Vec<Point3<usize>>, Vec<_>) = (
-        separated(1.., point3(","), line_ending),
-        repeat(0.., line_ending),
-    )
-        .parse(data)
-        .unwrap();
-
-    JunctionBoxes {
-        circuits: points
-            .into_iter()
-            .enumerate()
-            .map(|(idx, point)| (idx, vec![point]))
-            .collect(),
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        let (points, _): (Vec<Point3<usize>>, Vec<_>) = (
+            separated(1.., point3(","), line_ending),
+            repeat(0.., line_ending),
+        )
+            .parse(data)
+            .unwrap();
+
+        JunctionBoxes {
+            circuits: points
+                .into_iter()
+                .enumerate()
+                .map(|(idx, point)| (idx, vec![point]))
+                .collect(),
+        }
     }
-}
 
-pub fn part1(input: InputType) -> OutType {
-    _part1(input, 1000)
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        _part1(input, 1000)
+    }
+
+    #[allow(unused_variables)]
+    fn part2(mut input: Self::Input<'_>) -> Self::Output2 {
+        let pairs: Vec<_> = input.all_pairs_ranked().collect();
+        for (p1, p2) in pairs {
+            input.connect(&p1, &p2);
+
+            if input.circuits.len() == 1 {
+                return p1.x * p2.x;
+            }
+        }
+
+        0
+    }
 }
 
 pub fn _part1(mut input: InputType, n: usize) -> OutType {
@@ -107,20 +129,6 @@ pub fn _part1(mut input: InputType, n: usize) -> OutType {
         .product()
 }
 
-#[allow(unused_variables)]
-pub fn part2(mut input: InputType) -> OutType {
-    let pairs: Vec<_> = input.all_pairs_ranked().collect();
-    for (p1, p2) in pairs {
-        input.connect(&p1, &p2);
-
-        if input.circuits.len() == 1 {
-            return p1.x * p2.x;
-        }
-    }
-
-    0
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,27 +156,31 @@ mod tests {
 
     #[test]
     fn example_1() {
-        let data = parse(TEST_INPUT);
+        let data = Day::parse(TEST_INPUT);
         assert_eq!(_part1(data, 10), 40)
     }
 
     #[test]
     fn example_2() {
-        let data = parse(TEST_INPUT);
-        assert_eq!(part2(data), 25272)
+        let data = Day::parse(TEST_INPUT);
+        assert_eq!(Day::part2(data), 25272)
     }
 
     #[test]
     fn test_1() {
-        let input = util::read_input(2025, 8);
-        let data = parse(&input);
-        assert_eq!(part1(data), 175500)
+        let Some(input) = util::test_input(2025, 8) else {
+            return;
+        };
+        let data = Day::parse(&input);
+        assert_eq!(Day::part1(data), 175500)
     }
 
     #[test]
     fn test_2() {
-        let input = util::read_input(2025, 8);
-        let data = parse(&input);
-        assert_eq!(part2(data), 6934702555)
+        let Some(input) = util::test_input(2025, 8) else {
+            return;
+        };
+        let data = Day::parse(&input);
+        assert_eq!(Day::part2(data), 6934702555)
     }
 }
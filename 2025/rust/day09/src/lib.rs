@@ -1,7 +1,7 @@
 use itertools::Itertools;
 use parser::dig_pair;
 use rayon::prelude::*;
-use util::{Pos, Rect};
+use util::{Pos, Rect, Solution};
 use winnow::{
     Parser,
     ascii::line_ending,
@@ -11,33 +11,77 @@ use winnow::{
 type InputType = Vec<Pos<usize>>;
 type OutType = i64;
 
-pub fn parse(data: &str) -> InputType {
-    let (data, _): (InputType, Vec<_>) = (
-        separated(
-            1..,
-            dig_pair::<usize>(",").map(|(x, y)| Pos::new(x, y)),
-            line_ending,
-        ),
-        repeat(0.., line_ending),
-    )
-        .parse(data)
-        .unwrap();
-
-    data
-}
+pub struct Day;
+
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        let (data, _): (InputType, Vec<_>) = (
+            separated(
+                1..,
+                dig_pair::<usize>(",").map(|(x, y)| Pos::new(x, y)),
+                line_ending,
+            ),
+            repeat(0.., line_ending),
+        )
+            .parse(data)
+            .unwrap();
+
+        data
+    }
+
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        input
+            .iter()
+            .tuple_combinations()
+            .par_bridge()
+            .map(|(p1, p2)| {
+                ((p1.x as i64).abs_diff(p2.x as i64) + 1)
+                    * ((p1.y as i64).abs_diff(p2.y as i64) + 1)
+            })
+            .max()
+            .unwrap()
+            .try_into()
+            .unwrap()
+    }
 
-pub fn part1(input: InputType) -> OutType {
-    input
-        .iter()
-        .tuple_combinations()
-        .par_bridge()
-        .map(|(p1, p2)| {
-            ((p1.x as i64).abs_diff(p2.x as i64) + 1) * ((p1.y as i64).abs_diff(p2.y as i64) + 1)
-        })
-        .max()
-        .unwrap()
-        .try_into()
-        .unwrap()
+    fn part2(input: Self::Input<'_>) -> Self::Output2 {
+        input
+            .iter()
+            .tuple_combinations()
+            .filter_map(|(p1, p2)| {
+                let rect = Rect::from_corners(p1.clone(), p2.clone());
+
+                // Early exit 1: if any polygon vertex is strictly inside this rectangle (not on boundary)
+                if input.iter().any(|point| rect.contains_strict(point)) {
+                    return None;
+                }
+
+                // Early exit 2: if any polygon edge passes through rectangle interior
+                for (edge_p1, edge_p2) in input.iter().cycle().tuple_windows().take(input.len()) {
+                    if rect.segment_intersects_interior(edge_p1, edge_p2) {
+                        return None;
+                    }
+                }
+
+                // Check if all four corners of rectangle are inside or on polygon boundary
+                for corner in &rect.corners() {
+                    // Corner is valid if it's a polygon vertex (on boundary) OR inside polygon
+                    let is_vertex = input.iter().any(|v| v == corner);
+                    let is_inside = point_in_polygon(corner, &input);
+                    if !is_vertex && !is_inside {
+                        return None;
+                    }
+                }
+
+                Some(rect.area() as i64)
+            })
+            .max()
+            .unwrap()
+    }
 }
 
 /// Ray casting algorithm to check if a point is inside a polygon.
@@ -75,41 +119,6 @@ fn point_in_polygon(point: &Pos<usize>, polygon: &[Pos<usize>]) -> bool {
     inside
 }
 
-pub fn part2(input: InputType) -> OutType {
-    input
-        .iter()
-        .tuple_combinations()
-        .filter_map(|(p1, p2)| {
-            let rect = Rect::from_corners(p1.clone(), p2.clone());
-
-            // Early exit 1: if any polygon vertex is strictly inside this rectangle (not on boundary)
-            if input.iter().any(|point| rect.contains_strict(point)) {
-                return None;
-            }
-
-            // Early exit 2: if any polygon edge passes through rectangle interior
-            for (edge_p1, edge_p2) in input.iter().cycle().tuple_windows().take(input.len()) {
-                if rect.segment_intersects_interior(edge_p1, edge_p2) {
-                    return None;
-                }
-            }
-
-            // Check if all four corners of rectangle are inside or on polygon boundary
-            for corner in &rect.corners() {
-                // Corner is valid if it's a polygon vertex (on boundary) OR inside polygon
-                let is_vertex = input.iter().any(|v| v == corner);
-                let is_inside = point_in_polygon(corner, &input);
-                if !is_vertex && !is_inside {
-                    return None;
-                }
-            }
-
-            Some(rect.area() as i64)
-        })
-        .max()
-        .unwrap()
-}
-
 #[cfg(test)]
 mod tests {
     use util::generate_test;
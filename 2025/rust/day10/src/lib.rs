@@ -1,5 +1,6 @@
 use parser::separated_digits;
 use pathfinding::prelude::dijkstra;
+use util::Solution;
 use winnow::{
     ModalResult, Parser,
     ascii::{newline, space1},
@@ -100,61 +101,69 @@ fn parse_machine(s: &mut &str) -> ModalResult<Machine> {
         .parse_next(s)
 }
 
-pub fn parse(data: &str) -> InputType {
-    terminated(
-        separated(1.., parse_machine, newline),
-        repeat::<_, _, Vec<_>, _, _>(0.., newline),
-    )
-    .parse(data)
-    .unwrap()
-}
+pub struct Day;
 
-pub fn part1(input: InputType) -> OutType {
-    input
-        .iter()
-        .map(|machine| {
-            dijkstra(
-                machine,
-                |machine| {
-                    let machine = machine.clone();
-                    (0..machine.buttons.len()).map(move |n| {
-                        let mut m = machine.clone();
-                        m.press_button_light(&n);
-                        (m, 1)
-                    })
-                },
-                |machine| machine.is_complete_lights(),
-            )
-            .unwrap()
-            .1
-        })
-        .sum()
-}
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        terminated(
+            separated(1.., parse_machine, newline),
+            repeat::<_, _, Vec<_>, _, _>(0.., newline),
+        )
+        .parse(data)
+        .unwrap()
+    }
 
-pub fn part2(input: InputType) -> OutType {
-    // input
-    //     .iter()
-    //     .map(|machine| {
-    //         let (_path, n) = dijkstra(
-    //             machine,
-    //             |machine| {
-    //                 let machine = machine.clone();
-    //                 (0..machine.buttons.len())
-    //                     .map(move |n| {
-    //                         let mut m = machine.clone();
-    //                         m.press_button_joltage(&n);
-    //                         (m, 1)
-    //                     })
-    //                     .filter(|(machine, _)| !machine.is_over_joltage())
-    //             },
-    //             |machine| machine.is_complete_joltage(),
-    //         )
-    //         .unwrap();
-    //
-    //         n
-    //     })
-    //     .sum()
-    1
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        input
+            .iter()
+            .map(|machine| {
+                dijkstra(
+                    machine,
+                    |machine| {
+                        let machine = machine.clone();
+                        (0..machine.buttons.len()).map(move |n| {
+                            let mut m = machine.clone();
+                            m.press_button_light(&n);
+                            (m, 1)
+                        })
+                    },
+                    |machine| machine.is_complete_lights(),
+                )
+                .unwrap()
+                .1
+            })
+            .sum()
+    }
+
+    fn part2(input: Self::Input<'_>) -> Self::Output2 {
+        // input
+        //     .iter()
+        //     .map(|machine| {
+        //         let (_path, n) = dijkstra(
+        //             machine,
+        //             |machine| {
+        //                 let machine = machine.clone();
+        //                 (0..machine.buttons.len())
+        //                     .map(move |n| {
+        //                         let mut m = machine.clone();
+        //                         m.press_button_joltage(&n);
+        //                         (m, 1)
+        //                     })
+        //                     .filter(|(machine, _)| !machine.is_over_joltage())
+        //             },
+        //             |machine| machine.is_complete_joltage(),
+        //         )
+        //         .unwrap();
+        //
+        //         n
+        //     })
+        //     .sum()
+        1
+    }
 }
 
 #[cfg(test)]
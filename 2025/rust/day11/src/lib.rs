@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::hash::Hash;
+use util::Solution;
 
 use winnow::{
     ModalResult, Parser,
@@ -14,15 +15,60 @@ fn parse_line<'a>(data: &mut &'a str) -> ModalResult<(&'a str, Vec<&'a str>)> {
     (terminated(alpha1, ": "), separated(1.., alpha1, space1)).parse_next(data)
 }
 
-pub fn parse(data: &str) -> InputType<'_> {
-    let (x, _): (Vec<(&str, Vec<&str>)>, Vec<_>) = (
-        separated(1.., parse_line, line_ending),
-        repeat(0.., line_ending),
-    )
-        .parse(data)
-        .unwrap();
+pub struct Day;
 
-    x.into_iter().collect()
+impl Solution for Day {
+    type Input<'a> = InputType<'a>;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    fn parse(data: &str) -> Self::Input<'_> {
+        let (x, _): (Vec<(&str, Vec<&str>)>, Vec<_>) = (
+            separated(1.., parse_line, line_ending),
+            repeat(0.., line_ending),
+        )
+            .parse(data)
+            .unwrap();
+
+        x.into_iter().collect()
+    }
+
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        count_paths(
+            "you",
+            |&device| input.get(device).unwrap().iter().copied(),
+            |&device| device == "out",
+        )
+    }
+
+    fn part2(input: Self::Input<'_>) -> Self::Output2 {
+        static EMPTY_STATE: Vec<(&str, bool, bool)> = Vec::new();
+
+        let mut expanded: HashMap<(&str, bool, bool), Vec<(&str, bool, bool)>> = HashMap::new();
+
+        for &node in input.keys() {
+            for seen_dac in [false, true] {
+                for seen_fft in [false, true] {
+                    let state = (node, seen_dac, seen_fft);
+
+                    let successors: Vec<_> = input
+                        .get(node)
+                        .unwrap()
+                        .iter()
+                        .map(|&next| (next, seen_dac || next == "dac", seen_fft || next == "fft"))
+                        .collect();
+
+                    expanded.insert(state, successors);
+                }
+            }
+        }
+
+        count_paths(
+            ("svr", false, false),
+            |&state| expanded.get(&state).unwrap_or(&EMPTY_STATE).iter().copied(),
+            |&(node, seen_dac, seen_fft)| node == "out" && seen_dac && seen_fft,
+        )
+    }
 }
 
 fn count_paths<N, FN, IN, FS>(start: N, mut successors: FN, mut is_goal: FS) -> usize
@@ -66,43 +112,6 @@ where
     count_paths(&start, &mut successors, &mut is_goal, &mut cache)
 }
 
-pub fn part1(input: InputType) -> OutType {
-    count_paths(
-        "you",
-        |&device| input.get(device).unwrap().iter().copied(),
-        |&device| device == "out",
-    )
-}
-
-pub fn part2(input: InputType) -> OutType {
-    static EMPTY_STATE: Vec<(&str, bool, bool)> = Vec::new();
-
-    let mut expanded: HashMap<(&str, bool, bool), Vec<(&str, bool, bool)>> = HashMap::new();
-
-    for &node in input.keys() {
-        for seen_dac in [false, true] {
-            for seen_fft in [false, true] {
-                let state = (node, seen_dac, seen_fft);
-
-                let successors: Vec<_> = input
-                    .get(node)
-                    .unwrap()
-                    .iter()
-                    .map(|&next| (next, seen_dac || next == "dac", seen_fft || next == "fft"))
-                    .collect();
-
-                expanded.insert(state, successors);
-            }
-        }
-    }
-
-    count_paths(
-        ("svr", false, false),
-        |&state| expanded.get(&state).unwrap_or(&EMPTY_STATE).iter().copied(),
-        |&(node, seen_dac, seen_fft)| node == "out" && seen_dac && seen_fft,
-    )
-}
-
 #[cfg(test)]
 mod tests {
     use util::generate_test;
@@ -0,0 +1,220 @@
+use runner::{Format, OPTIONS, Selection};
+
+const USAGE: &str = "\
+Usage: aoc [OPTIONS] [YEAR | FIRST-LAST [DAY[:PART] | FIRST-LAST[:PART] [PART]]]
+       aoc new YEAR DAY
+       aoc puzzle YEAR DAY [--account NAME]
+       aoc submit YEAR DAY PART [ANSWER] [--account NAME]
+       aoc watch YEAR DAY
+
+Runs each year in its own workspace and collects the results. Of several years, those
+without any of the days given are skipped.
+
+Examples:
+  aoc                 Run every day of every year
+  aoc 2022            Run every day of 2022
+  aoc 2022 22 2       Run part 2 of day 22 of 2022
+  aoc 2021-2023 3-9   Run days 3 through 9 of 2021, 2022 and 2023
+  aoc new 2025 12     Start day 12 of 2025
+  aoc puzzle 2024 5   Download the description of day 5 of 2024
+  aoc submit 2024 5 2 Solve part 2 of day 5 of 2024 and send the answer
+  aoc watch 2025 11   Test and run day 11 of 2025 whenever it changes
+
+Options are passed on to each year's runner.";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Args {
+    pub years: Vec<u32>,
+    /// Arguments for each year's runner, which always reports as JSON.
+    pub forward: Vec<String>,
+    /// The options as the runners see them.
+    pub run: runner::Args,
+    /// The days given, if any.
+    pub days: Option<Selection>,
+}
+
+impl Args {
+    pub fn from_env(available: &[u32]) -> Self {
+        match Self::parse(std::env::args().skip(1), available) {
+            Ok(Some(args)) => args,
+            Ok(None) => {
+                println!("{}\n\n{}", USAGE, OPTIONS);
+                std::process::exit(0);
+            }
+            Err(e) => {
+                eprintln!("{}\n\n{}\n\n{}", e, USAGE, OPTIONS);
+                std::process::exit(2);
+            }
+        }
+    }
+
+    /// Returns `Ok(None)` when help was requested.
+    pub fn parse<I, S>(args: I, available: &[u32]) -> Result<Option<Self>, String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut parsed = Self::default();
+        let mut positional = Vec::new();
+        let mut format = Format::default();
+        let mut args = args.into_iter().map(|a| a.as_ref().to_string());
+
+        while let Some(arg) = args.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => (flag, Some(value)),
+                _ => (arg.as_str(), None),
+            };
+            let mut value = || match inline {
+                Some(value) => Ok(value.to_string()),
+                None => args
+                    .next()
+                    .ok_or_else(|| format!("Missing value for {}", flag)),
+            };
+
+            match flag {
+                "-h" | "--help" => return Ok(None),
+                "--format" => format = value()?.parse()?,
+                flag if flag.starts_with('-') => {
+                    let takes_value = inline.is_none() && runner::Args::takes_value(flag);
+                    let next = if takes_value { Some(value()?) } else { None };
+                    parsed.forward.push(arg.clone());
+                    parsed.forward.extend(next);
+                }
+                _ => positional.push(arg.clone()),
+            }
+        }
+
+        let mut positional = positional.into_iter();
+        parsed.years = match positional.next() {
+            Some(years) => parse_years(&years, available)?,
+            None => available.to_vec(),
+        };
+        let spec = match (positional.next(), positional.next()) {
+            (Some(day), Some(part)) if !day.contains(':') => Some(format!("{}:{}", day, part)),
+            (Some(_), Some(part)) => return Err(format!("Part given twice: {}", part)),
+            (day, _) => day,
+        };
+        if let Some(extra) = positional.next() {
+            return Err(format!("Unexpected argument: {}", extra));
+        }
+
+        let days = (1..=25).collect::<Vec<_>>();
+        if let Some(spec) = spec {
+            let mut selection = Selection::default();
+            selection.push_spec(&spec, &days)?;
+            parsed.days = Some(selection);
+            parsed.forward.push(spec);
+        }
+        parsed.run = runner::Args::parse(&parsed.forward, &days)?.unwrap_or_default();
+        parsed.run.format = format;
+        if parsed.run.input == Some(runner::Input::Stdin) && parsed.years.len() != 1 {
+            return Err("--stdin needs a single year".to_string());
+        }
+
+        Ok(Some(parsed))
+    }
+
+    /// Whether to leave out a year that has `days`, because it has none of those given.
+    /// With a single year that is the runner's error to report instead.
+    pub fn skips(&self, days: &[u32]) -> bool {
+        self.years.len() > 1
+            && self
+                .days
+                .as_ref()
+                .is_some_and(|selection| !days.iter().any(|&day| selection.contains_day(day)))
+    }
+}
+
+fn parse_years(spec: &str, available: &[u32]) -> Result<Vec<u32>, String> {
+    let year = |s: &str| {
+        s.parse::<u32>()
+            .ok()
+            .filter(|y| available.contains(y))
+            .ok_or_else(|| {
+                let available = available.iter().map(u32::to_string).collect::<Vec<_>>();
+                format!("No such year: {}, there are {}", s, available.join(", "))
+            })
+    };
+
+    let (first, last) = match spec.split_once('-') {
+        Some((first, last)) => (year(first)?, year(last)?),
+        None => (year(spec)?, year(spec)?),
+    };
+    if first > last {
+        return Err(format!("Invalid year range: {}", spec));
+    }
+    Ok(available
+        .iter()
+        .copied()
+        .filter(|y| (first..=last).contains(y))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEARS: &[u32] = &[2021, 2022, 2023, 2024, 2025];
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        Args::parse(args, YEARS).map(Option::unwrap)
+    }
+
+    #[test]
+    fn picks_years() {
+        assert_eq!(parse(&[]).unwrap().years, YEARS);
+        assert_eq!(parse(&["2022"]).unwrap().years, [2022]);
+        assert_eq!(parse(&["2022-2024"]).unwrap().years, [2022, 2023, 2024]);
+        assert!(parse(&["2019"]).is_err());
+        assert!(parse(&["2024-2022"]).is_err());
+        assert!(parse(&["7"]).is_err());
+    }
+
+    #[test]
+    fn joins_day_and_part() {
+        let args = parse(&["2022", "22", "2"]).unwrap();
+        assert_eq!(args.forward, ["22:2"]);
+        assert!(args.run.selection.contains(22, 2));
+        assert!(!args.run.selection.contains(22, 1));
+
+        assert_eq!(parse(&["2022", "3-9:1"]).unwrap().forward, ["3-9:1"]);
+        assert!(parse(&["2022", "22:1", "2"]).is_err());
+        assert!(parse(&["2022", "22", "3"]).is_err());
+        assert!(parse(&["2022", "1", "2", "3"]).is_err());
+    }
+
+    #[test]
+    fn skips_years_without_the_days() {
+        let twelve = (1..=12).collect::<Vec<_>>();
+        let all = (1..=25).collect::<Vec<_>>();
+        let args = parse(&["2021-2025", "20"]).unwrap();
+        assert!(args.skips(&twelve));
+        assert!(!args.skips(&all));
+        assert!(!parse(&["2021-2025", "10-20"]).unwrap().skips(&twelve));
+        assert!(!parse(&["2021-2025"]).unwrap().skips(&twelve));
+        assert!(!parse(&["2021-2025", "--latest"]).unwrap().skips(&twelve));
+        // The runner says there is no day 20.
+        assert!(!parse(&["2025", "20"]).unwrap().skips(&twelve));
+    }
+
+    #[test]
+    fn forwards_options() {
+        let args = parse(&["--bench", "50", "2024", "--format=csv", "--record", "6"]).unwrap();
+        assert_eq!(args.forward, ["--bench", "50", "--record", "6"]);
+        assert_eq!(args.run.format, Format::Csv);
+        assert_eq!(args.run.bench.unwrap().runs, 50);
+
+        let args = parse(&["--timeout=5", "2023"]).unwrap();
+        assert_eq!(args.forward, ["--timeout=5"]);
+        assert!(parse(&["--bench"]).is_err());
+        assert!(parse(&["--nope"]).is_err());
+        assert_eq!(Args::parse(["2022", "-h"], YEARS), Ok(None));
+
+        assert_eq!(
+            parse(&["--example", "1"]).unwrap().forward,
+            ["--example", "1"]
+        );
+        assert!(parse(&["2022", "5", "--stdin"]).is_ok());
+        assert!(parse(&["--stdin"]).is_err());
+    }
+}
@@ -0,0 +1,115 @@
+mod args;
+mod new;
+mod puzzle;
+mod submit;
+mod summary;
+mod watch;
+mod year;
+
+use args::Args;
+use new::New;
+use puzzle::Puzzle;
+use runner::{Answers, Format, Report};
+use std::path::Path;
+use std::process::ExitCode;
+use submit::Submit;
+use summary::Summary;
+use watch::Watch;
+use year::Year;
+
+fn main() -> ExitCode {
+    let years = Year::discover(Path::new(env!("CARGO_WORKSPACE_DIR")));
+    let available = years.iter().map(|y| y.year).collect::<Vec<_>>();
+    match std::env::args().nth(1).as_deref() {
+        Some("new") => return New::from_env(&available).run(),
+        Some("puzzle") => return Puzzle::from_env(&available).run(),
+        Some("submit") => return Submit::from_env(&available).run(&years),
+        Some("watch") => return Watch::from_env(&available).run(&years),
+        _ => {}
+    }
+    let args = Args::from_env(&available);
+    let table = args.run.format == Format::Table;
+
+    // Tables are printed per year, the other formats are one stream across years.
+    let mut combined = (!table).then(|| Report::new(0, &args.run, Answers::default()));
+    let mut summary = Summary::default();
+    let mut success = true;
+
+    for year in years.iter().filter(|y| args.years.contains(&y.year)) {
+        let executable = match year.build() {
+            Ok(executable) => executable,
+            Err(e) => {
+                eprintln!("{} failed to build:\n{}", year.year, e);
+                summary.broken(year.year, "build failed");
+                success = false;
+                continue;
+            }
+        };
+
+        if args.run.list {
+            match year.days(&executable) {
+                Ok(days) => {
+                    let days = days.iter().map(u32::to_string).collect::<Vec<_>>();
+                    println!("{}: {}", year.year, days.join(" "));
+                }
+                Err(e) => {
+                    eprintln!("{}: {}", year.year, e);
+                    success = false;
+                }
+            }
+            continue;
+        }
+
+        if args.days.is_some() {
+            match year.days(&executable) {
+                Ok(days) if args.skips(&days) => {
+                    eprintln!("{} has none of the days given, skipped", year.year);
+                    continue;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    eprintln!("{}: {}", year.year, e);
+                    summary.broken(year.year, "failed to run");
+                    success = false;
+                    continue;
+                }
+            }
+        }
+
+        if table {
+            println!("{}", year.year);
+        }
+        let mut report = table.then(|| Report::new(0, &args.run, Answers::default()));
+        let ran = year.run(&executable, &args.forward, |record| {
+            summary.add(&record);
+            report.as_mut().or(combined.as_mut()).unwrap().push(record);
+        });
+        if let Some(report) = report {
+            report.finish();
+            println!();
+        }
+
+        match ran {
+            Ok(ok) => success &= ok,
+            Err(e) => {
+                eprintln!("{}: {}", year.year, e);
+                summary.broken(year.year, "failed to run");
+                success = false;
+            }
+        }
+    }
+
+    if args.run.list {
+        // Nothing ran.
+    } else if let Some(report) = combined {
+        report.finish();
+    } else {
+        summary.write(std::io::stdout()).unwrap();
+    }
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,399 @@
+use days::is_day;
+use runner::{Account, release};
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+use toml_edit::{DocumentMut, Item, Table, Value};
+
+const USAGE: &str = "\
+Usage: aoc new YEAR DAY
+
+Creates the day's crate from rust-template in the year's workspace, adds it to the
+workspace members and the runner's dependencies, which is all it takes to run it, and
+downloads the input and the puzzle with its examples. Whatever is already there is
+left alone, so running it again after part 1 only adds part 2's example.";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct New {
+    pub year: u32,
+    pub day: u32,
+}
+
+impl New {
+    /// The arguments after `new`.
+    pub fn from_env(available: &[u32]) -> Self {
+        match Self::parse(std::env::args().skip(2), available) {
+            Ok(Some(new)) => new,
+            Ok(None) => {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+            Err(e) => {
+                eprintln!("{}\n\n{}", e, USAGE);
+                std::process::exit(2);
+            }
+        }
+    }
+
+    /// Returns `Ok(None)` when help was requested.
+    pub fn parse<I, S>(args: I, available: &[u32]) -> Result<Option<Self>, String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let args = args
+            .into_iter()
+            .map(|a| a.as_ref().to_string())
+            .collect::<Vec<_>>();
+        if args.iter().any(|a| a == "-h" || a == "--help") {
+            return Ok(None);
+        }
+        let [year, day] = args.as_slice() else {
+            return Err("Expected a year and a day".to_string());
+        };
+        let year = year
+            .parse::<u32>()
+            .ok()
+            .filter(|year| available.contains(year))
+            .ok_or_else(|| format!("No such year: {}", year))?;
+        let day = day
+            .parse::<u32>()
+            .ok()
+            .filter(|day| (1..=release::days(year as i32)).contains(day))
+            .ok_or_else(|| format!("No such day: {}", day))?;
+        Ok(Some(Self { year, day }))
+    }
+
+    pub fn run(&self) -> ExitCode {
+        let root = Path::new(env!("CARGO_WORKSPACE_DIR"));
+        if let Err(e) = self.scaffold(root) {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+
+        // The day can be set up before it unlocks, running this again fetches the rest.
+        let year = self.year as i32;
+        let account = Account::default(&root.join(self.year.to_string()));
+        match account.downloads(year).read(self.day) {
+            Ok(_) => println!(
+                "Input in {}",
+                account.downloads(year).path(self.day).display()
+            ),
+            Err(e) => eprintln!("No input yet: {}", e),
+        }
+        match crate::puzzle::fetch(self.year, self.day, None) {
+            Ok(path) => println!("Puzzle in {}", path.display()),
+            Err(e) => eprintln!("No puzzle yet: {}", e),
+        }
+        ExitCode::SUCCESS
+    }
+
+    /// Creates and registers the crate, skipping the steps that are already done.
+    fn scaffold(&self, root: &Path) -> Result<(), String> {
+        let workspace = root.join(self.year.to_string()).join("rust");
+        let name = format!("day{:0>2}", self.day);
+        let write = |path: &Path, contents: String| {
+            fs::write(path, contents).map_err(|e| format!("{}: {}", path.display(), e))
+        };
+        let read = |path: &Path| {
+            fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))
+        };
+
+        let dir = workspace.join(&name);
+        if !dir.exists() {
+            let vars = [
+                ("project-name", name.clone()),
+                ("crate_name", name.clone()),
+                ("year", self.year.to_string()),
+                ("day", self.day.to_string()),
+            ];
+            for file in template_files(&root.join("rust-template"))? {
+                let contents = read(&root.join("rust-template").join(&file))?;
+                let path = dir.join(&file);
+                fs::create_dir_all(path.parent().unwrap()).map_err(|e| e.to_string())?;
+                write(&path, instantiate(&contents, &vars))?;
+            }
+            println!("Created {}", dir.display());
+        }
+
+        let manifest = workspace.join("Cargo.toml");
+        if let Some(registered) = register(&read(&manifest)?, &name)? {
+            write(&manifest, registered)?;
+            println!("Added {} to {}", name, manifest.display());
+        }
+        Ok(())
+    }
+}
+
+/// The template's `Cargo.toml` and sources, relative to it.
+fn template_files(template: &Path) -> Result<Vec<std::path::PathBuf>, String> {
+    let mut files = Vec::new();
+    let mut dirs = vec![template.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        let entries = fs::read_dir(&dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.ends_with("Cargo.toml") && dir == template
+                || path.extension().is_some_and(|e| e == "rs")
+            {
+                files.push(path.strip_prefix(template).unwrap().to_path_buf());
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Replaces the template's `{{name}}` placeholders.
+fn instantiate(template: &str, vars: &[(&str, String)]) -> String {
+    vars.iter()
+        .fold(template.to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{{{}}}}}", name), value)
+        })
+}
+
+/// Adds the crate to the workspace members and the runner's dependencies, after the days
+/// before it and in the style of the other days. `None` when it is there already.
+fn register(manifest: &str, name: &str) -> Result<Option<String>, String> {
+    let mut doc = manifest
+        .parse::<DocumentMut>()
+        .map_err(|e| format!("Cargo.toml: {}", e))?;
+
+    let members = doc
+        .get_mut("workspace")
+        .and_then(|w| w.get_mut("members"))
+        .and_then(Item::as_array_mut)
+        .ok_or("Cargo.toml has no workspace members")?;
+    if !members.iter().any(|m| m.as_str() == Some(name)) {
+        let names = members
+            .iter()
+            .map(|m| m.as_str().unwrap_or_default().to_string())
+            .collect::<Vec<_>>();
+        let at = insertion_point(&names, name);
+        let mut value = Value::from(name);
+        if let Some(neighbour) = members.get(at.min(members.len().saturating_sub(1))) {
+            *value.decor_mut() = neighbour.decor().clone();
+        }
+        members.insert_formatted(at, value);
+    }
+
+    let dependencies = doc
+        .get_mut("dependencies")
+        .and_then(Item::as_table_mut)
+        .ok_or("Cargo.toml has no dependencies")?;
+    if !dependencies.contains_key(name) {
+        let names = dependencies
+            .iter()
+            .map(|(key, _)| key.to_string())
+            .collect::<Vec<_>>();
+        let at = insertion_point(&names, name);
+        let path = format!("./{}", name);
+        // The days as `[dependencies.day01]` tables or as `day01 = { path = "./day01" }`.
+        let table_style = dependencies
+            .iter()
+            .find(|(key, _)| is_day(key))
+            .is_some_and(|(_, item)| item.is_table());
+
+        let after = names[at..]
+            .iter()
+            .filter_map(|key| dependencies.remove_entry(key))
+            .collect::<Vec<_>>();
+        if table_style {
+            let mut table = Table::new();
+            table.insert("path", toml_edit::value(path));
+            // Tables are written in the order of their positions in the file.
+            let position = after
+                .iter()
+                .find_map(|(_, item)| item.as_table()?.position());
+            if let Some(position) = position {
+                shift_positions(doc.as_table_mut(), position);
+                table.set_position(position);
+            }
+            doc["dependencies"]
+                .as_table_mut()
+                .unwrap()
+                .insert(name, Item::Table(table));
+        } else {
+            let mut dependency = toml_edit::InlineTable::new();
+            dependency.insert("path", path.into());
+            dependencies.insert(name, toml_edit::value(dependency));
+        }
+        let dependencies = doc["dependencies"].as_table_mut().unwrap();
+        for (key, item) in after {
+            dependencies.insert_formatted(&key, item);
+        }
+    }
+
+    let registered = doc.to_string();
+    Ok((registered != manifest).then_some(registered))
+}
+
+/// Where a day goes among the entries: after the days before it, else before the days
+/// after it, else at the end.
+fn insertion_point(names: &[String], name: &str) -> usize {
+    names
+        .iter()
+        .rposition(|n| is_day(n) && n.as_str() < name)
+        .map(|i| i + 1)
+        .or_else(|| names.iter().position(|n| is_day(n) && n.as_str() > name))
+        .unwrap_or(names.len())
+}
+
+/// Moves the tables from `from` on one position down to make room.
+fn shift_positions(table: &mut Table, from: isize) {
+    for (_, item) in table.iter_mut() {
+        if let Some(table) = item.as_table_mut() {
+            if table.position().is_some_and(|p| p >= from) {
+                table.set_position(table.position().unwrap() + 1);
+            }
+            shift_positions(table, from);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_days() {
+        let parse = |args: &[&str]| New::parse(args, &[2025]).map(Option::unwrap);
+        assert_eq!(
+            parse(&["2025", "12"]).unwrap(),
+            New {
+                year: 2025,
+                day: 12
+            }
+        );
+        assert!(parse(&["2025"]).is_err());
+        assert!(parse(&["2024", "12"]).is_err());
+        assert!(parse(&["2025", "0"]).is_err());
+        // 2025 only has 12.
+        assert!(parse(&["2025", "13"]).is_err());
+    }
+
+    #[test]
+    fn registers_inline_dependencies() {
+        let manifest = "\
+[package]
+name = \"aoc\"
+
+[dependencies]
+day01 = { path = \"./day01\" }
+util = { path = \"./util\" }
+
+[workspace]
+
+members = [
+    \"util\",
+    \"parser\",
+    \"day01\",
+]
+";
+        let registered = register(manifest, "day02").unwrap().unwrap();
+        assert_eq!(
+            registered,
+            "\
+[package]
+name = \"aoc\"
+
+[dependencies]
+day01 = { path = \"./day01\" }
+day02 = { path = \"./day02\" }
+util = { path = \"./util\" }
+
+[workspace]
+
+members = [
+    \"util\",
+    \"parser\",
+    \"day01\",
+    \"day02\",
+]
+"
+        );
+        assert_eq!(register(&registered, "day02").unwrap(), None);
+    }
+
+    #[test]
+    fn registers_dependency_tables() {
+        let manifest = "\
+[package]
+name = \"aoc\"
+
+[dependencies.day01]
+path = \"./day01\"
+
+[dependencies.day03]
+path = \"./day03\"
+
+[dependencies.util]
+path = \"./util\"
+
+[workspace]
+members = [
+    \"day01\",
+    \"day03\",
+    \"parser\",
+    \"util\",
+]
+
+[profile.dev]
+split-debuginfo = \"unpacked\"
+";
+        let registered = register(manifest, "day02").unwrap().unwrap();
+        assert_eq!(
+            registered,
+            "\
+[package]
+name = \"aoc\"
+
+[dependencies.day01]
+path = \"./day01\"
+
+[dependencies.day02]
+path = \"./day02\"
+
+[dependencies.day03]
+path = \"./day03\"
+
+[dependencies.util]
+path = \"./util\"
+
+[workspace]
+members = [
+    \"day01\",
+    \"day02\",
+    \"day03\",
+    \"parser\",
+    \"util\",
+]
+
+[profile.dev]
+split-debuginfo = \"unpacked\"
+"
+        );
+        assert_eq!(register(&registered, "day02").unwrap(), None);
+    }
+
+    #[test]
+    fn instantiates_the_template() {
+        let template = Path::new(env!("CARGO_WORKSPACE_DIR")).join("rust-template");
+        let files = template_files(&template).unwrap();
+        assert_eq!(
+            files,
+            [
+                Path::new("Cargo.toml"),
+                Path::new("benches/bench.rs"),
+                Path::new("src/lib.rs")
+            ]
+        );
+        let vars = [("year", "2025".to_string()), ("day", "7".to_string())];
+        assert_eq!(
+            instantiate("generate_test! { {{year}}, {{day}}, 1, 0}", &vars),
+            "generate_test! { 2025, 7, 1, 0}"
+        );
+    }
+}
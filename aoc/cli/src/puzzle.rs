@@ -0,0 +1,153 @@
+use runner::{Account, Puzzles, fill_tests, placeholder, release};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: aoc puzzle YEAR DAY [--account NAME]
+
+Downloads the puzzle description to {year}/puzzles/dayNN.md, as Markdown next to the
+HTML it came from, and its examples to {year}/examples/dayNN. The empty example tests
+of the day's crate are filled in with them, those whose answer can't be found are
+left empty. Once part 1 is solved, downloading it again adds part 2.";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Puzzle {
+    pub year: u32,
+    pub day: u32,
+    /// Whose session to download with; part 2 only shows to those who solved part 1.
+    pub account: Option<String>,
+}
+
+impl Puzzle {
+    /// The arguments after `puzzle`.
+    pub fn from_env(available: &[u32]) -> Self {
+        match Self::parse(std::env::args().skip(2), available) {
+            Ok(Some(puzzle)) => puzzle,
+            Ok(None) => {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+            Err(e) => {
+                eprintln!("{}\n\n{}", e, USAGE);
+                std::process::exit(2);
+            }
+        }
+    }
+
+    /// Returns `Ok(None)` when help was requested.
+    pub fn parse<I, S>(args: I, available: &[u32]) -> Result<Option<Self>, String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut account = None;
+        let mut positional = Vec::new();
+        let mut args = args.into_iter().map(|a| a.as_ref().to_string());
+        while let Some(arg) = args.next() {
+            match arg.split_once('=') {
+                Some(("--account", name)) => account = Some(name.to_string()),
+                _ => match arg.as_str() {
+                    "-h" | "--help" => return Ok(None),
+                    "--account" => {
+                        account = Some(args.next().ok_or("Missing value for --account")?)
+                    }
+                    flag if flag.starts_with('-') => {
+                        return Err(format!("Unknown option: {}", flag));
+                    }
+                    _ => positional.push(arg),
+                },
+            }
+        }
+
+        let [year, day] = positional.as_slice() else {
+            return Err("Expected a year and a day".to_string());
+        };
+        let year = year
+            .parse::<u32>()
+            .ok()
+            .filter(|year| available.contains(year))
+            .ok_or_else(|| format!("No such year: {}", year))?;
+        let day = day
+            .parse::<u32>()
+            .ok()
+            .filter(|day| (1..=release::days(year as i32)).contains(day))
+            .ok_or_else(|| format!("No such day: {}", day))?;
+
+        Ok(Some(Self { year, day, account }))
+    }
+
+    pub fn run(&self) -> ExitCode {
+        match fetch(self.year, self.day, self.account.as_deref()) {
+            Ok(path) => {
+                println!("{}", path.display());
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::FAILURE
+            }
+        }
+    }
+}
+
+/// Downloads a puzzle description with an account's session, returning where it went.
+/// The day's crate gets the examples of what was downloaded as tests.
+pub fn fetch(year: u32, day: u32, account: Option<&str>) -> Result<PathBuf, String> {
+    let root = Path::new(env!("CARGO_WORKSPACE_DIR")).join(year.to_string());
+    let account = match account {
+        Some(name) => Account::named(&root, name),
+        None => Account::default(&root),
+    };
+    let session = account.session(year as i32).map_err(|e| e.to_string())?;
+    let puzzle = Puzzles::with_root(year as i32, &root)
+        .fetch(day, &session)
+        .map_err(|e| format!("Day {}: {}", day, e))?;
+
+    let lib = root.join(format!("rust/day{:0>2}/src/lib.rs", day));
+    let Ok(mut source) = fs::read_to_string(&lib) else {
+        return Ok(puzzle.path);
+    };
+    if let Some(filled) = fill_tests(&source, &puzzle.examples) {
+        fs::write(&lib, &filled).map_err(|e| format!("{}: {}", lib.display(), e))?;
+        println!("Added the examples to {}", lib.display());
+        source = filled;
+    }
+    // The answer is taken to be the last highlighted code of a part, which it may lack.
+    for part in 1..=puzzle.parts {
+        if source.contains(&placeholder(part)) {
+            eprintln!(
+                "Found no example answer for part {} in the description, its test in {} is left empty",
+                part,
+                lib.display()
+            );
+        }
+    }
+    Ok(puzzle.path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEARS: &[u32] = &[2022, 2024, 2025];
+
+    #[test]
+    fn parses_puzzles() {
+        let parse = |args: &[&str]| Puzzle::parse(args, YEARS).map(Option::unwrap);
+        assert_eq!(
+            parse(&["2024", "5", "--account=bob"]).unwrap(),
+            Puzzle {
+                year: 2024,
+                day: 5,
+                account: Some("bob".to_string())
+            }
+        );
+        assert!(parse(&["2024"]).is_err());
+        assert!(parse(&["2019", "5"]).is_err());
+        assert!(parse(&["2024", "26"]).is_err());
+        assert!(parse(&["2024", "13"]).is_ok());
+        assert!(parse(&["2025", "13"]).is_err());
+        assert!(parse(&["2024", "5", "1"]).is_err());
+    }
+}
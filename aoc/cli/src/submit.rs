@@ -0,0 +1,229 @@
+use crate::year::Year;
+use chrono::Utc;
+use runner::{Account, Client, Status, Submission, Verdict, refusal, release};
+use std::path::Path;
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: aoc submit YEAR DAY PART [ANSWER] [--account NAME]
+
+Sends an answer to adventofcode.com, solving the part first when no ANSWER is given.
+Answers that are already accepted, or that earlier verdicts rule out, are not sent.
+Every answer sent is kept in the account's submissions.jsonl, and a right one is
+added to its answers.toml. A right part 1 also downloads part 2 of the puzzle.";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Submit {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: Option<String>,
+    pub account: Option<String>,
+}
+
+impl Submit {
+    /// The arguments after `submit`.
+    pub fn from_env(available: &[u32]) -> Self {
+        match Self::parse(std::env::args().skip(2), available) {
+            Ok(Some(submit)) => submit,
+            Ok(None) => {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+            Err(e) => {
+                eprintln!("{}\n\n{}", e, USAGE);
+                std::process::exit(2);
+            }
+        }
+    }
+
+    /// Returns `Ok(None)` when help was requested.
+    pub fn parse<I, S>(args: I, available: &[u32]) -> Result<Option<Self>, String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut account = None;
+        let mut positional = Vec::new();
+        let mut args = args.into_iter().map(|a| a.as_ref().to_string());
+        while let Some(arg) = args.next() {
+            match arg.split_once('=') {
+                Some(("--account", name)) => account = Some(name.to_string()),
+                _ => match arg.as_str() {
+                    "-h" | "--help" => return Ok(None),
+                    "--account" => {
+                        account = Some(args.next().ok_or("Missing value for --account")?)
+                    }
+                    flag if flag.starts_with('-') && flag.parse::<i64>().is_err() => {
+                        return Err(format!("Unknown option: {}", flag));
+                    }
+                    _ => positional.push(arg),
+                },
+            }
+        }
+
+        let mut positional = positional.into_iter();
+        let mut number = |name: &str| {
+            let arg = positional
+                .next()
+                .ok_or_else(|| format!("Missing {}", name))?;
+            arg.parse::<u32>()
+                .map_err(|_| format!("Invalid {}: {}", name, arg))
+        };
+        let year = number("year")?;
+        let day = number("day")?;
+        let part = number("part")?;
+        if !available.contains(&year) {
+            return Err(format!("No such year: {}", year));
+        }
+        if !(1..=release::days(year as i32)).contains(&day) {
+            return Err(format!("No such day: {}", day));
+        }
+        if !(1..=2).contains(&part) {
+            return Err(format!("No such part: {}", part));
+        }
+        let answer = positional.next();
+        if let Some(extra) = positional.next() {
+            return Err(format!("Unexpected argument: {}", extra));
+        }
+
+        Ok(Some(Self {
+            year,
+            day,
+            part,
+            answer,
+            account,
+        }))
+    }
+
+    pub fn run(&self, years: &[Year]) -> ExitCode {
+        match self.submit(years) {
+            Ok(Verdict::Right) => ExitCode::SUCCESS,
+            Ok(_) => ExitCode::FAILURE,
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::FAILURE
+            }
+        }
+    }
+
+    fn submit(&self, years: &[Year]) -> Result<Verdict, String> {
+        let root = Path::new(env!("CARGO_WORKSPACE_DIR")).join(self.year.to_string());
+        let account = match &self.account {
+            Some(name) => Account::named(&root, name),
+            None => Account::default(&root),
+        };
+        let mut answers = account.answers()?;
+        let submissions = account.submissions();
+
+        let answer = match &self.answer {
+            Some(answer) => answer.clone(),
+            None => self.solve(years)?,
+        };
+        if let Some(reason) = refusal(&answers, &submissions.load(), self.day, self.part, &answer) {
+            return Err(format!("Not sending {}: {}", answer, reason));
+        }
+
+        let year = self.year as i32;
+        let session = account.session(year).map_err(|e| e.to_string())?;
+        println!(
+            "Sending {} for {} day {} part {}",
+            answer, year, self.day, self.part
+        );
+        let (verdict, text) = Client::default()
+            .submit(year, self.day, self.part, &answer, &session)
+            .map_err(|e| e.to_string())?;
+        println!("{}: {}", verdict, text);
+
+        // Neither a refusal to look at the answer nor an already solved part says
+        // anything about it.
+        if !matches!(verdict, Verdict::RateLimited(_) | Verdict::Solved) {
+            let submission = Submission {
+                at: Utc::now(),
+                day: self.day,
+                part: self.part,
+                answer: answer.clone(),
+                verdict,
+            };
+            submissions
+                .append(&submission)
+                .map_err(|e| format!("Failed to record the submission for {}: {}", account, e))?;
+        }
+        if verdict == Verdict::Right && answers.insert(self.day, self.part, &answer) {
+            let path = account.answers_path();
+            answers
+                .save(&path)
+                .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        }
+        // Part 2 of the description shows up now.
+        if verdict == Verdict::Right && self.part == 1 {
+            match crate::puzzle::fetch(self.year, self.day, self.account.as_deref()) {
+                Ok(path) => println!("Part 2 is in {}", path.display()),
+                Err(e) => eprintln!("Failed to download part 2: {}", e),
+            }
+        }
+        Ok(verdict)
+    }
+
+    /// Runs the part in the year's runner, on the account's input.
+    fn solve(&self, years: &[Year]) -> Result<String, String> {
+        let year = years
+            .iter()
+            .find(|y| y.year == self.year)
+            .ok_or_else(|| format!("No such year: {}", self.year))?;
+        let executable = year
+            .build()
+            .map_err(|e| format!("{} failed to build:\n{}", self.year, e))?;
+
+        let mut args = vec![format!("{}:{}", self.day, self.part), "--no-history".into()];
+        if let Some(name) = &self.account {
+            args.extend(["--account".to_string(), name.clone()]);
+        }
+        let mut solved = None;
+        year.run(&executable, &args, |record| solved = Some(record))?;
+
+        match solved {
+            Some(record) if record.status == Status::Ok => Ok(record.answer),
+            Some(record) => Err(format!(
+                "Day {} part {} did not solve: {}",
+                self.day,
+                self.part,
+                record.error.unwrap_or_else(|| record.status.to_string())
+            )),
+            None => Err(format!("Day {} part {} did not run", self.day, self.part)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEARS: &[u32] = &[2022, 2024, 2025];
+
+    fn parse(args: &[&str]) -> Result<Submit, String> {
+        Submit::parse(args, YEARS).map(Option::unwrap)
+    }
+
+    #[test]
+    fn parses_submissions() {
+        let submit = parse(&["2024", "5", "2", "-12"]).unwrap();
+        assert_eq!((submit.year, submit.day, submit.part), (2024, 5, 2));
+        assert_eq!(submit.answer.as_deref(), Some("-12"));
+        assert_eq!(submit.account, None);
+
+        let submit = parse(&["--account", "bob", "2022", "1", "1"]).unwrap();
+        assert_eq!(submit.answer, None);
+        assert_eq!(submit.account.as_deref(), Some("bob"));
+        assert_eq!(parse(&["2022", "1", "1", "--account=bob"]).unwrap(), submit);
+
+        assert!(parse(&["2022", "1"]).is_err());
+        assert!(parse(&["2019", "1", "1"]).is_err());
+        assert!(parse(&["2022", "26", "1"]).is_err());
+        assert!(parse(&["2025", "13", "1"]).is_err());
+        assert!(parse(&["2022", "1", "3"]).is_err());
+        assert!(parse(&["2022", "1", "1", "7", "8"]).is_err());
+        assert!(parse(&["2022", "1", "1", "--bench"]).is_err());
+        assert_eq!(Submit::parse(["-h"], YEARS), Ok(None));
+    }
+}
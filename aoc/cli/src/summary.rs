@@ -0,0 +1,141 @@
+use num_format::{Locale, ToFormattedString};
+use runner::{Check, Record, Status};
+use std::io::Write;
+use std::time::Duration;
+
+/// Totals per year and overall.
+#[derive(Default)]
+pub struct Summary {
+    years: Vec<(u32, Totals)>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct Totals {
+    parts: usize,
+    correct: usize,
+    wrong: usize,
+    unknown: usize,
+    failed: usize,
+    time: Duration,
+    /// Why the year produced no results at all.
+    broken: Option<&'static str>,
+}
+
+impl Summary {
+    /// Days that aren't out yet are left out.
+    pub fn add(&mut self, record: &Record) {
+        let totals = self.year(record.year as u32);
+        if record.status == Status::Unreleased {
+            return;
+        }
+        totals.parts += 1;
+        match (record.status, record.check) {
+            (Status::Ok, Some(Check::Correct)) => totals.correct += 1,
+            (Status::Ok, Some(Check::Wrong)) => totals.wrong += 1,
+            (Status::Ok, _) => totals.unknown += 1,
+            _ => totals.failed += 1,
+        }
+        totals.time +=
+            record.read.unwrap_or_default() + record.parse.unwrap_or_default() + record.solve;
+    }
+
+    pub fn broken(&mut self, year: u32, reason: &'static str) {
+        self.year(year).broken = Some(reason);
+    }
+
+    fn year(&mut self, year: u32) -> &mut Totals {
+        match self.years.iter().position(|(y, _)| *y == year) {
+            Some(i) => &mut self.years[i].1,
+            None => {
+                self.years.push((year, Totals::default()));
+                &mut self.years.last_mut().unwrap().1
+            }
+        }
+    }
+
+    pub fn write(&self, mut out: impl Write) -> std::io::Result<()> {
+        writeln!(
+            out,
+            "Year   Parts      ✓      ✗      ?  Failed            Time"
+        )?;
+        let mut all = Totals::default();
+        for (year, totals) in &self.years {
+            match totals.broken {
+                Some(reason) => writeln!(out, "{}   {}", year, reason)?,
+                None => writeln!(out, "{: <6}{}", year, row(totals))?,
+            }
+            all.parts += totals.parts;
+            all.correct += totals.correct;
+            all.wrong += totals.wrong;
+            all.unknown += totals.unknown;
+            all.failed += totals.failed;
+            all.time += totals.time;
+        }
+        if self.years.len() > 1 {
+            writeln!(out, "{: <6}{}", "All", row(&all))?;
+        }
+        Ok(())
+    }
+}
+
+fn row(totals: &Totals) -> String {
+    format!(
+        "{: >6} {: >6} {: >6} {: >6} {: >7} {: >13}μs",
+        totals.parts,
+        totals.correct,
+        totals.wrong,
+        totals.unknown,
+        totals.failed,
+        totals.time.as_micros().to_formatted_string(&Locale::en)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(year: i32, status: Status, check: Option<Check>, solve_us: u64) -> Record {
+        Record {
+            year,
+            day: 1,
+            part: 1,
+            account: None,
+            answer: String::new(),
+            check,
+            expected: None,
+            read: Some(Duration::from_micros(1)),
+            parse: None,
+            solve: Duration::from_micros(solve_us),
+            reparsed: false,
+            cached: false,
+            contended: false,
+            status,
+            error: None,
+            stats: None,
+            parse_allocs: None,
+            allocs: None,
+        }
+    }
+
+    #[test]
+    fn totals_per_year() {
+        let mut summary = Summary::default();
+        summary.add(&record(2022, Status::Ok, Some(Check::Correct), 1_000));
+        summary.add(&record(2022, Status::Ok, Some(Check::Wrong), 2_000));
+        summary.add(&record(2023, Status::Ok, Some(Check::Unknown), 5));
+        summary.add(&record(2023, Status::Timeout, None, 120_000_000));
+        summary.add(&record(2023, Status::Unreleased, None, 0));
+        summary.broken(2021, "build failed");
+
+        let mut out = Vec::new();
+        summary.write(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Year   Parts      ✓      ✗      ?  Failed            Time\n\
+             2022       2      1      1      0       0         3,002μs\n\
+             2023       2      0      0      1       1   120,000,007μs\n\
+             2021   build failed\n\
+             All        4      1      1      1       1   120,003,009μs\n"
+        );
+    }
+}
@@ -0,0 +1,288 @@
+use crate::year::Year;
+use chrono::Local;
+use notify::{EventKind, RecursiveMode, Watcher};
+use runner::{Answers, Report, release};
+use std::path::PathBuf;
+use std::process::{Command, ExitCode, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+
+const USAGE: &str = "\
+Usage: aoc watch YEAR DAY
+
+Watches the day's src/ and tests/ and the year's util and parser crates. On every
+change the day's example tests, all but those on the real input (input_N and
+accounts), are built and run, and once they pass the day runs on the real input.";
+
+/// What every year calls the tests on the real input, which take a while and wait for
+/// the examples to pass.
+const REAL_INPUT: &[&str] = &["input_", "accounts"];
+
+/// Saves come as bursts of events, e.g. a write and then a rename.
+const SETTLE: Duration = Duration::from_millis(200);
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Watch {
+    pub year: u32,
+    pub day: u32,
+}
+
+impl Watch {
+    /// The arguments after `watch`.
+    pub fn from_env(available: &[u32]) -> Self {
+        match Self::parse(std::env::args().skip(2), available) {
+            Ok(Some(watch)) => watch,
+            Ok(None) => {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+            Err(e) => {
+                eprintln!("{}\n\n{}", e, USAGE);
+                std::process::exit(2);
+            }
+        }
+    }
+
+    /// Returns `Ok(None)` when help was requested.
+    pub fn parse<I, S>(args: I, available: &[u32]) -> Result<Option<Self>, String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let args = args
+            .into_iter()
+            .map(|a| a.as_ref().to_string())
+            .collect::<Vec<_>>();
+        if args.iter().any(|a| a == "-h" || a == "--help") {
+            return Ok(None);
+        }
+        let [year, day] = args.as_slice() else {
+            return Err("Expected a year and a day".to_string());
+        };
+        let year = year
+            .parse::<u32>()
+            .ok()
+            .filter(|year| available.contains(year))
+            .ok_or_else(|| format!("No such year: {}", year))?;
+        let day = day
+            .parse::<u32>()
+            .ok()
+            .filter(|day| (1..=release::days(year as i32)).contains(day))
+            .ok_or_else(|| format!("No such day: {}", day))?;
+        Ok(Some(Self { year, day }))
+    }
+
+    /// Runs until interrupted.
+    pub fn run(&self, years: &[Year]) -> ExitCode {
+        let year = years.iter().find(|y| y.year == self.year).unwrap();
+        let name = format!("day{:0>2}", self.day);
+        if !year.crate_dir(&name).exists() {
+            eprintln!("There is no {} in {}, aoc new creates it", name, self.year);
+            return ExitCode::FAILURE;
+        }
+        let watched = [
+            format!("{}/src", name),
+            format!("{}/tests", name),
+            "util".into(),
+            "parser".into(),
+        ]
+        .into_iter()
+        .map(|dir| year.crate_dir(&dir))
+        .filter(|dir| dir.exists())
+        .collect::<Vec<_>>();
+
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = match notify::recommended_watcher(tx) {
+            Ok(watcher) => watcher,
+            Err(e) => {
+                eprintln!("Failed to watch for changes: {}", e);
+                return ExitCode::FAILURE;
+            }
+        };
+        for dir in &watched {
+            if let Err(e) = watcher.watch(dir, RecursiveMode::Recursive) {
+                eprintln!("Failed to watch {}: {}", dir.display(), e);
+                return ExitCode::FAILURE;
+            }
+        }
+
+        loop {
+            self.cycle(year, &name, &watched);
+            // Wait for a change, then for the rest of its burst.
+            let changed = |event: notify::Result<notify::Event>| {
+                event.is_ok_and(|e| !matches!(e.kind, EventKind::Access(_)))
+            };
+            while !rx.recv().is_ok_and(changed) {}
+            while rx.recv_timeout(SETTLE).is_ok() {}
+        }
+    }
+
+    /// Clears the terminal and shows how the examples and then the real input did.
+    fn cycle(&self, year: &Year, name: &str, watched: &[PathBuf]) {
+        print!("\x1b[2J\x1b[H");
+        println!(
+            "{} day {}, {}",
+            self.year,
+            self.day,
+            Local::now().format("%H:%M:%S")
+        );
+        if self.examples(year, name) {
+            self.solve(year);
+        }
+        let watched = watched
+            .iter()
+            .map(|dir| {
+                dir.strip_prefix(year.crate_dir(""))
+                    .unwrap_or(dir)
+                    .display()
+            })
+            .map(|dir| dir.to_string())
+            .collect::<Vec<_>>();
+        println!("\nWatching {}", watched.join(", "));
+    }
+
+    /// Builds and runs the example tests, returning whether the real input is next.
+    fn examples(&self, year: &Year, name: &str) -> bool {
+        let executables = match year.build_tests(name) {
+            Ok(executables) => executables,
+            Err(e) => {
+                println!("Build failed:\n{}", e);
+                return false;
+            }
+        };
+        let skips = REAL_INPUT.iter().flat_map(|test| ["--skip", test]);
+        let mut output = String::new();
+        for executable in executables {
+            let run = Command::new(&executable)
+                .args(skips.clone())
+                .args(["--color", "never"])
+                .current_dir(year.crate_dir(name))
+                .stdin(Stdio::null())
+                .output();
+            match run {
+                Ok(run) => output.push_str(&String::from_utf8_lossy(&run.stdout)),
+                Err(e) => {
+                    println!("Failed to run {}: {}", executable.display(), e);
+                    return false;
+                }
+            }
+        }
+
+        let results = results(&output);
+        if results.is_empty() {
+            println!("Examples  none");
+            return true;
+        }
+        let marks = results
+            .iter()
+            .map(|(test, ok)| format!("{} {}", if *ok { "✓" } else { "✗" }, test))
+            .collect::<Vec<_>>();
+        println!("Examples  {}", marks.join("  "));
+        if results.iter().all(|(_, ok)| *ok) {
+            return true;
+        }
+        if let Some(failures) = failures(&output) {
+            println!("\n{}", failures);
+        }
+        false
+    }
+
+    /// Runs the day on its real input and shows the answers and timings.
+    fn solve(&self, year: &Year) {
+        let executable = match year.build() {
+            Ok(executable) => executable,
+            Err(e) => {
+                println!("Build failed:\n{}", e);
+                return;
+            }
+        };
+        println!();
+        let args = [self.day.to_string(), "--no-history".into()];
+        let mut report = Report::new(0, &runner::Args::default(), Answers::default());
+        if let Err(e) = year.run(&executable, &args, |record| report.push(record)) {
+            println!("{}", e);
+        }
+        report.finish();
+    }
+}
+
+/// The tests the harness ran and whether they passed, from lines like
+/// `test tests::example_1 ... ok`, by name.
+fn results(output: &str) -> Vec<(String, bool)> {
+    let mut results = output
+        .lines()
+        .filter_map(|line| {
+            let (test, result) = line.strip_prefix("test ")?.rsplit_once(" ... ")?;
+            let test = test.rsplit("::").next().unwrap().to_string();
+            match result {
+                "ok" => Some((test, true)),
+                "FAILED" => Some((test, false)),
+                _ => None,
+            }
+        })
+        .collect::<Vec<_>>();
+    results.sort();
+    results
+}
+
+/// What the failed tests printed, the section between the two `failures:` headings.
+fn failures(output: &str) -> Option<&str> {
+    let (_, rest) = output.split_once("\nfailures:\n")?;
+    let (failures, _) = rest.split_once("\nfailures:\n")?;
+    Some(failures.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_days() {
+        let parse = |args: &[&str]| Watch::parse(args, &[2025]).map(Option::unwrap);
+        assert_eq!(
+            parse(&["2025", "11"]).unwrap(),
+            Watch {
+                year: 2025,
+                day: 11
+            }
+        );
+        assert!(parse(&["2025"]).is_err());
+        assert!(parse(&["2024", "11"]).is_err());
+        assert!(parse(&["2025", "13"]).is_err());
+    }
+
+    #[test]
+    fn reads_test_results() {
+        let output = "
+running 2 tests
+test tests::example_2 ... FAILED
+test tests::example_1 ... ok
+
+failures:
+
+---- tests::example_2 stdout ----
+
+thread 'tests::example_2' panicked at day11/src/lib.rs:146:5:
+assertion `left == right` failed
+  left: 3
+ right: 2
+
+failures:
+    tests::example_2
+
+test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 3 filtered out
+";
+        assert_eq!(
+            results(output),
+            [
+                ("example_1".to_string(), true),
+                ("example_2".to_string(), false)
+            ]
+        );
+        let failures = failures(output).unwrap();
+        assert!(failures.starts_with("---- tests::example_2 stdout ----"));
+        assert!(failures.ends_with(" right: 2"));
+
+        assert_eq!(results("\nrunning 0 tests\n\ntest result: ok."), []);
+    }
+}
@@ -0,0 +1,144 @@
+use runner::Record;
+use serde_json::Value;
+use std::env;
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// One year's workspace, e.g. `2022/rust`, with its own `util`, `parser` and runner.
+pub struct Year {
+    pub year: u32,
+    dir: PathBuf,
+}
+
+impl Year {
+    pub fn discover(root: &Path) -> Vec<Self> {
+        let mut years = fs::read_dir(root)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| {
+                let year = entry.file_name().to_str()?.parse().ok()?;
+                let dir = entry.path().join("rust");
+                dir.join("Cargo.toml")
+                    .is_file()
+                    .then_some(Self { year, dir })
+            })
+            .collect::<Vec<_>>();
+        years.sort_by_key(|y| y.year);
+        years
+    }
+
+    /// Builds the year's runner in release mode and returns the path of the binary.
+    /// Warnings are left out, errors come back rendered.
+    pub fn build(&self) -> Result<PathBuf, String> {
+        let mut executables = self.cargo(&["build", "--release", "--bin", "aoc"], |target| {
+            target["name"] == "aoc"
+        })?;
+        Ok(executables.remove(0))
+    }
+
+    /// Builds the unit and integration tests of a day's crate, e.g. `day07`, and returns
+    /// the paths of their test binaries, like [`Year::build`].
+    pub fn build_tests(&self, name: &str) -> Result<Vec<PathBuf>, String> {
+        self.cargo(
+            &["test", "--no-run", "--lib", "--tests", "-p", name],
+            |target| target["kind"] != serde_json::json!(["bin"]),
+        )
+    }
+
+    /// Runs cargo in the workspace, returning the executables of the artifacts whose
+    /// target `wanted` picks.
+    fn cargo(
+        &self,
+        args: &[&str],
+        wanted: impl Fn(&Value) -> bool,
+    ) -> Result<Vec<PathBuf>, String> {
+        let output = Command::new(env::var_os("CARGO").unwrap_or("cargo".into()))
+            .args(args)
+            .args(["--quiet", "--message-format=json"])
+            .current_dir(&self.dir)
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|e| format!("Failed to run cargo: {}", e))?;
+
+        let mut executables = Vec::new();
+        let mut errors = Vec::new();
+        for message in String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        {
+            match message["reason"].as_str() {
+                Some("compiler-artifact") if wanted(&message["target"]) => {
+                    executables.extend(message["executable"].as_str().map(PathBuf::from));
+                }
+                Some("compiler-message") if message["message"]["level"] == "error" => {
+                    errors.extend(message["message"]["rendered"].as_str().map(str::to_string));
+                }
+                _ => {}
+            }
+        }
+
+        if executables.is_empty() || !output.status.success() {
+            errors.push(String::from_utf8_lossy(&output.stderr).to_string());
+            return Err(errors.concat().trim_end().to_string());
+        }
+        Ok(executables)
+    }
+
+    /// Where the crate `name` of the workspace is, e.g. `day07` or `util`.
+    pub fn crate_dir(&self, name: &str) -> PathBuf {
+        self.dir.join(name)
+    }
+
+    /// The days the year's binary runs.
+    pub fn days(&self, executable: &Path) -> Result<Vec<u32>, String> {
+        let output = Command::new(executable)
+            .arg("--list")
+            .current_dir(&self.dir)
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|e| format!("Failed to run {}: {}", executable.display(), e))?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr)
+                .trim_end()
+                .to_string());
+        }
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|line| {
+                line.parse()
+                    .map_err(|_| format!("Unexpected output from {}: {}", self.year, line))
+            })
+            .collect()
+    }
+
+    /// Runs the year's binary, handing over each result as it comes in. The runner's
+    /// own messages go straight to stderr, and it gets stdin for `--stdin`. Returns
+    /// whether the runner succeeded.
+    pub fn run(
+        &self,
+        executable: &Path,
+        args: &[String],
+        mut record: impl FnMut(Record),
+    ) -> Result<bool, String> {
+        let mut child = Command::new(executable)
+            .args(args)
+            .args(["--format", "json"])
+            .current_dir(&self.dir)
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("Failed to run {}: {}", executable.display(), e))?;
+
+        for line in BufReader::new(child.stdout.take().unwrap()).lines() {
+            let line = line.map_err(|e| e.to_string())?;
+            match serde_json::from_str(&line) {
+                Ok(parsed) => record(parsed),
+                Err(e) => eprintln!("Unexpected output from {}: {}: {}", self.year, e, line),
+            }
+        }
+
+        Ok(child.wait().map_err(|e| e.to_string())?.success())
+    }
+}
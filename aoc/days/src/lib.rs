@@ -0,0 +1,216 @@
+//! Finds a year's days when its runner is built, so that a `dayNN` crate in the
+//! runner's dependencies is all it takes to run that day.
+//!
+//! The runner's `build.rs` calls [`generate_main`] with the path of the year's
+//! `generate_main!` macro, and its `main.rs` includes what that wrote:
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/main.rs"));
+//! ```
+//!
+//! It also hashes what each day is solved with into `AOC_CODE_dayNN`, which the
+//! `generate_main!` macros hand to the runner to cache answers under.
+
+use std::fs;
+use std::hash::Hasher;
+use std::path::{Path, PathBuf};
+use twox_hash::XxHash64;
+
+/// What goes into every day's hash besides its own crate, relative to the year's
+/// workspace: the runner binary's sources, the shared crates and the lockfile with the
+/// versions of every dependency.
+const SHARED: &[&str] = &[
+    "Cargo.toml",
+    "Cargo.lock",
+    "src",
+    "util/Cargo.toml",
+    "util/src",
+    "parser/Cargo.toml",
+    "parser/src",
+];
+
+/// Writes `$OUT_DIR/main.rs`, invoking `generate_main` with the `dayNN` dependencies of
+/// the crate being built, and sets each day's `AOC_CODE_dayNN`. Rebuilds whenever
+/// anything hashed changes.
+pub fn generate_main(generate_main: &str) {
+    let dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+    let manifest = dir.join("Cargo.toml");
+    println!("cargo::rerun-if-changed={}", manifest.display());
+
+    let manifest = fs::read_to_string(&manifest).unwrap();
+    let days = days(&manifest).unwrap_or_else(|e| panic!("Cargo.toml: {}", e));
+    let main = format!("{}!(\n    {}\n);\n", generate_main, days.join("\n    "));
+    let out = Path::new(&std::env::var("OUT_DIR").unwrap()).join("main.rs");
+    fs::write(out, main).unwrap();
+
+    let runner = Path::new(env!("CARGO_MANIFEST_DIR")).join("../runner");
+    for path in hashed(&dir, &runner, &days) {
+        println!("cargo::rerun-if-changed={}", path.display());
+    }
+    for day in &days {
+        let code = code(&dir, &runner, day);
+        println!("cargo::rustc-env=AOC_CODE_{}={:016x}", day, code);
+    }
+}
+
+/// The files and directories that go into the hashes that exist, for Cargo to watch.
+fn hashed(workspace: &Path, runner: &Path, days: &[String]) -> Vec<PathBuf> {
+    let own = days
+        .iter()
+        .flat_map(|day| [format!("{}/Cargo.toml", day), format!("{}/src", day)]);
+    SHARED
+        .iter()
+        .map(|path| path.to_string())
+        .chain(own)
+        .map(|path| workspace.join(path))
+        .chain([runner.join("Cargo.toml"), runner.join("src")])
+        .filter(|path| path.exists())
+        .collect()
+}
+
+/// The hash of a day's crate, the year's shared code and the runner. Unlike std's
+/// hashes it doesn't change with the toolchain.
+fn code(workspace: &Path, runner: &Path, day: &str) -> u64 {
+    let own = [format!("{}/Cargo.toml", day), format!("{}/src", day)];
+    let mut files = SHARED
+        .iter()
+        .map(|path| path.to_string())
+        .chain(own)
+        .flat_map(|path| files(workspace, &path))
+        .chain(["Cargo.toml", "src"].iter().flat_map(|path| {
+            files(runner, path)
+                .into_iter()
+                .map(|(name, file)| (format!("runner/{}", name), file))
+        }))
+        .collect::<Vec<_>>();
+    files.sort();
+
+    let mut hasher = XxHash64::with_seed(0);
+    for (name, file) in files {
+        let contents = fs::read(&file).unwrap_or_else(|e| panic!("{}: {}", file.display(), e));
+        for bytes in [name.as_bytes(), &contents] {
+            hasher.write(&(bytes.len() as u64).to_le_bytes());
+            hasher.write(bytes);
+        }
+    }
+    hasher.finish()
+}
+
+/// The files at `path` under `root`, or under it if it is a directory, each with its
+/// path relative to `root` with `/` for a separator.
+fn files(root: &Path, path: &str) -> Vec<(String, PathBuf)> {
+    let mut files = Vec::new();
+    let mut pending = vec![path.to_string()];
+    while let Some(path) = pending.pop() {
+        let full = root.join(&path);
+        match fs::read_dir(&full) {
+            Ok(entries) => pending.extend(
+                entries
+                    .flatten()
+                    .map(|entry| format!("{}/{}", path, entry.file_name().to_string_lossy())),
+            ),
+            Err(_) if full.is_file() => files.push((path, full)),
+            Err(_) => {}
+        }
+    }
+    files
+}
+
+/// The `dayNN` dependencies of a manifest, in order.
+pub fn days(manifest: &str) -> Result<Vec<String>, String> {
+    let manifest = manifest.parse::<toml::Table>().map_err(|e| e.to_string())?;
+    let mut days = manifest
+        .get("dependencies")
+        .and_then(toml::Value::as_table)
+        .into_iter()
+        .flat_map(|dependencies| dependencies.keys())
+        .filter(|name| is_day(name))
+        .cloned()
+        .collect::<Vec<_>>();
+    days.sort();
+    Ok(days)
+}
+
+/// `day01` through `day25`.
+pub fn is_day(name: &str) -> bool {
+    name.strip_prefix("day")
+        .filter(|n| n.len() == 2)
+        .and_then(|n| n.parse::<u32>().ok())
+        .is_some_and(|n| (1..=25).contains(&n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_days() {
+        let inline = "\
+[dependencies]
+day02 = { path = \"./day02\" }
+day01 = { path = \"./day01\" }
+# day03 = { path = \"./day03\" }
+dayfive = { path = \"./dayfive\" }
+util = { path = \"./util\" }
+";
+        assert_eq!(days(inline).unwrap(), ["day01", "day02"]);
+
+        let tables = "\
+[dependencies.day11]
+path = \"./day11\"
+
+[dependencies.util]
+path = \"./util\"
+
+[dev-dependencies.day12]
+path = \"./day12\"
+";
+        assert_eq!(days(tables).unwrap(), ["day11"]);
+        assert_eq!(
+            days("[package]\nname = \"aoc\"\n").unwrap(),
+            Vec::<String>::new()
+        );
+        assert!(days("[dependencies").is_err());
+    }
+
+    #[test]
+    fn hashes_what_a_day_is_solved_with() {
+        let dir = std::env::temp_dir().join(format!("days-code-{}", std::process::id()));
+        let (workspace, runner) = (dir.join("rust"), dir.join("runner"));
+        for src in [
+            "rust/day03/src",
+            "rust/day04/src",
+            "rust/util/src",
+            "runner/src",
+        ] {
+            fs::create_dir_all(dir.join(src)).unwrap();
+        }
+        let write = |path: &str, contents: &str| fs::write(dir.join(path), contents).unwrap();
+        write("rust/day03/src/lib.rs", "fn part1() {}");
+        write("rust/day04/src/lib.rs", "fn part1() {}");
+        write("rust/util/src/lib.rs", "fn read() {}");
+        write("runner/src/lib.rs", "fn main() {}");
+        write("rust/Cargo.lock", "version = 4");
+
+        let first = code(&workspace, &runner, "day03");
+        assert_eq!(code(&workspace, &runner, "day03"), first);
+        // The same sources under another day's name are another day's.
+        assert_ne!(code(&workspace, &runner, "day04"), first);
+
+        let mut seen = vec![first];
+        for (path, contents) in [
+            ("rust/day03/src/lib.rs", "fn part1() { }"),
+            ("rust/day03/src/grid.rs", ""),
+            ("rust/day03/Cargo.toml", "[package]"),
+            ("rust/util/src/lib.rs", "fn read() { }"),
+            ("rust/Cargo.lock", "version = 3"),
+            ("runner/src/lib.rs", "fn main() { }"),
+        ] {
+            write(path, contents);
+            let changed = code(&workspace, &runner, "day03");
+            assert!(!seen.contains(&changed), "{}", path);
+            seen.push(changed);
+        }
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
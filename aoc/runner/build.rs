@@ -0,0 +1,54 @@
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+/// Sets `AOC_REPOSITORY` to the web address of this repository, for the User-Agent
+/// adventofcode.com asks automated tools to send. It's read from the `origin` remote,
+/// else from the manifest's `repository`.
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    println!(
+        "cargo::rerun-if-changed={}",
+        root.join(".git/config").display()
+    );
+
+    let origin = Command::new("git")
+        .arg("-C")
+        .arg(&root)
+        .args(["remote", "get-url", "origin"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| web_url(String::from_utf8_lossy(&o.stdout).trim()));
+    let manifest = env::var("CARGO_PKG_REPOSITORY")
+        .ok()
+        .filter(|r| !r.is_empty());
+
+    let repository = origin.or(manifest).unwrap_or_else(|| {
+        println!(
+            "cargo::warning=No origin remote or repository in the manifest, requests to adventofcode.com won't say where this runner lives"
+        );
+        "unknown".to_string()
+    });
+    println!("cargo::rustc-env=AOC_REPOSITORY={}", repository);
+}
+
+/// `git@github.com:me/aoc.git`, `ssh://git@github.com/me/aoc` and
+/// `https://token@github.com/me/aoc.git` all become `https://github.com/me/aoc`.
+fn web_url(remote: &str) -> String {
+    let remote = remote.trim_end_matches('/').trim_end_matches(".git");
+    let (host_and_path, scp) = match remote.split_once("://") {
+        Some((_, rest)) => (rest, false),
+        None => (remote, true),
+    };
+    // Credentials don't belong in a header sent to someone else.
+    let host_and_path = host_and_path
+        .split_once('@')
+        .map_or(host_and_path, |(_, rest)| rest);
+    let host_and_path = if scp {
+        host_and_path.replacen(':', "/", 1)
+    } else {
+        host_and_path.to_string()
+    };
+    format!("https://{}", host_and_path)
+}
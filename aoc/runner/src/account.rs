@@ -0,0 +1,141 @@
+use crate::{Answers, InputError, Inputs, Source, Submissions};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Someone's inputs and answers. The default account keeps them in the year's own
+/// `inputs`, `cookie` and `answers.toml`, the others in the same layout under
+/// `{year}/accounts/NAME`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Account {
+    pub name: Option<String>,
+    pub root: PathBuf,
+}
+
+impl Account {
+    /// `root` is the year's directory, e.g. `2024`.
+    pub fn default(root: &Path) -> Self {
+        Self {
+            name: None,
+            root: root.to_path_buf(),
+        }
+    }
+
+    pub fn named(root: &Path, name: &str) -> Self {
+        Self {
+            name: Some(name.to_string()),
+            root: root.join("accounts").join(name),
+        }
+    }
+
+    /// The default account first, then the others by name.
+    pub fn all(root: &Path) -> Vec<Self> {
+        let mut names = fs::read_dir(root.join("accounts"))
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .collect::<Vec<_>>();
+        names.sort();
+
+        let mut accounts = vec![Self::default(root)];
+        accounts.extend(names.iter().map(|name| Self::named(root, name)));
+        accounts
+    }
+
+    pub fn inputs(&self) -> PathBuf {
+        self.root.join("inputs")
+    }
+
+    pub fn answers_path(&self) -> PathBuf {
+        self.root.join("answers.toml")
+    }
+
+    pub fn answers(&self) -> Result<Answers, String> {
+        Answers::load(&self.answers_path())
+    }
+
+    /// The answers sent from this account, kept next to its inputs.
+    pub fn submissions(&self) -> Submissions {
+        Submissions::new(self.root.join("submissions.jsonl"))
+    }
+
+    /// `cookie`, or a saved `cookie.json` store when there is only that.
+    pub fn cookie(&self) -> PathBuf {
+        let store = self.root.join("cookie.json");
+        if store.exists() && !self.root.join("cookie").exists() {
+            store
+        } else {
+            self.root.join("cookie")
+        }
+    }
+
+    /// The account's inputs, downloaded with its cookie.
+    pub fn downloads(&self, year: i32) -> Inputs {
+        Inputs::with_root(year, &self.root).with_cookie(self.cookie())
+    }
+
+    pub fn session(&self, year: i32) -> Result<String, InputError> {
+        self.downloads(year).session()
+    }
+
+    /// Where to read from instead of the day's own input, downloading with the
+    /// account's own cookie.
+    pub fn source(&self, year: i32) -> Option<Source> {
+        let account = self.clone();
+        self.name
+            .as_ref()
+            .map(|_| -> Source { Arc::new(move |day| account.downloads(year).read(day)) })
+    }
+
+    /// The other accounts' stored inputs for a day, with the accepted answer for `part`,
+    /// so tests can check a solution against every input there is.
+    pub fn stored(year: i32, day: u32, part: u32) -> Vec<(Account, String, String)> {
+        let root = Path::new(env!("CARGO_WORKSPACE_DIR")).join(year.to_string());
+        Self::all(&root)
+            .into_iter()
+            .skip(1)
+            .filter_map(|account| {
+                let answers = account.answers().unwrap_or_else(|e| panic!("{}", e));
+                let expected = answers.get(day, part)?.to_string();
+                let input = fs::read_to_string(account.inputs().join(format!("day{:0>2}", day)));
+                Some((account, input.ok()?, expected))
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for Account {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name.as_deref().unwrap_or("default"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_accounts() {
+        let root = std::env::temp_dir().join(format!("runner-accounts-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("accounts/bob/inputs")).unwrap();
+        fs::create_dir_all(root.join("accounts/alice")).unwrap();
+        fs::write(root.join("accounts/notes.txt"), "").unwrap();
+        fs::write(root.join("accounts/bob/inputs/day02"), "bob").unwrap();
+
+        let accounts = Account::all(&root);
+        let names = accounts.iter().map(Account::to_string).collect::<Vec<_>>();
+        assert_eq!(names, ["default", "alice", "bob"]);
+        assert_eq!(
+            accounts[2].answers_path(),
+            root.join("accounts/bob/answers.toml")
+        );
+        assert!(accounts[0].source(2022).is_none());
+        assert_eq!(accounts[2].source(2022).unwrap().read(2).unwrap(), "bob");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,155 @@
+//! Heap allocation counts for `--alloc`. Each year's runner installs [`Counting`] as
+//! its global allocator, which only counts once [`enable`] was called, so that other
+//! runs just pay for checking a flag.
+//!
+//! Counts are kept per thread: what a solution hands off to other threads, e.g. with
+//! rayon, is not included.
+
+use serde::{Deserialize, Serialize};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static COUNTER: Cell<Counter> = const { Cell::new(Counter::ZERO) };
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Allocs {
+    /// Allocations, a reallocation counting as one.
+    pub count: u64,
+    /// Bytes allocated in total, freed or not.
+    pub bytes: u64,
+    /// The most bytes live at once, counting from the start.
+    pub peak: u64,
+}
+
+#[derive(Clone, Copy)]
+struct Counter {
+    count: u64,
+    bytes: u64,
+    /// Negative when freeing what was allocated before the count started.
+    live: i64,
+    peak: i64,
+}
+
+impl Counter {
+    const ZERO: Self = Self {
+        count: 0,
+        bytes: 0,
+        live: 0,
+        peak: 0,
+    };
+}
+
+/// The system allocator, counting allocations while `--alloc` is on. Installed by the
+/// `generate_main!` macros:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOC: runner::Counting = runner::Counting;
+/// ```
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        allocated(layout.size(), layout.size() as i64);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        allocated(layout.size(), layout.size() as i64);
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        freed(layout.size());
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        allocated(new_size, new_size as i64 - layout.size() as i64);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+fn allocated(bytes: usize, growth: i64) {
+    if ENABLED.load(Ordering::Relaxed) {
+        // Unavailable while the thread is being torn down, which is after any count.
+        let _ = COUNTER.try_with(|counter| {
+            let mut c = counter.get();
+            c.count += 1;
+            c.bytes += bytes as u64;
+            c.live += growth;
+            c.peak = c.peak.max(c.live);
+            counter.set(c);
+        });
+    }
+}
+
+fn freed(bytes: usize) {
+    if ENABLED.load(Ordering::Relaxed) {
+        let _ = COUNTER.try_with(|counter| {
+            let mut c = counter.get();
+            c.live -= bytes as i64;
+            counter.set(c);
+        });
+    }
+}
+
+/// Starts counting. Returns whether [`Counting`] is the global allocator, without
+/// which nothing gets counted.
+pub fn enable() -> bool {
+    ENABLED.store(true, Ordering::Relaxed);
+    let ((), allocs) = track(|| drop(std::hint::black_box(Box::new(0u8))));
+    allocs.count > 0
+}
+
+/// Runs `func`, counting what it allocates on this thread.
+pub fn track<T>(func: impl FnOnce() -> T) -> (T, Allocs) {
+    COUNTER.set(Counter::ZERO);
+    let res = func();
+    let c = COUNTER.get();
+    let allocs = Allocs {
+        count: c.count,
+        bytes: c.bytes,
+        peak: c.peak.max(0) as u64,
+    };
+    (res, allocs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOC: Counting = Counting;
+
+    #[test]
+    fn counts_allocations() {
+        assert!(enable());
+
+        let (v, allocs) = track(|| {
+            let mut v = Vec::<u64>::with_capacity(4);
+            v.extend([1, 2, 3, 4, 5]);
+            drop(vec![0u8; 100]);
+            v
+        });
+        assert_eq!(v.len(), 5);
+        assert_eq!(allocs.count, 3);
+        assert_eq!(allocs.bytes, 32 + 64 + 100);
+        assert_eq!(allocs.peak, 164);
+
+        let ((), allocs) = track(|| drop(v));
+        assert_eq!(
+            allocs,
+            Allocs {
+                count: 0,
+                bytes: 0,
+                peak: 0
+            }
+        );
+    }
+}
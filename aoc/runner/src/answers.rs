@@ -0,0 +1,189 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Check {
+    Correct,
+    Wrong,
+    Unknown,
+}
+
+impl std::fmt::Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Check::Correct => "correct",
+            Check::Wrong => "wrong",
+            Check::Unknown => "unknown",
+        })
+    }
+}
+
+/// Accepted answers, kept per year in `answers.toml`:
+///
+/// ```toml
+/// [day01]
+/// part1 = "64929"
+/// part2 = "193697"
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    days: BTreeMap<String, Parts>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct Parts {
+    #[serde(
+        default,
+        deserialize_with = "answer",
+        skip_serializing_if = "Option::is_none"
+    )]
+    part1: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "answer",
+        skip_serializing_if = "Option::is_none"
+    )]
+    part2: Option<String>,
+}
+
+/// Answers typed in by hand are often bare numbers.
+fn answer<'de, D: Deserializer<'de>>(d: D) -> Result<Option<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Int(i64),
+        Str(String),
+    }
+
+    Ok(Some(match Raw::deserialize(d)? {
+        Raw::Int(n) => n.to_string(),
+        Raw::Str(s) => s,
+    }))
+}
+
+impl Answers {
+    /// A missing file has no answers yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(s) => s.parse(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.to_string()),
+        }
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let toml = toml::to_string(&self.days).map_err(io::Error::other)?;
+        fs::write(path, toml)
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        let parts = self.days.get(&key(day))?;
+        match part {
+            1 => parts.part1.as_deref(),
+            _ => parts.part2.as_deref(),
+        }
+    }
+
+    pub fn check(&self, day: u32, part: u32, answer: &str) -> Check {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Check::Correct,
+            Some(_) => Check::Wrong,
+            None => Check::Unknown,
+        }
+    }
+
+    /// Returns false, leaving the accepted answer alone, when there already is one.
+    pub fn insert(&mut self, day: u32, part: u32, answer: &str) -> bool {
+        let parts = self.days.entry(key(day)).or_default();
+        let slot = match part {
+            1 => &mut parts.part1,
+            _ => &mut parts.part2,
+        };
+        if slot.is_some() {
+            return false;
+        }
+        *slot = Some(answer.to_string());
+        true
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let days: BTreeMap<String, Parts> = toml::from_str(s).map_err(|e| e.to_string())?;
+        // The 0 a new day's tests start with is not an answer, an unsolved part has none.
+        for (day, parts) in &days {
+            for (part, answer) in [(1, &parts.part1), (2, &parts.part2)] {
+                if answer.as_deref() == Some("0") {
+                    return Err(format!(
+                        "{} part {} is the placeholder 0, leave an unsolved part out",
+                        day, part
+                    ));
+                }
+            }
+        }
+        Ok(Self { days })
+    }
+}
+
+fn key(day: u32) -> String {
+    format!("day{:0>2}", day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_answers() {
+        let answers =
+            "[day01]\npart1 = \"64929\"\npart2 = 193697\n\n[day10]\npart1 = \"#..#\\n.##.\"\n"
+                .parse::<Answers>()
+                .unwrap();
+
+        assert_eq!(answers.check(1, 1, "64929"), Check::Correct);
+        assert_eq!(answers.check(1, 2, "193697"), Check::Correct);
+        assert_eq!(answers.check(1, 2, "193698"), Check::Wrong);
+        assert_eq!(answers.check(10, 1, "#..#\n.##."), Check::Correct);
+        assert_eq!(answers.check(10, 2, "1"), Check::Unknown);
+        assert_eq!(answers.check(2, 1, "1"), Check::Unknown);
+        assert!("[day01]\npart1 = [1]".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn records_new_answers_only() {
+        let mut answers = Answers::default();
+        assert!(answers.insert(3, 2, "42"));
+        assert!(!answers.insert(3, 2, "43"));
+        assert!(answers.insert(3, 1, "7"));
+        assert!(answers.insert(12, 1, "ABC"));
+        assert_eq!(answers.get(3, 2), Some("42"));
+
+        let path = std::env::temp_dir().join(format!("runner-answers-{}", std::process::id()));
+        answers.save(&path).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "[day03]\npart1 = \"7\"\npart2 = \"42\"\n\n[day12]\npart1 = \"ABC\"\n"
+        );
+        assert_eq!(Answers::load(&path).unwrap(), answers);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(Answers::load(&path).unwrap(), Answers::default());
+    }
+
+    #[test]
+    fn rejects_placeholders() {
+        let placeholder = "[day01]\npart1 = \"64929\"\n\n[day02]\npart1 = 1\npart2 = 0\n";
+        assert_eq!(
+            placeholder.parse::<Answers>(),
+            Err("day02 part 2 is the placeholder 0, leave an unsolved part out".to_string())
+        );
+        assert!("[day02]\npart2 = \"0\"".parse::<Answers>().is_err());
+        assert!("[day02]\npart2 = \"10\"".parse::<Answers>().is_ok());
+    }
+}
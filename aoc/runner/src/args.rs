@@ -0,0 +1,456 @@
+use crate::bench::{Bench, DEFAULT_BUDGET};
+use crate::{Format, Input, Selection};
+use std::path::PathBuf;
+use std::time::Duration;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(120);
+
+const USAGE: &str = "\
+Usage: aoc [OPTIONS] [DAY[:PART] | FIRST-LAST[:PART]]...
+
+Examples:
+  aoc 7        Run both parts of day 7
+  aoc 7:2      Run part 2 of day 7
+  aoc 3-9      Run days 3 through 9
+  aoc 5 --input ~/alice/day05
+               Run day 5 on someone else's input
+  aoc --all-accounts
+               Run every day on every account's stored inputs";
+
+pub const OPTIONS: &str = "\
+Options:
+      --latest          Run the most recent day
+      --format FORMAT   Output as table, json (one object per line) or csv
+      --bench N         Time each part over N runs after a short warm-up
+      --budget SECS     Stop benchmarking a part after SECS seconds [default: 5]
+      --alloc           Count the allocations, bytes and peak live bytes of parsing and
+                        of each part, shown instead of the timings
+      --profile         Sample each part while it runs, writing a flamegraph and folded
+                        stacks to dayNN/profile in the workspace. Parts get no timeout
+      --jobs N          Run up to N days at once, reported in day order. Their timings are
+                        flagged as contended, and days using rayon get 1/N of the cores
+      --fresh           Solve every part, also those answered before for the same input and
+                        code; with --bench, --alloc, --profile or --compare they always are
+      --compare         Flag parts that got slower than in the previous run
+      --baseline NAME   Compare against the latest run saved as NAME instead
+      --threshold PCT   Slowdown that counts as a regression [default: 10]
+      --save-baseline NAME
+                        Label this run in the history as NAME
+      --no-history      Don't append this run to the timing history
+      --record          Save answers that answers.toml doesn't have yet
+      --timeout SECS    Give up on a part after SECS seconds, 0 to wait forever [default: 120]
+      --input PATH      Read the input from PATH, or from PATH/dayNN if it is a directory
+      --stdin           Read the input from stdin, for a single day
+      --example N       Read the input from examples/dayNN/N
+                        Answers aren't checked and timings not recorded for other inputs
+      --account NAME    Run on the inputs stored in accounts/NAME, checked against its answers
+      --all-accounts    Run on the inputs of every account
+      --list            Print the days there are, one per line, and exit
+  -h, --help            Print this help";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Args {
+    pub selection: Selection,
+    pub format: Format,
+    pub bench: Option<Bench>,
+    pub alloc: bool,
+    pub profile: bool,
+    /// Days run at once, `None` for one after another.
+    pub jobs: Option<usize>,
+    /// Don't reuse cached answers.
+    pub fresh: bool,
+    pub compare: Option<Compare>,
+    pub label: Option<String>,
+    pub no_history: bool,
+    pub record: bool,
+    pub timeout: Option<Duration>,
+    /// Replaces the cached input.
+    pub input: Option<Input>,
+    pub account: Option<String>,
+    pub all_accounts: bool,
+    pub list: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Compare {
+    pub baseline: Option<String>,
+    /// Percent
+    pub threshold: u32,
+}
+
+impl Args {
+    pub fn from_env(available: &[u32]) -> Self {
+        match Self::parse(std::env::args().skip(1), available) {
+            Ok(Some(args)) => args,
+            Ok(None) => {
+                println!("{}\n\n{}", USAGE, OPTIONS);
+                std::process::exit(0);
+            }
+            Err(e) => {
+                eprintln!("{}\n\n{}\n\n{}", e, USAGE, OPTIONS);
+                std::process::exit(2);
+            }
+        }
+    }
+
+    /// Whether `flag` is followed by its value as a separate argument.
+    pub fn takes_value(flag: &str) -> bool {
+        VALUED.contains(&flag)
+    }
+
+    /// Returns `Ok(None)` when help was requested.
+    pub fn parse<I, S>(args: I, available: &[u32]) -> Result<Option<Self>, String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+        let mut runs = None;
+        let mut budget = DEFAULT_BUDGET;
+        let mut compare = false;
+        let mut baseline = None;
+        let mut threshold = 10;
+        let mut timeout = None;
+
+        while let Some(arg) = args.next() {
+            let (flag, inline) = match arg.as_ref().split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => (flag, Some(value)),
+                _ => (arg.as_ref(), None),
+            };
+            let mut value = || match inline {
+                Some(value) => Ok(value.to_string()),
+                None => args
+                    .next()
+                    .map(|v| v.as_ref().to_string())
+                    .ok_or_else(|| format!("Missing value for {}", flag)),
+            };
+
+            let mut secs = |flag| {
+                Duration::try_from_secs_f64(parse_value(flag, &value()?)?)
+                    .map_err(|e| format!("Invalid value for {}: {}", flag, e))
+            };
+
+            match flag {
+                "-h" | "--help" => return Ok(None),
+                "--latest" => parsed.selection.push_latest(available),
+                "--format" => parsed.format = value()?.parse()?,
+                "--bench" => runs = Some(parse_value::<usize>(flag, &value()?)?.max(1)),
+                "--budget" => budget = secs(flag)?,
+                "--alloc" => parsed.alloc = true,
+                "--profile" => parsed.profile = true,
+                "--jobs" => match parse_value::<usize>(flag, &value()?)? {
+                    0 => return Err("Invalid value for --jobs: 0, it takes at least 1".to_string()),
+                    jobs => parsed.jobs = Some(jobs),
+                },
+                "--fresh" => parsed.fresh = true,
+                "--compare" => compare = true,
+                "--baseline" => baseline = Some(value()?),
+                "--threshold" => threshold = parse_value(flag, &value()?)?,
+                "--save-baseline" => parsed.label = Some(value()?),
+                "--no-history" => parsed.no_history = true,
+                "--record" => parsed.record = true,
+                "--timeout" => timeout = Some(secs(flag)?),
+                "--input" | "--stdin" | "--example" if parsed.input.is_some() => {
+                    return Err("Only one of --input, --stdin and --example".to_string());
+                }
+                "--input" => parsed.input = Some(Input::Path(PathBuf::from(value()?))),
+                "--stdin" => parsed.input = Some(Input::Stdin),
+                "--account" => parsed.account = Some(value()?),
+                "--all-accounts" => parsed.all_accounts = true,
+                "--list" => parsed.list = true,
+                "--example" => parsed.input = Some(Input::Example(parse_value(flag, &value()?)?)),
+                flag if flag.starts_with('-') => {
+                    return Err(format!("Unknown option: {}", flag));
+                }
+                spec => parsed.selection.push_spec(spec, available)?,
+            }
+            if inline.is_some() && !Self::takes_value(flag) {
+                return Err(format!("{} doesn't take a value", flag));
+            }
+        }
+
+        if parsed.record && parsed.input.is_some() {
+            return Err("--record only applies to the real input".to_string());
+        }
+        if parsed.alloc && runs.is_some() {
+            return Err("Either --alloc or --bench".to_string());
+        }
+        // One day at a time is the same as without.
+        parsed.jobs = parsed.jobs.filter(|&jobs| jobs > 1);
+        if parsed.jobs.is_some() && (runs.is_some() || compare || baseline.is_some()) {
+            return Err("--bench and --compare need timings that --jobs would contend".to_string());
+        }
+        if parsed.profile && runs.is_some() {
+            return Err("Either --profile or --bench".to_string());
+        }
+        // A part that times out keeps running on its abandoned worker, and with it the
+        // profiler, so a profiled run waits for every part.
+        if parsed.profile && timeout.is_some_and(|t: Duration| !t.is_zero()) {
+            return Err(
+                "Either --profile or --timeout, a timed out part would keep sampling".to_string(),
+            );
+        }
+        if parsed.jobs.is_some() && parsed.profile {
+            return Err(
+                "Either --profile or --jobs, the profiler samples every day at once".to_string(),
+            );
+        }
+        if parsed.account.is_some() && parsed.all_accounts {
+            return Err("Either --account or --all-accounts".to_string());
+        }
+        if parsed.input.is_some() && (parsed.account.is_some() || parsed.all_accounts) {
+            return Err(
+                "--input, --stdin and --example can't be combined with accounts".to_string(),
+            );
+        }
+
+        let days = available
+            .iter()
+            .filter(|&&day| parsed.selection.contains_day(day))
+            .count();
+        // A directory has an input per day, a file or stdin only the one.
+        let single = match &parsed.input {
+            Some(Input::Path(path)) => !path.is_dir(),
+            Some(Input::Stdin) => true,
+            _ => false,
+        };
+        if single && days != 1 {
+            return Err(
+                "--stdin and --input with a file are one day's input, select exactly one day"
+                    .to_string(),
+            );
+        }
+
+        parsed.bench = runs.map(|runs| Bench { runs, budget });
+        parsed.timeout = match timeout {
+            Some(timeout) => Some(timeout).filter(|t| !t.is_zero()),
+            None if parsed.profile => None,
+            None => Some(DEFAULT_TIMEOUT),
+        };
+        if compare || baseline.is_some() {
+            parsed.compare = Some(Compare {
+                baseline,
+                threshold,
+            });
+        }
+
+        Ok(Some(parsed))
+    }
+}
+
+/// Options that take a value, for wrappers that pass options through.
+const VALUED: &[&str] = &[
+    "--format",
+    "--bench",
+    "--budget",
+    "--jobs",
+    "--baseline",
+    "--threshold",
+    "--save-baseline",
+    "--timeout",
+    "--input",
+    "--example",
+    "--account",
+];
+
+fn parse_value<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for {}: {}", flag, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_flags_and_specs() {
+        let args = Args::parse(["--latest", "2:1"], &[1, 2, 3])
+            .unwrap()
+            .unwrap();
+        assert!(args.selection.contains(3, 2));
+        assert!(args.selection.contains(2, 1));
+        assert!(!args.selection.contains(2, 2));
+        assert!(!args.selection.contains(1, 1));
+    }
+
+    #[test]
+    fn parses_format() {
+        let args = Args::parse(["--format", "json"], &[1]).unwrap().unwrap();
+        assert_eq!(args.format, Format::Json);
+        let args = Args::parse(["--format=csv", "1"], &[1]).unwrap().unwrap();
+        assert_eq!(args.format, Format::Csv);
+        assert!(Args::parse(["--format"], &[1]).is_err());
+        assert!(Args::parse(["--format", "xml"], &[1]).is_err());
+    }
+
+    #[test]
+    fn parses_bench() {
+        let args = Args::parse(["--budget", "0.5", "--bench=200"], &[1])
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            args.bench,
+            Some(Bench {
+                runs: 200,
+                budget: Duration::from_millis(500)
+            })
+        );
+        assert_eq!(Args::parse(["1"], &[1]).unwrap().unwrap().bench, None);
+        assert!(Args::parse(["--bench", "many"], &[1]).is_err());
+        assert!(Args::parse(["--bench", "5", "--budget", "-1"], &[1]).is_err());
+    }
+
+    #[test]
+    fn parses_alloc() {
+        assert!(Args::parse(["--alloc", "1"], &[1]).unwrap().unwrap().alloc);
+        assert!(!Args::parse(["1"], &[1]).unwrap().unwrap().alloc);
+        assert!(Args::parse(["--alloc", "--bench", "10"], &[1]).is_err());
+    }
+
+    #[test]
+    fn parses_jobs() {
+        let jobs = |args: &[&str]| Args::parse(args, &[1]).map(|a| a.unwrap().jobs);
+        assert_eq!(jobs(&["--jobs", "4"]), Ok(Some(4)));
+        assert_eq!(jobs(&["--jobs=1"]), Ok(None));
+        assert_eq!(jobs(&["1"]), Ok(None));
+        assert!(jobs(&["--jobs", "all"]).is_err());
+        assert_eq!(
+            jobs(&["--jobs", "0"]),
+            Err("Invalid value for --jobs: 0, it takes at least 1".to_string())
+        );
+        assert!(jobs(&["--jobs", "4", "--bench", "10"]).is_err());
+        assert!(jobs(&["--jobs", "4", "--compare"]).is_err());
+        assert!(jobs(&["--jobs", "4", "--profile"]).is_err());
+        assert!(Args::takes_value("--jobs"));
+    }
+
+    #[test]
+    fn parses_fresh() {
+        assert!(Args::parse(["--fresh", "1"], &[1]).unwrap().unwrap().fresh);
+        assert!(!Args::parse(["1"], &[1]).unwrap().unwrap().fresh);
+        assert!(!Args::takes_value("--fresh"));
+        assert_eq!(
+            Args::parse(["--fresh=false", "1"], &[1]),
+            Err("--fresh doesn't take a value".to_string())
+        );
+    }
+
+    #[test]
+    fn parses_profile() {
+        let args = Args::parse(["--profile", "3:2"], &[3]).unwrap().unwrap();
+        assert!(args.profile);
+        assert!(args.selection.contains(3, 2));
+        assert!(!Args::takes_value("--profile"));
+        assert_eq!(args.timeout, None);
+
+        let profile = |args: &[&str]| Args::parse(args, &[3]).map(Option::unwrap);
+        assert_eq!(
+            profile(&["--profile", "--bench", "10", "3"]).err(),
+            Some("Either --profile or --bench".to_string())
+        );
+        assert!(profile(&["--profile", "--timeout", "5", "3"]).is_err());
+        assert_eq!(
+            profile(&["--profile", "--timeout", "0", "3"])
+                .unwrap()
+                .timeout,
+            None
+        );
+    }
+
+    #[test]
+    fn parses_compare() {
+        let args = Args::parse(["--compare", "--threshold", "25"], &[1])
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            args.compare,
+            Some(Compare {
+                baseline: None,
+                threshold: 25
+            })
+        );
+
+        let args = Args::parse(["--baseline", "v1", "--save-baseline", "v2"], &[1])
+            .unwrap()
+            .unwrap();
+        assert_eq!(args.compare.unwrap().baseline.as_deref(), Some("v1"));
+        assert_eq!(args.label.as_deref(), Some("v2"));
+        assert_eq!(Args::parse(["1"], &[1]).unwrap().unwrap().compare, None);
+    }
+
+    #[test]
+    fn parses_timeout() {
+        let timeout = |args: &[&str]| Args::parse(args, &[1]).unwrap().unwrap().timeout;
+        assert_eq!(timeout(&[]), Some(DEFAULT_TIMEOUT));
+        assert_eq!(
+            timeout(&["--timeout", "2.5"]),
+            Some(Duration::from_millis(2500))
+        );
+        assert_eq!(timeout(&["--timeout=0"]), None);
+        assert!(Args::parse(["--timeout", "soon"], &[1]).is_err());
+    }
+
+    #[test]
+    fn parses_input() {
+        let input = |args: &[&str]| Args::parse(args, &[1]).map(|a| a.unwrap().input);
+        assert_eq!(input(&[]), Ok(None));
+        assert_eq!(
+            input(&["--input", "alice/day01"]),
+            Ok(Some(Input::Path(PathBuf::from("alice/day01"))))
+        );
+        assert_eq!(input(&["--stdin", "1"]), Ok(Some(Input::Stdin)));
+        assert_eq!(input(&["--example=2"]), Ok(Some(Input::Example(2))));
+        assert!(input(&["--example", "first"]).is_err());
+        assert!(input(&["--stdin", "--example", "1"]).is_err());
+        assert!(input(&["--stdin", "--record"]).is_err());
+
+        // Another day's input would only fail to parse, or worse, give a wrong answer.
+        let input = |args: &[&str]| Args::parse(args, &[1, 2, 3]).map(|a| a.unwrap().input);
+        assert_eq!(input(&["--stdin", "2"]), Ok(Some(Input::Stdin)));
+        assert!(input(&["--stdin"]).is_err());
+        assert!(input(&["--stdin", "1-2"]).is_err());
+        assert!(input(&["--input", "day02", "2", "3"]).is_err());
+        let dir = std::env::temp_dir().to_string_lossy().to_string();
+        assert!(input(&["--input", &dir, "2", "3"]).is_ok());
+        assert!(input(&["--example", "1"]).is_ok());
+    }
+
+    #[test]
+    fn parses_accounts() {
+        let args = Args::parse(["--account", "alice", "--record"], &[1])
+            .unwrap()
+            .unwrap();
+        assert_eq!(args.account.as_deref(), Some("alice"));
+        assert!(!args.all_accounts);
+        assert!(
+            Args::parse(["--all-accounts"], &[1])
+                .unwrap()
+                .unwrap()
+                .all_accounts
+        );
+        assert!(Args::parse(["--account", "alice", "--all-accounts"], &[1]).is_err());
+        assert!(Args::parse(["--all-accounts", "--stdin"], &[1]).is_err());
+    }
+
+    #[test]
+    fn knows_which_flags_take_values() {
+        for flag in VALUED {
+            assert_eq!(
+                Args::parse([flag], &[1]),
+                Err(format!("Missing value for {}", flag))
+            );
+        }
+        assert!(Args::parse(["--record"], &[1]).is_ok());
+        assert!(!Args::takes_value("--record"));
+        assert!(Args::parse(["--list"], &[1]).unwrap().unwrap().list);
+        assert!(!Args::takes_value("--list"));
+    }
+
+    #[test]
+    fn rejects_unknown_flags() {
+        assert!(Args::parse(["--nope"], &[1]).is_err());
+        assert_eq!(Args::parse(["-h"], &[1]), Ok(None));
+    }
+}
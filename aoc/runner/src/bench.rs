@@ -0,0 +1,162 @@
+use crate::measure;
+use crate::report::nanos;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_BUDGET: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bench {
+    pub runs: usize,
+    /// Wall-clock limit per part, including warm-up and input preparation.
+    pub budget: Duration,
+}
+
+impl Bench {
+    /// Runs `part` on fresh data from `fresh` until `runs` samples are taken or the
+    /// budget is spent, after roughly a tenth as many untimed warm-up runs. At least
+    /// one sample is always taken.
+    pub fn run<T, R>(&self, fresh: impl Fn() -> T, part: impl Fn(T) -> R) -> (R, Stats) {
+        let start = Instant::now();
+        let mut answer = None;
+
+        for _ in 0..self.runs.div_ceil(10) {
+            answer = Some(part(fresh()));
+            if start.elapsed() >= self.budget {
+                break;
+            }
+        }
+
+        let mut samples = Vec::with_capacity(self.runs);
+        while samples.len() < self.runs && (samples.is_empty() || start.elapsed() < self.budget) {
+            let data = fresh();
+            let (res, duration) = measure(|| part(data));
+            samples.push(duration);
+            answer = Some(res);
+        }
+
+        (answer.unwrap(), Stats::from_samples(&mut samples).unwrap())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub runs: usize,
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", with = "nanos")]
+    pub mean: Duration,
+    #[serde(rename = "p95_ns", with = "nanos")]
+    pub p95: Duration,
+    #[serde(rename = "stddev_ns", with = "nanos")]
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        samples.sort();
+        let n = samples.len();
+        let nanos = samples
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<_>>();
+
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        let p95 = samples[(n * 95).div_ceil(100) - 1];
+
+        Some(Self {
+            runs: n,
+            min: samples[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            p95,
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn micros(ns: &[u64]) -> Vec<Duration> {
+        ns.iter().map(|&n| Duration::from_micros(n)).collect()
+    }
+
+    #[test]
+    fn stats() {
+        let mut samples = micros(&[5, 1, 4, 2, 3]);
+        let stats = Stats::from_samples(&mut samples).unwrap();
+
+        assert_eq!(stats.runs, 5);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_micros(3));
+        assert_eq!(stats.mean, Duration::from_micros(3));
+        assert_eq!(stats.p95, Duration::from_micros(5));
+        assert_eq!(stats.stddev, Duration::from_nanos(1581));
+    }
+
+    #[test]
+    fn stats_even_and_single() {
+        let mut samples = micros(&[4, 1, 3, 2]);
+        assert_eq!(
+            Stats::from_samples(&mut samples).unwrap().median,
+            Duration::from_nanos(2500)
+        );
+
+        let mut samples = micros(&[7]);
+        let stats = Stats::from_samples(&mut samples).unwrap();
+        assert_eq!(stats.p95, Duration::from_micros(7));
+        assert_eq!(stats.stddev, Duration::ZERO);
+
+        assert_eq!(Stats::from_samples(&mut []), None);
+    }
+
+    #[test]
+    fn runs_requested_samples() {
+        let calls = Cell::new(0);
+        let bench = Bench {
+            runs: 20,
+            budget: Duration::from_secs(60),
+        };
+        let (answer, stats) = bench.run(
+            || 21,
+            |n| {
+                calls.set(calls.get() + 1);
+                n * 2
+            },
+        );
+
+        assert_eq!(answer, 42);
+        assert_eq!(stats.runs, 20);
+        assert_eq!(calls.get(), 22);
+    }
+
+    #[test]
+    fn stops_at_budget() {
+        let bench = Bench {
+            runs: 1_000_000,
+            budget: Duration::from_millis(20),
+        };
+        let (_, stats) = bench.run(|| (), |_| std::thread::sleep(Duration::from_millis(5)));
+
+        assert!(stats.runs >= 1 && stats.runs < 10);
+    }
+}
@@ -0,0 +1,134 @@
+//! Answers of earlier runs, kept in `{year}/cache.jsonl` and reused unless `--fresh`.
+//! Each is keyed by a hash of the input and one of the code it was solved with, which
+//! the year's build works out from the sources, manifests and lockfile of the day, the
+//! shared crates and the runner (see the `days` crate), so that changing any of them
+//! solves the day again, and a binary built before a change still has its own.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use twox_hash::XxHash64;
+
+pub struct Cache {
+    path: PathBuf,
+    answers: Mutex<HashMap<Key, String>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Key {
+    day: u32,
+    part: u32,
+    input: u64,
+    code: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    day: u32,
+    part: u32,
+    input: String,
+    code: String,
+    answer: String,
+}
+
+impl Cache {
+    /// Lines that fail to parse are skipped, like those of the history.
+    pub fn load(path: PathBuf) -> Self {
+        let answers = fs::read_to_string(&path)
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| serde_json::from_str::<Entry>(line).ok())
+            .filter_map(|entry| {
+                let key = Key {
+                    day: entry.day,
+                    part: entry.part,
+                    input: u64::from_str_radix(&entry.input, 16).ok()?,
+                    code: u64::from_str_radix(&entry.code, 16).ok()?,
+                };
+                Some((key, entry.answer))
+            })
+            .collect();
+        Self {
+            path,
+            answers: Mutex::new(answers),
+        }
+    }
+
+    pub fn get(&self, day: u32, part: u32, input: u64, code: u64) -> Option<String> {
+        let key = Key {
+            day,
+            part,
+            input,
+            code,
+        };
+        self.answers.lock().unwrap().get(&key).cloned()
+    }
+
+    /// Keeps an answer, adding it to the file unless it is there already.
+    pub fn insert(
+        &self,
+        day: u32,
+        part: u32,
+        input: u64,
+        code: u64,
+        answer: &str,
+    ) -> std::io::Result<()> {
+        let key = Key {
+            day,
+            part,
+            input,
+            code,
+        };
+        let mut answers = self.answers.lock().unwrap();
+        if answers.get(&key).is_some_and(|a| a == answer) {
+            return Ok(());
+        }
+        let entry = Entry {
+            day,
+            part,
+            input: format!("{:016x}", input),
+            code: format!("{:016x}", code),
+            answer: answer.to_string(),
+        };
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(&entry).unwrap())?;
+        answers.insert(key, answer.to_string());
+        Ok(())
+    }
+}
+
+/// The hash of an input, the same with every toolchain.
+pub fn hash(input: &str) -> u64 {
+    XxHash64::oneshot(0, input.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_answers_per_input_and_code() {
+        let path = std::env::temp_dir().join(format!("runner-cache-{}", std::process::id()));
+        let (code, changed) = (0xc0de, 0xc0df);
+
+        let cache = Cache::load(path.clone());
+        cache.insert(3, 1, hash("1 2 3"), code, "6").unwrap();
+        cache.insert(3, 1, hash("1 2 3"), code, "6").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 1);
+
+        let cache = Cache::load(path.clone());
+        assert_eq!(cache.get(3, 1, hash("1 2 3"), code).as_deref(), Some("6"));
+        assert_eq!(cache.get(3, 2, hash("1 2 3"), code), None);
+        assert_eq!(cache.get(3, 1, hash("1 2 4"), code), None);
+        assert_eq!(cache.get(3, 1, hash("1 2 3"), changed), None);
+        // Pinned, a different hash would miss every cached answer.
+        assert_eq!(hash("1 2 3"), 0xb3a9_fb5f_b179_00e9);
+        fs::remove_file(path).unwrap();
+    }
+}
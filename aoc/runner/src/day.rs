@@ -0,0 +1,731 @@
+use crate::cache::{self, Cache};
+use crate::isolate::catch;
+use crate::profile::Profiler;
+use crate::{Allocs, Args, Bench, InputError, InputSource, Source, Stats, alloc, measure};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::Duration;
+
+/// Solutions may recurse deeply, so workers get more than the default 2 MiB.
+const STACK_SIZE: usize = 64 << 20;
+
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u32,
+    run: fn(&mut Ctx),
+    code: Option<u64>,
+}
+
+impl Day {
+    /// `name` is the day crate's name, e.g. `day07`.
+    pub fn new(name: &str, run: fn(&mut Ctx)) -> Self {
+        let day = name.trim_start_matches("day").parse::<u32>().unwrap();
+        Self {
+            day,
+            run,
+            code: None,
+        }
+    }
+
+    /// The hash of what the day is solved with, the `AOC_CODE_dayNN` its build sets, to
+    /// cache its answers under. Without one, as in a day's own bin, they aren't.
+    pub fn with_code(mut self, code: Option<&str>) -> Self {
+        self.code = code.and_then(|code| u64::from_str_radix(code, 16).ok());
+        self
+    }
+
+    /// Runs the selected parts on a worker thread. Each part gets `args.timeout`,
+    /// counted from the previous answer, so part 1's also covers reading and parsing.
+    /// When a part times out the worker is abandoned and the remaining part starts
+    /// over on a fresh one. `input` replaces the day's own input, and with `--profile`
+    /// each part's profile is written to `profile`. Parts in `cache` aren't solved.
+    pub fn run(
+        &self,
+        args: &Args,
+        input: Option<&Source>,
+        profile: Option<&Path>,
+        cache: Option<&Arc<Cache>>,
+    ) -> Vec<Outcome> {
+        let mut pending = [1, 2].map(|part| args.selection.contains(self.day, part));
+        let mut outcomes = Vec::new();
+
+        while pending.contains(&true) {
+            let rx = self.spawn(
+                pending,
+                args.bench,
+                args.alloc,
+                profile.map(Path::to_path_buf),
+                input.cloned(),
+                cache.cloned(),
+            );
+            loop {
+                let received = match args.timeout {
+                    Some(timeout) => rx.recv_timeout(timeout),
+                    None => rx.recv().map_err(RecvTimeoutError::from),
+                };
+                match received {
+                    Ok(outcome) => {
+                        pending[outcome.part as usize - 1] = false;
+                        outcomes.push(outcome);
+                    }
+                    Err(RecvTimeoutError::Timeout) => {
+                        let part = pending.iter().position(|&p| p).unwrap() as u32 + 1;
+                        pending[part as usize - 1] = false;
+                        outcomes.push(Outcome::timeout(part, args.timeout.unwrap()));
+                        break;
+                    }
+                    Err(RecvTimeoutError::Disconnected) => {
+                        pending = [false; 2];
+                        break;
+                    }
+                }
+            }
+        }
+
+        outcomes
+    }
+
+    fn spawn(
+        &self,
+        parts: [bool; 2],
+        bench: Option<Bench>,
+        alloc: bool,
+        profile: Option<PathBuf>,
+        input: Option<Source>,
+        cache: Option<Arc<Cache>>,
+    ) -> Receiver<Outcome> {
+        let (tx, rx) = mpsc::channel();
+        let (day, run, code) = (self.day, self.run, self.code);
+        thread::Builder::new()
+            .name(format!("day{:0>2}", day))
+            .stack_size(STACK_SIZE)
+            .spawn(move || {
+                let mut ctx = Ctx::new(day, parts, bench, alloc, profile, input, tx);
+                ctx.cache = cache;
+                ctx.code = code;
+                if let Err(message) = catch(|| run(&mut ctx)) {
+                    ctx.fail(Status::Panic, message);
+                }
+            })
+            .unwrap();
+        rx
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    #[default]
+    Ok,
+    Panic,
+    Timeout,
+    /// The puzzle isn't out yet; skipped rather than failed.
+    Unreleased,
+    /// The input could not be read or downloaded.
+    NoInput,
+}
+
+impl Status {
+    /// The name used in JSON and CSV.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Panic => "panic",
+            Status::Timeout => "timeout",
+            Status::Unreleased => "unreleased",
+            Status::NoInput => "no_input",
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Status::Ok => "OK",
+            Status::Panic => "PANIC",
+            Status::Timeout => "TIMEOUT",
+            Status::Unreleased => "UNRELEASED",
+            Status::NoInput => "NO INPUT",
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Outcome {
+    pub part: u32,
+    pub answer: String,
+    pub read: Option<Duration>,
+    pub parse: Option<Duration>,
+    pub solve: Duration,
+    pub reparsed: bool,
+    /// The answer of an earlier run, nothing was solved.
+    pub cached: bool,
+    pub stats: Option<Stats>,
+    /// With `--alloc`, on the part that parsed like `parse`.
+    pub parse_allocs: Option<Allocs>,
+    pub allocs: Option<Allocs>,
+    pub status: Status,
+    pub error: Option<String>,
+}
+
+impl Outcome {
+    fn timeout(part: u32, timeout: Duration) -> Self {
+        Self {
+            part,
+            solve: timeout,
+            status: Status::Timeout,
+            error: Some(format!("no answer after {:?}", timeout)),
+            ..Default::default()
+        }
+    }
+}
+
+/// Drives a single day. The input is read and parsed once; when both parts run and
+/// the parsed type is not `Clone`, part 2 parses the input again.
+pub struct Ctx {
+    day: u32,
+    /// Parts that are selected and have not reported yet.
+    parts: [bool; 2],
+    bench: Option<Bench>,
+    /// Count allocations.
+    alloc: bool,
+    /// Where to write each part's profile.
+    profile: Option<PathBuf>,
+    /// Sampling the part that reports next.
+    profiler: Option<Profiler>,
+    /// Read instead of the input the day asks for.
+    input: Option<Source>,
+    read: Option<Duration>,
+    cache: Option<Arc<Cache>>,
+    code: Option<u64>,
+    /// The hashes of the input and of the code, to cache answers under.
+    key: Option<(u64, u64)>,
+    /// Reported with the next outcome, like `read`.
+    parse_allocs: Option<Allocs>,
+    allocs: Option<Allocs>,
+    outcomes: Sender<Outcome>,
+}
+
+impl Ctx {
+    fn new(
+        day: u32,
+        parts: [bool; 2],
+        bench: Option<Bench>,
+        alloc: bool,
+        profile: Option<PathBuf>,
+        input: Option<Source>,
+        outcomes: Sender<Outcome>,
+    ) -> Self {
+        Self {
+            day,
+            parts,
+            bench,
+            alloc,
+            profile,
+            profiler: None,
+            input,
+            read: None,
+            cache: None,
+            code: None,
+            key: None,
+            parse_allocs: None,
+            allocs: None,
+            outcomes,
+        }
+    }
+
+    /// Reads the input from `source`, unless one was given on the command line. Returns
+    /// `None` when there is no input, after reporting why for every part, and when
+    /// every part was answered from the cache.
+    pub fn read(&mut self, source: impl InputSource) -> Option<String> {
+        let (input, duration) = measure(|| match &self.input {
+            Some(input) => input.read(self.day),
+            None => source.read(self.day),
+        });
+        self.read = Some(duration);
+        match input {
+            Ok(input) => {
+                self.answer_cached(&input);
+                self.parts.contains(&true).then_some(input)
+            }
+            Err(e) => {
+                let status = match e {
+                    InputError::NotYetReleased(_) | InputError::TooEarly => Status::Unreleased,
+                    _ => Status::NoInput,
+                };
+                self.fail(status, e.to_string());
+                None
+            }
+        }
+    }
+
+    pub fn solve<T, A, B>(
+        &mut self,
+        parse: impl Fn() -> T,
+        cloner: Option<fn(&T) -> T>,
+        part1: impl Fn(T) -> A,
+        part2: impl Fn(T) -> B,
+    ) where
+        A: Display,
+        B: Display,
+    {
+        self.start_profile();
+        if let Some(bench) = self.bench {
+            return self.bench(bench, parse, cloner, part1, part2);
+        }
+
+        let [run1, run2] = self.parts;
+        let ((parsed, allocs), duration) = measure(|| self.track(&parse));
+        let mut parse_time = Some(duration);
+        let mut parsed = Some(parsed);
+        self.parse_allocs = allocs;
+
+        if run1 {
+            let data = match (run2, cloner) {
+                (true, Some(clone)) => clone(parsed.as_ref().unwrap()),
+                _ => parsed.take().unwrap(),
+            };
+            let ((answer, allocs), solve) = measure(|| self.track(|| catch(|| part1(data))));
+            self.allocs = allocs;
+            self.push(1, answer, parse_time.take(), solve, false, None);
+        }
+
+        if run2 {
+            self.start_profile();
+            let (data, reparsed) = match parsed.take() {
+                Some(data) => (data, false),
+                None => {
+                    let ((data, allocs), duration) = measure(|| self.track(&parse));
+                    parse_time = Some(duration);
+                    self.parse_allocs = allocs;
+                    (data, true)
+                }
+            };
+            let ((answer, allocs), solve) = measure(|| self.track(|| catch(|| part2(data))));
+            self.allocs = allocs;
+            self.push(2, answer, parse_time.take(), solve, reparsed, None);
+        }
+    }
+
+    /// Between runs the input is cloned, or parsed again when it is not `Clone`;
+    /// neither is included in the samples.
+    fn bench<T, A, B>(
+        &mut self,
+        bench: Bench,
+        parse: impl Fn() -> T,
+        cloner: Option<fn(&T) -> T>,
+        part1: impl Fn(T) -> A,
+        part2: impl Fn(T) -> B,
+    ) where
+        A: Display,
+        B: Display,
+    {
+        let [run1, run2] = self.parts;
+        let (parsed, duration) = measure(&parse);
+        let mut parse_time = Some(duration);
+        let fresh = || match cloner {
+            Some(clone) => clone(&parsed),
+            None => parse(),
+        };
+
+        if run1 {
+            let (answer, solve, stats) = time(Some(bench), fresh, &part1);
+            self.push(1, answer, parse_time.take(), solve, false, stats);
+        }
+
+        if run2 {
+            self.start_profile();
+            let (answer, solve, stats) = time(Some(bench), fresh, &part2);
+            self.push(2, answer, parse_time.take(), solve, false, stats);
+        }
+    }
+
+    /// Runs `func`, counting its allocations with `--alloc`.
+    fn track<R>(&self, func: impl FnOnce() -> R) -> (R, Option<Allocs>) {
+        if self.alloc {
+            let (res, allocs) = alloc::track(func);
+            (res, Some(allocs))
+        } else {
+            (func(), None)
+        }
+    }
+
+    /// Reports the parts that were solved before for the same input and code.
+    fn answer_cached(&mut self, input: &str) {
+        let (Some(cache), Some(code)) = (self.cache.clone(), self.code) else {
+            return;
+        };
+        let input = cache::hash(input);
+        self.key = Some((input, code));
+        let [run1, run2] = self.parts;
+        for part in [(1, run1), (2, run2)]
+            .into_iter()
+            .filter_map(|(p, run)| run.then_some(p))
+        {
+            if let Some(answer) = cache.get(self.day, part, input, code) {
+                let read = self.read.take();
+                self.send(Outcome {
+                    part,
+                    answer,
+                    read,
+                    cached: true,
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
+    /// Samples from here until the next part reports, with `--profile`. The first part's
+    /// profile includes parsing.
+    fn start_profile(&mut self) {
+        if self.profile.is_some() && self.profiler.is_none() {
+            match Profiler::start() {
+                Ok(profiler) => self.profiler = Some(profiler),
+                Err(e) => eprintln!("Not profiling day {}: {}", self.day, e),
+            }
+        }
+    }
+
+    fn push(
+        &mut self,
+        part: u32,
+        answer: Result<impl Display, String>,
+        parse: Option<Duration>,
+        solve: Duration,
+        reparsed: bool,
+        stats: Option<Stats>,
+    ) {
+        let (answer, status, error) = match answer {
+            Ok(answer) => (answer.to_string(), Status::Ok, None),
+            Err(message) => (String::new(), Status::Panic, Some(message)),
+        };
+        let read = self.read.take();
+        let parse_allocs = self.parse_allocs.take();
+        let allocs = self.allocs.take();
+        if let (Some(cache), Some((input, code))) = (&self.cache, self.key)
+            && status == Status::Ok
+            && let Err(e) = cache.insert(self.day, part, input, code, &answer)
+        {
+            eprintln!("Failed to cache day {} part {}: {}", self.day, part, e);
+        }
+        if let (Some(dir), Some(profiler)) = (&self.profile, self.profiler.take()) {
+            let title = format!("day{:0>2} part {}", self.day, part);
+            match profiler.write(dir, &format!("part{}", part), &title) {
+                Ok(path) => eprintln!("Profile of {} in {}", title, path.display()),
+                Err(e) => eprintln!("No profile of {}: {}", title, e),
+            }
+        }
+        self.send(Outcome {
+            part,
+            answer,
+            read,
+            parse,
+            solve,
+            reparsed,
+            cached: false,
+            stats,
+            parse_allocs,
+            allocs,
+            status,
+            error,
+        });
+    }
+
+    /// Reports every part that has no answer yet with `status`, for failures outside
+    /// the parts themselves, e.g. while reading or parsing.
+    fn fail(&mut self, status: Status, message: String) {
+        let [run1, run2] = self.parts;
+        for part in [(1, run1), (2, run2)]
+            .into_iter()
+            .filter_map(|(p, run)| run.then_some(p))
+        {
+            let read = self.read.take();
+            self.send(Outcome {
+                part,
+                read,
+                status,
+                error: Some(message.clone()),
+                ..Default::default()
+            });
+        }
+    }
+
+    fn send(&mut self, outcome: Outcome) {
+        self.parts[outcome.part as usize - 1] = false;
+        // The runner may have given up on this day already.
+        let _ = self.outcomes.send(outcome);
+    }
+}
+
+fn time<T, R>(
+    bench: Option<Bench>,
+    fresh: impl Fn() -> T,
+    part: impl Fn(T) -> R,
+) -> (Result<R, String>, Duration, Option<Stats>) {
+    match bench {
+        Some(bench) => match measure(|| catch(|| bench.run(fresh, part))) {
+            (Ok((answer, stats)), _) => (Ok(answer), stats.median, Some(stats)),
+            (Err(message), elapsed) => (Err(message), elapsed, None),
+        },
+        None => {
+            let data = fresh();
+            let (answer, solve) = measure(|| catch(|| part(data)));
+            (answer, solve, None)
+        }
+    }
+}
+
+/// Picks `Clone::clone` for the parsed type when it is available. Use as
+/// `(&&Cloner::of(&parse)).cloner()` with `ViaClone` and `ViaReparse` in scope.
+pub struct Cloner<T>(PhantomData<T>);
+
+impl<T> Cloner<T> {
+    pub fn of<F: Fn() -> T>(_parse: &F) -> Self {
+        Self(PhantomData)
+    }
+}
+
+pub trait ViaClone<T> {
+    fn cloner(&self) -> Option<fn(&T) -> T>;
+}
+
+impl<T: Clone> ViaClone<T> for &Cloner<T> {
+    fn cloner(&self) -> Option<fn(&T) -> T> {
+        Some(T::clone)
+    }
+}
+
+pub trait ViaReparse<T> {
+    fn cloner(&self) -> Option<fn(&T) -> T>;
+}
+
+impl<T> ViaReparse<T> for Cloner<T> {
+    fn cloner(&self) -> Option<fn(&T) -> T> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    struct Opaque(usize);
+
+    fn run<T>(
+        parts: [bool; 2],
+        parse: impl Fn() -> T,
+        cloner: Option<fn(&T) -> T>,
+        part: fn(T) -> usize,
+    ) -> Vec<Outcome> {
+        let (tx, rx) = mpsc::channel();
+        let mut ctx = Ctx::new(1, parts, None, false, None, None, tx);
+        ctx.read(|_| Ok(String::new()));
+        ctx.solve(parse, cloner, part, part);
+        drop(ctx);
+        rx.iter().collect()
+    }
+
+    #[test]
+    #[allow(clippy::needless_borrow)]
+    fn picks_clone_when_available() {
+        assert!((&&Cloner::of(&|| vec![1])).cloner().is_some());
+        assert!((&&Cloner::of(&|| Opaque(1))).cloner().is_none());
+    }
+
+    #[test]
+    fn parses_once_when_clone() {
+        let parses = Cell::new(0);
+        let parse = || {
+            parses.set(parses.get() + 1);
+            vec![1, 2, 3]
+        };
+        let outcomes = run([true, true], parse, Some(Vec::clone), |v| v.len());
+
+        assert_eq!(parses.get(), 1);
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes[0].read.is_some() && outcomes[0].parse.is_some());
+        assert!(outcomes[1].read.is_none() && outcomes[1].parse.is_none());
+        assert!(!outcomes[1].reparsed);
+    }
+
+    #[test]
+    fn reparses_when_not_clone() {
+        let parses = Cell::new(0);
+        let parse = || {
+            parses.set(parses.get() + 1);
+            Opaque(7)
+        };
+        let outcomes = run([true, true], parse, None, |o| o.0);
+
+        assert_eq!(parses.get(), 2);
+        assert_eq!(outcomes[1].answer, "7");
+        assert!(outcomes[1].parse.is_some());
+        assert!(outcomes[1].reparsed);
+    }
+
+    #[test]
+    fn single_part_never_reparses() {
+        let parses = Cell::new(0);
+        let parse = || {
+            parses.set(parses.get() + 1);
+            Opaque(7)
+        };
+        let outcomes = run([false, true], parse, None, |o| o.0);
+
+        assert_eq!(parses.get(), 1);
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].part, 2);
+        assert!(!outcomes[0].reparsed);
+        assert!(outcomes[0].read.is_some());
+    }
+
+    #[test]
+    fn part_panic_keeps_other_part() {
+        let (tx, rx) = mpsc::channel();
+        let mut ctx = Ctx::new(1, [true, true], None, false, None, None, tx);
+        ctx.solve(
+            || Opaque(7),
+            None,
+            |_| -> usize { unimplemented!() },
+            |o| o.0,
+        );
+        drop(ctx);
+        let outcomes = rx.iter().collect::<Vec<_>>();
+
+        assert_eq!(outcomes[0].status, Status::Panic);
+        assert_eq!(outcomes[0].error.as_deref(), Some("not implemented"));
+        assert_eq!(outcomes[1].status, Status::Ok);
+        assert_eq!(outcomes[1].answer, "7");
+    }
+
+    #[test]
+    fn counts_allocations_with_alloc() {
+        assert!(alloc::enable());
+        let (tx, rx) = mpsc::channel();
+        let mut ctx = Ctx::new(1, [true, true], None, true, None, None, tx);
+        ctx.solve(
+            || vec![0u8; 10],
+            Some(Vec::clone),
+            |v| v.len(),
+            |v| v.repeat(3).len(),
+        );
+        drop(ctx);
+        let outcomes = rx.iter().collect::<Vec<_>>();
+
+        let parsed = outcomes[0].parse_allocs.unwrap();
+        assert_eq!((parsed.count, parsed.bytes, parsed.peak), (1, 10, 10));
+        assert_eq!(outcomes[0].allocs.unwrap().count, 0);
+        assert_eq!(outcomes[1].parse_allocs, None);
+        assert_eq!(outcomes[1].allocs.unwrap().bytes, 30);
+    }
+
+    #[test]
+    fn answers_from_the_cache() {
+        let path = std::env::temp_dir().join(format!("runner-day-cache-{}", std::process::id()));
+        let cache = Arc::new(Cache::load(path.clone()));
+        let solves = Cell::new(0);
+        let run = || {
+            let (tx, rx) = mpsc::channel();
+            let mut ctx = Ctx::new(1, [true, true], None, false, None, None, tx);
+            ctx.cache = Some(cache.clone());
+            ctx.code = Some(0xc0de);
+            if let Some(input) = ctx.read(|_| Ok("7".to_string())) {
+                ctx.solve(
+                    || input.parse::<usize>().unwrap(),
+                    Some(usize::clone),
+                    |n| {
+                        solves.set(solves.get() + 1);
+                        n + 1
+                    },
+                    |_| -> usize { unimplemented!() },
+                );
+            }
+            drop(ctx);
+            rx.iter().collect::<Vec<_>>()
+        };
+
+        let outcomes = run();
+        assert_eq!(outcomes[0].answer, "8");
+        assert!(!outcomes[0].cached);
+        // Failures are solved again.
+        let outcomes = run();
+        assert_eq!(solves.get(), 1);
+        assert_eq!(outcomes[0].answer, "8");
+        assert!(outcomes[0].cached && outcomes[0].read.is_some());
+        assert_eq!(outcomes[1].status, Status::Panic);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    fn args(timeout: Option<Duration>) -> Args {
+        Args {
+            timeout,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn panic_outside_parts_fails_remaining() {
+        let day = Day::new("day03", |ctx| {
+            ctx.read(|_| panic!("It's not time yet"));
+        });
+        let outcomes = day.run(&args(None), None, None, None);
+
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes.iter().all(|o| o.status == Status::Panic));
+        assert_eq!(outcomes[1].error.as_deref(), Some("It's not time yet"));
+    }
+
+    #[test]
+    fn input_errors_skip_or_fail_the_day() {
+        let unreleased = Day::new("day12", |ctx| {
+            let at = chrono::DateTime::from_timestamp(0, 0).unwrap();
+            if ctx.read(|_| Err(InputError::NotYetReleased(at))).is_some() {
+                unreachable!();
+            }
+        });
+        let outcomes = unreleased.run(&args(None), None, None, None);
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes.iter().all(|o| o.status == Status::Unreleased));
+
+        let expired = Day::new("day05", |ctx| {
+            ctx.read(|_| Err(InputError::Unauthorized));
+        });
+        let outcomes = expired.run(&args(None), None, None, None);
+        assert!(outcomes.iter().all(|o| o.status == Status::NoInput));
+        assert!(
+            outcomes[0]
+                .error
+                .as_ref()
+                .unwrap()
+                .contains("session cookie")
+        );
+        assert!(outcomes[0].read.is_some() && outcomes[1].read.is_none());
+    }
+
+    #[test]
+    fn timeout_moves_on_to_next_part() {
+        let day = Day::new("day04", |ctx| {
+            ctx.solve(
+                || (),
+                Some(<()>::clone),
+                |_| {
+                    thread::sleep(Duration::from_secs(60));
+                    1
+                },
+                |_| 2,
+            );
+        });
+        let outcomes = day.run(&args(Some(Duration::from_millis(50))), None, None, None);
+
+        assert_eq!(outcomes.len(), 2);
+        assert_eq!(outcomes[0].status, Status::Timeout);
+        assert_eq!(outcomes[1].status, Status::Ok);
+        assert_eq!(outcomes[1].answer, "2");
+    }
+}
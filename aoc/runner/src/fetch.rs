@@ -0,0 +1,240 @@
+use crate::InputError;
+use std::io;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+use ureq::http::Response;
+use ureq::{Agent, Body};
+
+/// Identifies the tool and where it lives to adventofcode.com, as its owner asks
+/// automated tools to. Set `AOC_CONTACT` to add a way to reach you.
+const USER_AGENT: &str = concat!(
+    "aoc-runner/",
+    env!("CARGO_PKG_VERSION"),
+    " (+",
+    env!("AOC_REPOSITORY"),
+    ")"
+);
+
+/// The least time between two requests from one process.
+const SPACING: Duration = Duration::from_secs(3);
+
+/// When the last request of this process went out.
+static LAST_REQUEST: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// A client for adventofcode.com that introduces itself, spaces out its requests and
+/// recognises the pages it gets instead of the one it asked for.
+pub struct Client {
+    agent: Agent,
+    base: String,
+    spacing: Duration,
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Self::with_base("https://adventofcode.com")
+    }
+}
+
+impl Client {
+    /// A client for another server, e.g. a stand-in for tests.
+    pub fn with_base(base: &str) -> Self {
+        let user_agent = match std::env::var("AOC_CONTACT") {
+            Ok(contact) => format!("{} {}", USER_AGENT, contact),
+            Err(_) => USER_AGENT.to_string(),
+        };
+        let agent = Agent::config_builder()
+            .user_agent(user_agent)
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        Self {
+            agent,
+            base: base.trim_end_matches('/').to_string(),
+            spacing: SPACING,
+        }
+    }
+
+    pub fn with_spacing(mut self, spacing: Duration) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Downloads a day's input with the session cookie.
+    pub fn input(&self, year: i32, day: u32, session: &str) -> Result<String, InputError> {
+        let body = self.get(&format!("/{}/day/{}/input", year, day), session)?;
+        // Inputs are plain text, an HTML page is the login page of an expired session.
+        if is_html(&body) {
+            return Err(InputError::Unauthorized);
+        }
+        Ok(body)
+    }
+
+    /// GETs a page, e.g. `/2024/day/5`, with the session cookie.
+    pub fn get(&self, path: &str, session: &str) -> Result<String, InputError> {
+        self.wait();
+        let response = self
+            .agent
+            .get(format!("{}{}", self.base, path))
+            .header("Cookie", format!("session={}", session.trim()))
+            .call();
+        read(response)
+    }
+
+    /// POSTs a form, e.g. an answer, with the session cookie.
+    pub fn post(
+        &self,
+        path: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<String, InputError> {
+        self.wait();
+        let response = self
+            .agent
+            .post(format!("{}{}", self.base, path))
+            .header("Cookie", format!("session={}", session.trim()))
+            .send_form(form.iter().copied());
+        read(response)
+    }
+
+    /// Sleeps until `spacing` has passed since the previous request of any client.
+    fn wait(&self) {
+        let mut last = LAST_REQUEST.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(last) = *last {
+            thread::sleep(self.spacing.saturating_sub(last.elapsed()));
+        }
+        *last = Some(Instant::now());
+    }
+}
+
+fn read(response: Result<Response<Body>, ureq::Error>) -> Result<String, InputError> {
+    let response = response.map_err(http_error)?;
+    let status = response.status().as_u16();
+    let body = response.into_body().read_to_string().map_err(http_error)?;
+    check(status, body)
+}
+
+fn check(status: u16, body: String) -> Result<String, InputError> {
+    if body.contains("Please don't repeatedly request this endpoint before it unlocks") {
+        return Err(InputError::TooEarly);
+    }
+    match status {
+        200 if body.contains("Please log in") => Err(InputError::Unauthorized),
+        200 => Ok(body),
+        // adventofcode.com answers 400 when the session cookie has expired.
+        400 | 401 | 403 => Err(InputError::Unauthorized),
+        code => Err(InputError::HttpStatus(code)),
+    }
+}
+
+/// Whether `body` is an HTML document. Inputs can start with `<` too, e.g. 2021 day 10.
+fn is_html(body: &str) -> bool {
+    let start = body.trim_start().chars().take(9).collect::<String>();
+    let start = start.to_ascii_lowercase();
+    start.starts_with("<!doctype") || start.starts_with("<html")
+}
+
+fn http_error(e: ureq::Error) -> InputError {
+    match e {
+        ureq::Error::Io(e) => InputError::Io(e),
+        e => InputError::Io(io::Error::other(e)),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+
+    /// A stand-in for adventofcode.com that answers each request with the next of
+    /// `responses` and hands over the request heads it got.
+    pub fn serve(responses: &[(u16, &str)]) -> (String, Receiver<String>) {
+        let responses = responses
+            .iter()
+            .map(|&(status, body)| (status, body.to_string()))
+            .collect::<Vec<_>>();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut head = String::new();
+                let mut reader = BufReader::new(&stream);
+                while reader.read_line(&mut head).unwrap() > 2 {}
+                tx.send(head).unwrap();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        (base, rx)
+    }
+
+    #[test]
+    fn introduces_itself_and_spaces_requests() {
+        let (base, requests) = serve(&[(200, "1 2 3\n"), (200, "4 5 6\n")]);
+        let client = Client::with_base(&base).with_spacing(Duration::from_millis(200));
+
+        let start = Instant::now();
+        assert_eq!(client.input(2024, 5, "abc\n").unwrap(), "1 2 3\n");
+        assert_eq!(client.input(2024, 6, "abc").unwrap(), "4 5 6\n");
+        assert!(start.elapsed() >= Duration::from_millis(200));
+
+        let head = requests.recv().unwrap().to_lowercase();
+        assert!(
+            head.starts_with("get /2024/day/5/input http/1.1"),
+            "{}",
+            head
+        );
+        assert!(head.contains("cookie: session=abc\r\n"), "{}", head);
+        assert!(head.contains("user-agent: aoc-runner/"), "{}", head);
+        assert!(
+            head.contains(&env!("AOC_REPOSITORY").to_lowercase()),
+            "{}",
+            head
+        );
+    }
+
+    #[test]
+    fn recognises_what_it_got_instead() {
+        let (base, _requests) = serve(&[
+            (200, "<!DOCTYPE html>\n<html><body>[Log In]</body></html>"),
+            (200, "\n<HTML><body>[Log In]</body></HTML>"),
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+            ),
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n",
+            ),
+            (500, "Internal Server Error"),
+        ]);
+        let client = Client::with_base(&base).with_spacing(Duration::ZERO);
+
+        let input = || client.input(2024, 1, "abc");
+        assert!(matches!(input(), Err(InputError::Unauthorized)));
+        assert!(matches!(input(), Err(InputError::Unauthorized)));
+        assert!(matches!(input(), Err(InputError::Unauthorized)));
+        assert!(matches!(input(), Err(InputError::TooEarly)));
+        assert!(matches!(input(), Err(InputError::HttpStatus(500))));
+    }
+
+    #[test]
+    fn takes_inputs_that_start_with_a_bracket() {
+        let navigation = "<{([{{}}[<[[[<>{}]]]>[]]\n[(()[<>])]({[<{<<[]>>(\n";
+        let (base, _requests) = serve(&[(200, navigation)]);
+        let client = Client::with_base(&base).with_spacing(Duration::ZERO);
+
+        assert_eq!(client.input(2021, 10, "abc").unwrap(), navigation);
+    }
+}
@@ -0,0 +1,303 @@
+use crate::{Record, Status};
+use chrono::{DateTime, Utc};
+use num_format::{Locale, ToFormattedString};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+/// Changes smaller than this are treated as noise regardless of the threshold.
+const MIN_DELTA: Duration = Duration::from_micros(50);
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub commit: String,
+    pub dirty: bool,
+    pub timestamp: DateTime<Utc>,
+    pub label: Option<String>,
+    pub release: bool,
+    pub bench: bool,
+    pub samples: Vec<Sample>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sample {
+    pub day: u32,
+    pub part: u32,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: u64,
+}
+
+impl Entry {
+    pub fn new(root: &Path, label: Option<String>, bench: bool, records: &[Record]) -> Self {
+        let (commit, dirty) = git_state(root);
+        Self {
+            commit,
+            dirty,
+            timestamp: Utc::now(),
+            label,
+            release: !cfg!(debug_assertions),
+            bench,
+            samples: records
+                .iter()
+                // Cached answers weren't timed.
+                .filter(|r| r.status == Status::Ok && !r.cached)
+                .map(|r| Sample {
+                    day: r.day,
+                    part: r.part,
+                    parse_ns: r.parse.map(|d| d.as_nanos() as u64),
+                    solve_ns: r.solve.as_nanos() as u64,
+                })
+                .collect(),
+        }
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "{}{} ({})",
+            self.commit,
+            if self.dirty { "+dirty" } else { "" },
+            self.timestamp.format("%Y-%m-%d %H:%M:%S")
+        )
+    }
+}
+
+fn git_state(root: &Path) -> (String, bool) {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .arg("-C")
+            .arg(root)
+            .args(args)
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(commit) => {
+            let dirty = git(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty());
+            (commit, dirty)
+        }
+        None => ("unknown".to_string(), false),
+    }
+}
+
+pub struct History {
+    path: PathBuf,
+}
+
+impl History {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Lines that fail to parse are skipped so a damaged file never blocks a run.
+    pub fn load(&self) -> Vec<Entry> {
+        fs::read_to_string(&self.path)
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect()
+    }
+
+    pub fn append(&self, entry: &Entry) -> std::io::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(entry)?)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change {
+    pub day: u32,
+    pub part: u32,
+    pub before: Duration,
+    pub after: Duration,
+    pub against: String,
+}
+
+impl Change {
+    pub fn ratio(&self) -> f64 {
+        self.after.as_nanos() as f64 / self.before.as_nanos().max(1) as f64
+    }
+}
+
+/// Compares each part of `current` with its most recent earlier measurement taken in the
+/// same build profile and mode, optionally only among runs labelled `baseline`. Returns
+/// the parts that got slower by more than `threshold` (0.1 is 10%) and how many parts
+/// had something to compare with.
+pub fn compare(
+    history: &[Entry],
+    current: &Entry,
+    baseline: Option<&str>,
+    threshold: f64,
+) -> (Vec<Change>, usize) {
+    let candidates = history
+        .iter()
+        .rev()
+        .filter(|e| e.release == current.release && e.bench == current.bench)
+        .filter(|e| baseline.is_none_or(|b| e.label.as_deref() == Some(b)))
+        .collect::<Vec<_>>();
+
+    let mut compared = 0;
+    let mut slower = Vec::new();
+
+    for sample in &current.samples {
+        let previous = candidates.iter().find_map(|e| {
+            e.samples
+                .iter()
+                .find(|s| s.day == sample.day && s.part == sample.part)
+                .map(|s| (e, s))
+        });
+
+        if let Some((entry, before)) = previous {
+            compared += 1;
+            let change = Change {
+                day: sample.day,
+                part: sample.part,
+                before: Duration::from_nanos(before.solve_ns),
+                after: Duration::from_nanos(sample.solve_ns),
+                against: entry.describe(),
+            };
+            if change.ratio() > 1.0 + threshold
+                && change.after.saturating_sub(change.before) >= MIN_DELTA
+            {
+                slower.push(change);
+            }
+        }
+    }
+
+    (slower, compared)
+}
+
+pub fn print_comparison(
+    slower: &[Change],
+    compared: usize,
+    threshold: u32,
+    baseline: Option<&str>,
+) {
+    let against = match baseline {
+        Some(name) => format!("baseline {}", name),
+        None => "earlier runs".to_string(),
+    };
+
+    if compared == 0 {
+        eprintln!("Nothing to compare, no matching {}", against);
+        return;
+    }
+
+    eprintln!(
+        "Compared {} parts with {}, {} slower by more than {}%",
+        compared,
+        against,
+        slower.len(),
+        threshold
+    );
+    for change in slower {
+        eprintln!(
+            "Day{:0>2}-{:0>2} {: >10}μs -> {: >10}μs {: >+8.1}%  vs {}",
+            change.day,
+            change.part,
+            change.before.as_micros().to_formatted_string(&Locale::en),
+            change.after.as_micros().to_formatted_string(&Locale::en),
+            (change.ratio() - 1.0) * 100.0,
+            change.against
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(label: Option<&str>, release: bool, samples: &[(u32, u32, u64)]) -> Entry {
+        Entry {
+            commit: "abc1234".to_string(),
+            dirty: false,
+            timestamp: DateTime::from_timestamp(1_733_000_000, 0).unwrap(),
+            label: label.map(str::to_string),
+            release,
+            bench: false,
+            samples: samples
+                .iter()
+                .map(|&(day, part, solve_ns)| Sample {
+                    day,
+                    part,
+                    parse_ns: None,
+                    solve_ns,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn round_trips_through_file() {
+        let path = std::env::temp_dir().join(format!("runner-history-{}", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let history = History::new(path.clone());
+
+        let first = entry(Some("base"), true, &[(1, 1, 1000)]);
+        let second = entry(None, true, &[(1, 2, 2000)]);
+        history.append(&first).unwrap();
+        history.append(&second).unwrap();
+        fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"not json\n")
+            .unwrap();
+
+        assert_eq!(history.load(), vec![first, second]);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn flags_slower_parts_against_latest_measurement() {
+        let history = vec![
+            entry(None, true, &[(1, 1, 1_000_000), (1, 2, 1_000_000)]),
+            entry(None, true, &[(1, 1, 2_000_000)]),
+            entry(None, false, &[(1, 2, 9_000_000)]),
+        ];
+        let current = entry(
+            None,
+            true,
+            &[(1, 1, 2_100_000), (1, 2, 1_500_000), (2, 1, 5)],
+        );
+
+        let (slower, compared) = compare(&history, &current, None, 0.1);
+
+        assert_eq!(compared, 2);
+        assert_eq!(slower.len(), 1);
+        assert_eq!((slower[0].day, slower[0].part), (1, 2));
+        assert_eq!(slower[0].before, Duration::from_millis(1));
+    }
+
+    #[test]
+    fn compares_against_named_baseline() {
+        let history = vec![
+            entry(Some("base"), true, &[(3, 1, 1_000_000)]),
+            entry(None, true, &[(3, 1, 3_000_000)]),
+        ];
+        let current = entry(None, true, &[(3, 1, 2_000_000)]);
+
+        assert_eq!(compare(&history, &current, None, 0.1).0.len(), 0);
+        assert_eq!(compare(&history, &current, Some("base"), 0.1).0.len(), 1);
+        assert_eq!(
+            compare(&history, &current, Some("missing"), 0.1),
+            (vec![], 0)
+        );
+    }
+
+    #[test]
+    fn ignores_tiny_changes() {
+        let history = vec![entry(None, true, &[(1, 1, 1_000)])];
+        let current = entry(None, true, &[(1, 1, 10_000)]);
+
+        assert_eq!(compare(&history, &current, None, 0.1).0.len(), 0);
+    }
+}
@@ -0,0 +1,267 @@
+use crate::Client;
+use crate::release::{self, Clock};
+use chrono::{DateTime, Utc};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A year's puzzle inputs in `{year}/inputs`, downloaded on first use.
+pub struct Inputs {
+    year: i32,
+    dir: PathBuf,
+    cookie: PathBuf,
+    /// Why `AOC_NOW` can't be gone by, if it can't.
+    clock: Result<Box<dyn Clock>, String>,
+    client: Client,
+}
+
+impl Inputs {
+    /// The inputs next to the year's workspace. `AOC_NOW` stops the clock, see [`release::clock`].
+    pub fn new(year: i32) -> Self {
+        Self::with_root(
+            year,
+            Path::new(env!("CARGO_WORKSPACE_DIR")).join(year.to_string()),
+        )
+    }
+
+    /// Inputs in `root/inputs`, with the session cookie in `root/cookie`.
+    pub fn with_root(year: i32, root: impl AsRef<Path>) -> Self {
+        let root = root.as_ref();
+        Self {
+            year,
+            dir: root.join("inputs"),
+            cookie: root.join("cookie"),
+            clock: release::clock(),
+            client: Client::default(),
+        }
+    }
+
+    /// A cookie file ending in `.json` is a saved cookie store with the session cookie in
+    /// it, any other holds just the session cookie's value.
+    pub fn with_cookie(mut self, cookie: impl Into<PathBuf>) -> Self {
+        self.cookie = cookie.into();
+        self
+    }
+
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Ok(Box::new(clock));
+        self
+    }
+
+    pub fn with_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    pub fn path(&self, day: u32) -> PathBuf {
+        self.dir.join(format!("day{:0>2}", day))
+    }
+
+    /// Reads the day's input, downloading it first when it isn't cached yet.
+    pub fn read(&self, day: u32) -> Result<String, InputError> {
+        let clock = self
+            .clock
+            .as_ref()
+            .map_err(|e| InputError::Clock(e.clone()))?;
+        match release::unlocks(self.year, day) {
+            None => return Err(InputError::NoSuchDay(day)),
+            Some(at) if at > clock.now() => return Err(InputError::NotYetReleased(at)),
+            Some(_) => {}
+        }
+
+        let file = self.path(day);
+        if !file.exists() {
+            let body = self.client.input(self.year, day, &self.session()?)?;
+            fs::create_dir_all(&self.dir)?;
+            write_atomic(&file, &body)?;
+        }
+
+        Ok(fs::read_to_string(&file)?)
+    }
+
+    pub fn session(&self) -> Result<String, InputError> {
+        let cookie = fs::read_to_string(&self.cookie).map_err(|e| match e.kind() {
+            io::ErrorKind::NotFound => InputError::MissingCookie(self.cookie.clone()),
+            _ => e.into(),
+        })?;
+        if self.cookie.extension().is_some_and(|e| e == "json") {
+            session_from_store(&cookie).ok_or_else(|| {
+                let e = format!("No session cookie in {}", self.cookie.display());
+                io::Error::new(io::ErrorKind::InvalidData, e).into()
+            })
+        } else {
+            Ok(cookie.trim().to_string())
+        }
+    }
+}
+
+/// Finds the session in a cookie store saved as JSON, one cookie per line like
+/// `{"raw_cookie":"session=53616c74; Domain=adventofcode.com", ...}`.
+fn session_from_store(json: &str) -> Option<String> {
+    json.lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter_map(|cookie| cookie["raw_cookie"].as_str().map(str::to_string))
+        .find_map(|raw| {
+            let (name, value) = raw.split(';').next()?.split_once('=')?;
+            (name.trim() == "session").then(|| value.trim().to_string())
+        })
+}
+
+/// Writes next to `path` first, so a failed write never leaves half an input behind.
+pub(crate) fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    fs::write(&tmp, contents)?;
+    fs::rename(&tmp, path)
+}
+
+/// Why a day's input could not be read.
+#[derive(Debug)]
+pub enum InputError {
+    /// The puzzle unlocks at the given time.
+    NotYetReleased(DateTime<Utc>),
+    /// The day isn't part of the year's event.
+    NoSuchDay(u32),
+    /// The input isn't downloaded yet and there is no session cookie at this path to do so.
+    MissingCookie(PathBuf),
+    /// The session cookie was rejected, it has most likely expired.
+    Unauthorized,
+    /// adventofcode.com says the puzzle isn't out yet, though the clock says it is.
+    TooEarly,
+    HttpStatus(u16),
+    Io(io::Error),
+    /// `AOC_NOW` is set to something that isn't a time.
+    Clock(String),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::NotYetReleased(at) => {
+                write!(
+                    f,
+                    "Not released yet, unlocks at {}",
+                    at.format("%Y-%m-%d %H:%M UTC")
+                )
+            }
+            InputError::NoSuchDay(day) => write!(f, "There is no day {} this year", day),
+            InputError::MissingCookie(path) => write!(
+                f,
+                "No session cookie at {}, copy the session cookie from adventofcode.com into it",
+                path.display()
+            ),
+            InputError::Unauthorized => write!(
+                f,
+                "The session cookie was rejected, log in again and replace it with the new one"
+            ),
+            InputError::TooEarly => write!(
+                f,
+                "adventofcode.com says the puzzle hasn't unlocked yet, is the clock right?"
+            ),
+            InputError::HttpStatus(code) => {
+                write!(f, "adventofcode.com answered with HTTP {}", code)
+            }
+            InputError::Io(e) => write!(f, "{}", e),
+            InputError::Clock(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for InputError {
+    fn from(e: io::Error) -> Self {
+        InputError::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::tests::serve;
+    use crate::release::FixedClock;
+    use std::time::Duration;
+
+    fn inputs(name: &str, now: &str, base: &str) -> Inputs {
+        let root = std::env::temp_dir().join(format!("runner-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        let now = DateTime::parse_from_rfc3339(now).unwrap().to_utc();
+        Inputs::with_root(2025, root)
+            .with_clock(FixedClock(now))
+            .with_client(Client::with_base(base).with_spacing(Duration::ZERO))
+    }
+
+    #[test]
+    fn not_released_yet() {
+        let inputs = inputs("unreleased", "2025-12-03T04:59:59Z", "http://127.0.0.1:9");
+        match inputs.read(3) {
+            Err(InputError::NotYetReleased(at)) => {
+                assert_eq!(at.to_rfc3339(), "2025-12-03T05:00:00+00:00")
+            }
+            other => panic!("{:?}", other),
+        }
+        assert!(matches!(inputs.read(13), Err(InputError::NoSuchDay(13))));
+    }
+
+    #[test]
+    fn downloads_once() {
+        let (base, requests) = serve(&[(200, "1 2 3\n"), (400, "Please log in")]);
+        let inputs = inputs("download", "2025-12-03T05:00:00Z", &base);
+        assert!(matches!(inputs.read(3), Err(InputError::MissingCookie(_))));
+
+        fs::write(&inputs.cookie, "abc\n").unwrap();
+        assert_eq!(inputs.read(3).unwrap(), "1 2 3\n");
+        assert!(
+            requests
+                .recv()
+                .unwrap()
+                .starts_with("GET /2025/day/3/input")
+        );
+        assert_eq!(fs::read_to_string(inputs.path(3)).unwrap(), "1 2 3\n");
+        assert_eq!(inputs.read(3).unwrap(), "1 2 3\n");
+
+        assert!(matches!(inputs.read(2), Err(InputError::Unauthorized)));
+        assert!(
+            requests
+                .recv()
+                .unwrap()
+                .starts_with("GET /2025/day/2/input")
+        );
+        assert_eq!(fs::read_dir(&inputs.dir).unwrap().count(), 1);
+        fs::remove_dir_all(inputs.dir.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn reads_the_session_from_a_cookie_store() {
+        let store = "{\"raw_cookie\":\"_ga=GA1; Domain=adventofcode.com\",\"path\":[\"/\",true]}\n\
+                     {\"raw_cookie\":\"session=53616c74; Domain=adventofcode.com; Secure\",\"path\":[\"/\",true]}\n";
+        assert_eq!(session_from_store(store).as_deref(), Some("53616c74"));
+        assert_eq!(session_from_store("{}"), None);
+    }
+
+    #[test]
+    fn messages() {
+        let at = DateTime::from_timestamp(1_764_910_800, 0).unwrap();
+        assert_eq!(
+            InputError::NotYetReleased(at).to_string(),
+            "Not released yet, unlocks at 2025-12-05 05:00 UTC"
+        );
+        assert_eq!(
+            InputError::MissingCookie(PathBuf::from("2025/cookie")).to_string(),
+            "No session cookie at 2025/cookie, copy the session cookie from adventofcode.com into it"
+        );
+        assert_eq!(
+            InputError::HttpStatus(500).to_string(),
+            "adventofcode.com answered with HTTP 500"
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static MESSAGE: Cell<Option<String>> = const { Cell::new(None) };
+}
+
+/// Keeps panics inside `catch` off stderr and remembers their location for the report.
+/// Panics anywhere else still go to the previous hook.
+pub(crate) fn install_hook() {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if CATCHING.get() {
+            let message = match info.location() {
+                Some(at) => format!("{} ({})", message(info.payload()), at),
+                None => message(info.payload()),
+            };
+            MESSAGE.set(Some(message));
+        } else {
+            previous(info);
+        }
+    }));
+}
+
+pub(crate) fn catch<R>(func: impl FnOnce() -> R) -> Result<R, String> {
+    let catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    CATCHING.set(catching);
+    result.map_err(|payload| MESSAGE.take().unwrap_or_else(|| message(&*payload)))
+}
+
+fn message(payload: &(dyn Any + Send)) -> String {
+    match payload.downcast_ref::<&str>() {
+        Some(s) => s.to_string(),
+        None => match payload.downcast_ref::<String>() {
+            Some(s) => s.clone(),
+            None => "Box<dyn Any>".to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catches_panic_message() {
+        assert_eq!(catch(|| 42), Ok(42));
+        assert_eq!(catch(|| -> u32 { panic!("boom") }), Err("boom".to_string()));
+        let day = 7;
+        assert_eq!(
+            catch(|| -> u32 { panic!("day {} failed", day) }),
+            Err("day 7 failed".to_string())
+        );
+    }
+}
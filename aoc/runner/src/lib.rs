@@ -0,0 +1,210 @@
+mod account;
+mod alloc;
+mod answers;
+mod args;
+mod bench;
+mod cache;
+mod day;
+mod fetch;
+mod history;
+mod input;
+mod isolate;
+mod parallel;
+mod profile;
+mod puzzle;
+pub mod release;
+mod report;
+mod selection;
+mod solution;
+mod source;
+mod submit;
+
+pub use account::Account;
+pub use alloc::{Allocs, Counting};
+pub use answers::{Answers, Check};
+pub use args::{Args, Compare, OPTIONS};
+pub use bench::{Bench, Stats};
+pub use cache::Cache;
+pub use day::{Cloner, Ctx, Day, Outcome, Status, ViaClone, ViaReparse};
+pub use fetch::Client;
+pub use history::{Entry, History, Sample};
+pub use input::{InputError, Inputs};
+pub use puzzle::{Example, Puzzle, Puzzles, examples, fill_tests, markdown, placeholder};
+pub use release::{Clock, FixedClock, SystemClock};
+pub use report::{Format, Record, Report};
+pub use selection::Selection;
+pub use solution::Solution;
+pub use source::{Input, InputSource, Source};
+pub use submit::{Submission, Submissions, Verdict, article, refusal};
+
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+pub fn main(year: i32, days: &[Day]) {
+    let available = days.iter().map(|d| d.day).collect::<Vec<_>>();
+    let args = Args::from_env(&available);
+    if args.list {
+        for day in &available {
+            println!("{}", day);
+        }
+        return;
+    }
+    isolate::install_hook();
+    if let Some(jobs) = args.jobs
+        && std::env::var_os("RAYON_NUM_THREADS").is_none()
+    {
+        // Rayon's pool is shared by the days running at once, so it gets their share.
+        let cores = std::thread::available_parallelism().map_or(1, |n| n.get());
+        // SAFETY: No other threads were started yet.
+        unsafe { std::env::set_var("RAYON_NUM_THREADS", (cores / jobs).max(1).to_string()) };
+    }
+    // Said once here rather than by every day that reads its input.
+    if let Err(e) = release::clock() {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    }
+    if args.alloc && !alloc::enable() {
+        eprintln!("--alloc needs runner::Counting as the global allocator");
+        std::process::exit(2);
+    }
+
+    let root = Path::new(env!("CARGO_WORKSPACE_DIR")).join(year.to_string());
+    let accounts = match &args.account {
+        Some(name) => vec![Account::named(&root, name)],
+        None if args.all_accounts => Account::all(&root),
+        None => vec![Account::default(&root)],
+    };
+    let mut answers = accounts
+        .iter()
+        .map(|account| {
+            account.answers().unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                std::process::exit(2);
+            })
+        })
+        .collect::<Vec<_>>();
+    let mut report = Report::new(year, &args, answers[0].clone());
+    let input = args.input.as_ref().map(|input| input.source(&root));
+    // Measuring takes solving, and so does comparing against an earlier measurement.
+    let measuring = args.bench.is_some() || args.alloc || args.profile || args.compare.is_some();
+    let cache =
+        (!args.fresh && !measuring).then(|| Arc::new(Cache::load(root.join("cache.jsonl"))));
+
+    for (account, answers) in accounts.iter().zip(&answers) {
+        if args.account.is_some() || args.all_accounts {
+            report.account(account, answers.clone());
+        }
+        let source = input.clone().or_else(|| account.source(year));
+        let selected = days
+            .iter()
+            .filter(|d| args.selection.contains_day(d.day))
+            .filter(|d| {
+                // Other accounts only have some of the days when running them all.
+                let stored = account.inputs().join(format!("day{:0>2}", d.day));
+                !(args.all_accounts && account.name.is_some() && !stored.exists())
+            })
+            .collect::<Vec<_>>();
+        let run = |day: &&Day| {
+            // Next to the day's crate, e.g. 2024/rust/day16/profile.
+            let profile = args.profile.then(|| {
+                root.join("rust")
+                    .join(format!("day{:0>2}", day.day))
+                    .join("profile")
+            });
+            day.run(&args, source.as_ref(), profile.as_deref(), cache.as_ref())
+        };
+        let mut done = |day: &&Day, outcomes: Vec<Outcome>| {
+            for outcome in outcomes {
+                report.record(day.day, outcome);
+            }
+        };
+        match args.jobs {
+            Some(jobs) => parallel::in_order(&selected, jobs, run, done),
+            None => selected.iter().for_each(|day| done(day, run(day))),
+        }
+    }
+
+    let records = report.finish();
+    if records.is_empty() {
+        return;
+    }
+
+    let history = History::new(root.join("history.jsonl"));
+    let entry = Entry::new(&root, args.label.clone(), args.bench.is_some(), &records);
+    let failed = records.iter().filter(|r| r.failed()).collect::<Vec<_>>();
+    let mut regressed = false;
+
+    if let Some(compare) = &args.compare {
+        let (slower, compared) = history::compare(
+            &history.load(),
+            &entry,
+            compare.baseline.as_deref(),
+            compare.threshold as f64 / 100.0,
+        );
+        history::print_comparison(
+            &slower,
+            compared,
+            compare.threshold,
+            compare.baseline.as_deref(),
+        );
+        regressed = !slower.is_empty();
+    }
+
+    // Timings on another input, while counting allocations or sampling, or of days run
+    // at once aren't comparable with the history.
+    if !args.no_history
+        && !args.alloc
+        && !args.profile
+        && args.jobs.is_none()
+        && args.input.is_none()
+        && args.account.is_none()
+        && !args.all_accounts
+        && let Err(e) = history.append(&entry)
+    {
+        eprintln!("Failed to record timing history: {}", e);
+    }
+
+    if args.record {
+        for (account, answers) in accounts.iter().zip(&mut answers) {
+            let new = records
+                .iter()
+                .filter(|r| r.account == account.name && r.check == Some(Check::Unknown))
+                .filter(|r| answers.insert(r.day, r.part, &r.answer))
+                .count();
+            let path = account.answers_path();
+            if new > 0 {
+                match answers.save(&path) {
+                    Ok(()) => eprintln!("Recorded {} answers in {}", new, path.display()),
+                    Err(e) => eprintln!("Failed to record answers: {}", e),
+                }
+            }
+        }
+    }
+
+    if !failed.is_empty() {
+        eprintln!("{} of {} parts failed:", failed.len(), records.len());
+        for record in &failed {
+            eprintln!(
+                "  Day{:0>2}-{:0>2} {}{}",
+                record.day,
+                record.part,
+                record
+                    .account
+                    .as_ref()
+                    .map_or(String::new(), |a| a.clone() + " "),
+                record.result()
+            );
+        }
+    }
+
+    if regressed || !failed.is_empty() {
+        std::process::exit(1);
+    }
+}
+
+pub(crate) fn measure<T, F: FnOnce() -> T>(func: F) -> (T, Duration) {
+    let start = Instant::now();
+    let res = func();
+    (res, start.elapsed())
+}
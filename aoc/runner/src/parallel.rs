@@ -0,0 +1,66 @@
+//! Running days at once for `--jobs`.
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// Runs `run` on each item on up to `jobs` threads, handing the results to `done` in the
+/// order of the items, each as soon as those before it are done.
+pub fn in_order<T, R>(
+    items: &[T],
+    jobs: usize,
+    run: impl Fn(&T) -> R + Sync,
+    mut done: impl FnMut(&T, R),
+) where
+    T: Sync,
+    R: Send,
+{
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let (next, run, tx) = (&next, &run, tx.clone());
+            scope.spawn(move || {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(index) else { break };
+                    tx.send((index, run(item))).unwrap();
+                }
+            });
+        }
+        drop(tx);
+
+        let mut finished = BTreeMap::new();
+        let mut reported = 0;
+        for (index, result) in rx {
+            finished.insert(index, result);
+            while let Some(result) = finished.remove(&reported) {
+                done(&items[reported], result);
+                reported += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn reports_in_order() {
+        let items = [30, 0, 20, 10, 0];
+        let mut reported = Vec::new();
+        in_order(
+            &items,
+            3,
+            |&ms| {
+                thread::sleep(Duration::from_millis(ms));
+                ms * 2
+            },
+            |&ms, doubled| reported.push((ms, doubled)),
+        );
+        assert_eq!(reported, [(30, 60), (0, 0), (20, 40), (10, 20), (0, 0)]);
+    }
+}
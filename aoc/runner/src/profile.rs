@@ -0,0 +1,121 @@
+//! Sampling profiles for `--profile`, written per part as folded stacks, one line per
+//! distinct stack as `thread;outermost;...;innermost count`, and as a flamegraph of them.
+
+use std::path::{Path, PathBuf};
+
+/// Samples per second of CPU time.
+#[cfg(unix)]
+const FREQUENCY: i32 = 1000;
+
+/// Samples every thread of the process from [`Profiler::start`] until it is written.
+pub struct Profiler {
+    #[cfg(unix)]
+    guard: pprof::ProfilerGuard<'static>,
+}
+
+impl Profiler {
+    #[cfg(unix)]
+    pub fn start() -> Result<Self, String> {
+        let guard = pprof::ProfilerGuardBuilder::default()
+            .frequency(FREQUENCY)
+            .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+            .build()
+            .map_err(|e| e.to_string())?;
+        Ok(Self { guard })
+    }
+
+    #[cfg(not(unix))]
+    pub fn start() -> Result<Self, String> {
+        Err("--profile is only supported on Unix".to_string())
+    }
+
+    /// Writes `{name}.folded` and `{name}.svg` to `dir`, returning the flamegraph's path.
+    #[cfg(unix)]
+    pub fn write(self, dir: &Path, name: &str, title: &str) -> Result<PathBuf, String> {
+        let report = self.guard.report().build().map_err(|e| e.to_string())?;
+        let stacks = folded(&report);
+        if stacks.is_empty() {
+            return Err(format!(
+                "no samples, it took less than the {}ms between them",
+                1000 / FREQUENCY
+            ));
+        }
+
+        std::fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+        let path = dir.join(format!("{}.folded", name));
+        std::fs::write(&path, stacks.join("\n") + "\n")
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        let path = dir.join(format!("{}.svg", name));
+        let svg = std::fs::File::create(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let mut options = pprof::flamegraph::Options::default();
+        options.title = title.to_string();
+        pprof::flamegraph::from_lines(&mut options, stacks.iter().map(String::as_str), svg)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+        Ok(path)
+    }
+
+    #[cfg(not(unix))]
+    pub fn write(self, _dir: &Path, _name: &str, _title: &str) -> Result<PathBuf, String> {
+        unreachable!()
+    }
+}
+
+/// The sampled stacks, outermost frame first, sorted.
+#[cfg(unix)]
+fn folded(report: &pprof::Report) -> Vec<String> {
+    let mut stacks = report
+        .data
+        .iter()
+        .map(|(frames, count)| {
+            let mut names = vec![frames.thread_name_or_id()];
+            // Inlined functions come innermost first within each frame.
+            names.extend(
+                frames
+                    .frames
+                    .iter()
+                    .rev()
+                    .flat_map(|frame| frame.iter().rev().map(|symbol| symbol.to_string())),
+            );
+            format!("{} {}", names.join(";"), count)
+        })
+        .collect::<Vec<_>>();
+    stacks.sort();
+    stacks
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[inline(never)]
+    fn spin(until: std::time::Instant) -> u64 {
+        let mut n = 0u64;
+        while std::time::Instant::now() < until {
+            n = std::hint::black_box(n.wrapping_mul(31).wrapping_add(7));
+        }
+        n
+    }
+
+    #[test]
+    fn writes_folded_stacks_and_a_flamegraph() {
+        let dir = std::env::temp_dir().join(format!("runner-profile-{}", std::process::id()));
+        let profiler = Profiler::start().unwrap();
+        spin(std::time::Instant::now() + std::time::Duration::from_millis(200));
+        let svg = profiler.write(&dir, "part1", "day01 part 1").unwrap();
+
+        assert_eq!(svg, dir.join("part1.svg"));
+        assert!(
+            std::fs::read_to_string(&svg)
+                .unwrap()
+                .contains("day01 part 1")
+        );
+        let folded = std::fs::read_to_string(dir.join("part1.folded")).unwrap();
+        assert!(
+            folded.lines().any(|line| line.contains("spin")),
+            "{}",
+            folded
+        );
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
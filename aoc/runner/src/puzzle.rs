@@ -0,0 +1,616 @@
+use crate::input::write_atomic;
+use crate::release::{self, Clock};
+use crate::{Client, InputError};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A year's puzzle descriptions as Markdown in `{year}/puzzles`, for reading offline, next
+/// to the HTML they came from, and their examples in `{year}/examples`.
+pub struct Puzzles {
+    year: i32,
+    root: PathBuf,
+    /// Why `AOC_NOW` can't be gone by, if it can't.
+    clock: Result<Box<dyn Clock>, String>,
+    client: Client,
+}
+
+impl Puzzles {
+    pub fn new(year: i32) -> Self {
+        Self::with_root(
+            year,
+            Path::new(env!("CARGO_WORKSPACE_DIR")).join(year.to_string()),
+        )
+    }
+
+    /// Puzzles in `root/puzzles` and examples in `root/examples`.
+    pub fn with_root(year: i32, root: impl AsRef<Path>) -> Self {
+        Self {
+            year,
+            root: root.as_ref().to_path_buf(),
+            clock: release::clock(),
+            client: Client::default(),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Ok(Box::new(clock));
+        self
+    }
+
+    pub fn with_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    pub fn path(&self, day: u32) -> PathBuf {
+        self.root.join("puzzles").join(format!("day{:0>2}.md", day))
+    }
+
+    /// Downloads the day's description unless the stored one already has part two, which
+    /// only shows up once part one is solved. Its examples are stored as
+    /// `examples/dayNN/N` unless they already are, and come back for the tests.
+    pub fn fetch(&self, day: u32, session: &str) -> Result<Puzzle, InputError> {
+        let clock = self
+            .clock
+            .as_ref()
+            .map_err(|e| InputError::Clock(e.clone()))?;
+        match release::unlocks(self.year, day) {
+            None => return Err(InputError::NoSuchDay(day)),
+            Some(at) if at > clock.now() => return Err(InputError::NotYetReleased(at)),
+            Some(_) => {}
+        }
+
+        let path = self.path(day);
+        let html = path.with_extension("html");
+        let stored = fs::read_to_string(&html).unwrap_or_default();
+        let page = if stored.contains(PART_TWO) {
+            stored
+        } else {
+            let page = self
+                .client
+                .get(&format!("/{}/day/{}", self.year, day), session)?;
+            // Only the description, the rest of the page is whose session it was.
+            let page = articles(&page).join("</article>\n") + "</article>\n";
+            let markdown = markdown(&page);
+            if markdown.is_empty() {
+                let e = format!("No puzzle description on the page of day {}", day);
+                return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, e).into());
+            }
+            fs::create_dir_all(path.parent().unwrap())?;
+            write_atomic(&html, &page)?;
+            write_atomic(&path, &markdown)?;
+            page
+        };
+
+        let examples = examples(&page);
+        let dir = self.root.join("examples").join(format!("day{:0>2}", day));
+        let mut inputs = examples.iter().map(|e| &e.input).collect::<Vec<_>>();
+        inputs.dedup();
+        for (n, input) in (1..).zip(inputs) {
+            let file = dir.join(n.to_string());
+            if !file.exists() {
+                fs::create_dir_all(&dir)?;
+                write_atomic(&file, &format!("{}\n", input))?;
+            }
+        }
+        Ok(Puzzle {
+            path,
+            parts: articles(&page).len() as u32,
+            examples,
+        })
+    }
+}
+
+/// A downloaded puzzle description.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    pub path: PathBuf,
+    /// How many parts the description has so far.
+    pub parts: u32,
+    /// The examples of the parts that have one with a highlighted answer.
+    pub examples: Vec<Example>,
+}
+
+/// A part's example input with the answer the description highlights for it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub part: u32,
+    pub input: String,
+    pub answer: String,
+}
+
+const PART_TWO: &str = "--- Part Two ---";
+
+/// The puzzle description of a day's page, each `<article class="day-desc">` of it, as
+/// Markdown.
+pub fn markdown(html: &str) -> String {
+    let parts = articles(html)
+        .into_iter()
+        .map(|article| Markdown::default().convert(article))
+        .collect::<Vec<_>>();
+    let mut markdown = parts.join("\n\n");
+    if !markdown.is_empty() {
+        markdown.push('\n');
+    }
+    markdown
+}
+
+/// The examples of each part: the first `<pre><code>` block of its description, or the
+/// previous part's when it has none, with the last highlighted `<code><em>` as the answer.
+pub fn examples(html: &str) -> Vec<Example> {
+    let mut examples = Vec::new();
+    let mut input = None;
+    for (part, article) in (1..).zip(articles(html)) {
+        let tokens = tokens(article);
+        let mut pre = None;
+        let mut blocks = Vec::new();
+        let mut answer = None;
+        for (i, token) in tokens.iter().enumerate() {
+            match token {
+                Token::Open("pre", _) => pre = Some(String::new()),
+                Token::Close("pre") => blocks.extend(pre.take()),
+                Token::Text(text) => {
+                    if let Some(pre) = &mut pre {
+                        pre.push_str(&decode(text));
+                    }
+                }
+                _ => {}
+            }
+            if let [
+                Token::Open(outer, _),
+                Token::Open(inner, _),
+                Token::Text(text),
+                Token::Close(_),
+                Token::Close(_),
+                ..,
+            ] = &tokens[i..]
+                && matches!((*outer, *inner), ("code", "em") | ("em", "code"))
+            {
+                answer = Some(decode(text));
+            }
+        }
+
+        if let Some(block) = blocks.into_iter().next() {
+            input = Some(block.trim_end_matches('\n').to_string());
+        }
+        if let (Some(input), Some(answer)) = (&input, answer) {
+            examples.push(Example {
+                part,
+                input: input.clone(),
+                answer,
+            });
+        }
+    }
+    examples
+}
+
+/// The empty test of a part a new day starts with.
+pub fn placeholder(part: u32) -> String {
+    format!("generate_test!(r#\"\"#, {}, 0);", part)
+}
+
+/// Fills the `placeholder` of each example's part in, leaving tests that were written by
+/// hand alone. `None` when there was nothing to fill in.
+pub fn fill_tests(source: &str, examples: &[Example]) -> Option<String> {
+    let mut source = source.to_string();
+    let mut filled = false;
+    for example in examples {
+        let placeholder = placeholder(example.part);
+        if !source.contains(&placeholder) {
+            continue;
+        }
+        let mut hashes = "#".to_string();
+        while example.input.contains(&format!("\"{}", hashes)) {
+            hashes.push('#');
+        }
+        let answer = match example.answer.parse::<i128>() {
+            Ok(_) => example.answer.clone(),
+            Err(_) => format!("{:?}", example.answer),
+        };
+        let test = format!(
+            "generate_test!(\n        r{}\"{}\"{},\n        {},\n        {}\n    );",
+            hashes, example.input, hashes, example.part, answer
+        );
+        source = source.replacen(&placeholder, &test, 1);
+        filled = true;
+    }
+    filled.then_some(source)
+}
+
+/// The `<article class="day-desc">` of each part on a day's page.
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find("<article class=\"day-desc\">") {
+        let article = &rest[start..];
+        let end = article.find("</article>").unwrap_or(article.len());
+        articles.push(&article[..end]);
+        rest = &article[end..];
+    }
+    articles
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    Open(&'a str, &'a str),
+    Close(&'a str),
+}
+
+fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+        let end = rest[start..]
+            .find('>')
+            .map_or(rest.len(), |end| start + end);
+        let tag = rest[start + 1..end].trim_end_matches('/');
+        match tag.strip_prefix('/') {
+            Some(name) => tokens.push(Token::Close(name.trim())),
+            None => {
+                let (name, attrs) = tag.split_once(' ').unwrap_or((tag, ""));
+                tokens.push(Token::Open(name, attrs));
+            }
+        }
+        rest = &rest[(end + 1).min(rest.len())..];
+    }
+    tokens
+}
+
+#[derive(Default)]
+struct Markdown {
+    out: String,
+    pre: bool,
+    code: bool,
+    lists: usize,
+    links: Vec<String>,
+}
+
+impl Markdown {
+    fn convert(mut self, html: &str) -> String {
+        let tokens = tokens(html);
+        let mut i = 0;
+        while i < tokens.len() {
+            // `<code><em>143</em></code>` is how answers are highlighted.
+            if let [
+                Token::Open("code", _),
+                Token::Open("em", _),
+                Token::Text(text),
+                Token::Close("em"),
+                Token::Close("code"),
+                ..,
+            ] = &tokens[i..]
+                && !self.pre
+            {
+                self.out.push_str(&format!("*`{}`*", decode(text)));
+                i += 5;
+                continue;
+            }
+            match tokens[i] {
+                Token::Text(text) => self.text(text),
+                Token::Open(name, attrs) => self.open(name, attrs),
+                Token::Close(name) => self.close(name),
+            }
+            i += 1;
+        }
+        let mut out = String::new();
+        for line in self.out.lines() {
+            // One blank line between blocks.
+            if !(line.trim().is_empty() && out.ends_with("\n\n")) {
+                out.push_str(line.trim_end());
+                out.push('\n');
+            }
+        }
+        out.trim().to_string()
+    }
+
+    fn text(&mut self, text: &str) {
+        let text = decode(text);
+        if self.pre {
+            self.out.push_str(&text);
+        } else if !(self.out.ends_with('\n') && text.trim().is_empty()) {
+            self.out.push_str(&text.replace('\n', " "));
+        }
+    }
+
+    fn open(&mut self, name: &str, attrs: &str) {
+        if self.pre {
+            return;
+        }
+        match name {
+            "h2" => self.block("## "),
+            "p" => self.block(""),
+            "pre" => {
+                self.block("```\n");
+                self.pre = true;
+            }
+            "code" => {
+                self.out.push('`');
+                self.code = true;
+            }
+            "em" if !self.code => self.out.push('*'),
+            "ul" => {
+                if self.lists == 0 {
+                    self.block("");
+                }
+                self.lists += 1;
+            }
+            "li" => {
+                self.line();
+                self.out
+                    .push_str(&"  ".repeat(self.lists.saturating_sub(1)));
+                self.out.push_str("- ");
+            }
+            "a" => {
+                self.out.push('[');
+                self.links.push(href(attrs));
+            }
+            _ => {}
+        }
+    }
+
+    fn close(&mut self, name: &str) {
+        match name {
+            "pre" => {
+                self.line();
+                self.out.push_str("```\n\n");
+                self.pre = false;
+            }
+            _ if self.pre => {}
+            "h2" | "p" => self.out.push_str("\n\n"),
+            "code" => {
+                self.out.push('`');
+                self.code = false;
+            }
+            "em" if !self.code => self.out.push('*'),
+            "ul" => {
+                self.lists = self.lists.saturating_sub(1);
+                if self.lists == 0 {
+                    self.out.push_str("\n\n");
+                }
+            }
+            "a" => {
+                let href = self.links.pop().unwrap_or_default();
+                self.out.push_str(&format!("]({})", href));
+            }
+            _ => {}
+        }
+    }
+
+    /// Starts a block on a line of its own.
+    fn block(&mut self, start: &str) {
+        if !self.out.is_empty() && !self.out.ends_with("\n\n") {
+            self.line();
+            self.out.push('\n');
+        }
+        self.out.push_str(start);
+    }
+
+    fn line(&mut self) {
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+    }
+}
+
+/// Links on the site are relative to it.
+fn href(attrs: &str) -> String {
+    let href = attrs
+        .split("href=\"")
+        .nth(1)
+        .and_then(|rest| rest.split('"').next())
+        .unwrap_or_default();
+    let href = decode(href);
+    if href.starts_with('/') {
+        format!("https://adventofcode.com{}", href)
+    } else {
+        href
+    }
+}
+
+fn decode(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest[1..].find(';').map(|end| &rest[1..end + 1]);
+        let decoded = entity.and_then(|entity| match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => {
+                let n = entity.strip_prefix('#')?;
+                let n = match n.strip_prefix('x') {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                    None => n.parse().ok()?,
+                };
+                char::from_u32(n)
+            }
+        });
+        match (entity, decoded) {
+            (Some(entity), Some(c)) => {
+                out.push(c);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::tests::serve;
+    use crate::release::FixedClock;
+    use chrono::DateTime;
+    use std::time::Duration;
+
+    fn fixture(name: &str) -> String {
+        let path = format!(
+            "{}/fixtures/puzzle/{}.html",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        );
+        fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    fn converts_to_markdown() {
+        let markdown = markdown(&fixture("part1"));
+        let expected = "\
+## --- Day 5: Print Queue ---
+
+Satisfied with their search on Ceres, the squadron of scholars suggests subsequently scanning the stationery stacks of sub-basement 17.
+
+The notation `X|Y` means that if both page number `X` and page number `Y` are to be produced as part of an update, page number `X` *must* be printed at some point before page number `Y`.
+
+For example:
+
+```
+47|53
+97|13
+97|61
+
+75,47,61,53,29
+97,61,53,29,13
+```
+
+In the above example, the first update (`75,47,61,53,29`) is in the right order:
+
+- `75` is correctly first because there are rules that put each other page after it: `75|47`, `75|61`, `75|53`, and `75|29`.
+- `47` is correctly second because `75` must be before it (`75|47`) and every other page must be after it according to `47|61`, `47|53`, and `47|29`.
+
+Adding these page numbers together gives *`143`*.
+
+Of course, you'll need to be careful: the actual list of page ordering rules is bigger & more complicated than the above example.
+
+Determine which updates are already in the correct order. *What do you get if you add up the middle page number from those correctly-ordered updates?*
+";
+        assert_eq!(markdown, expected);
+        assert!(!markdown.contains(PART_TWO));
+    }
+
+    #[test]
+    fn converts_the_rest() {
+        let both = markdown(&fixture("part2"));
+        assert!(both.contains("\n\n## --- Part Two ---\n\nWhile the Elves"));
+        assert!(both.contains("\n- `61,13,29` becomes `61,29,13`.\n"));
+        assert!(!both.contains("5374"), "{}", both);
+        assert_eq!(markdown("<html></html>"), "");
+
+        assert_eq!(
+            decode("&lt;&#62;&#x41;&amp;lt;&bogus; &"),
+            "<>A&lt;&bogus; &"
+        );
+        assert_eq!(
+            Markdown::default().convert(
+                "<p>See <a href=\"/2024/day/4\">day <em>4</em></a>:</p><ul><li>a<ul><li>b</li></ul></li></ul>"
+            ),
+            "See [day *4*](https://adventofcode.com/2024/day/4):\n\n- a\n  - b"
+        );
+    }
+
+    #[test]
+    fn finds_examples() {
+        let input = "47|53\n97|13\n97|61\n\n75,47,61,53,29\n97,61,53,29,13";
+        let example = |part: u32, answer: &str| Example {
+            part,
+            input: input.to_string(),
+            answer: answer.to_string(),
+        };
+        assert_eq!(examples(&fixture("part1")), [example(1, "143")]);
+        assert_eq!(
+            examples(&fixture("part2")),
+            [example(1, "143"), example(2, "123")]
+        );
+
+        let page = "<article class=\"day-desc\"><pre><code>a\"#b\n</code></pre>\
+            <p>That is <em><code>XYZ</code></em>.</p></article>";
+        assert_eq!(
+            examples(page),
+            [Example {
+                part: 1,
+                input: "a\"#b".to_string(),
+                answer: "XYZ".to_string()
+            }]
+        );
+        // Without a highlighted answer there is no telling what the example gives.
+        let unhighlighted = "<article class=\"day-desc\"><pre><code>1 2\n</code></pre>\
+            <p>That is <em>3</em>.</p></article>";
+        assert_eq!(examples(unhighlighted), []);
+    }
+
+    #[test]
+    fn fills_in_tests() {
+        let source = "    generate_test!(r#\"\"#, 1, 0);\n\n    generate_test!(r#\"\"#, 2, 0);\n";
+        let examples = examples(&fixture("part1"));
+        let filled = fill_tests(source, &examples).unwrap();
+        assert_eq!(
+            filled,
+            "    generate_test!(\n        r#\"47|53\n97|13\n97|61\n\n75,47,61,53,29\n97,61,53,29,13\"#,\n        1,\n        143\n    );\n\n    generate_test!(r#\"\"#, 2, 0);\n"
+        );
+        // Part 1 is done, only part 2 is left to fill in.
+        assert_eq!(fill_tests(&filled, &examples), None);
+        let both = fill_tests(&filled, &super::examples(&fixture("part2"))).unwrap();
+        assert!(both.contains("        2,\n        123\n    );"), "{}", both);
+
+        let odd = Example {
+            part: 1,
+            input: "a\"#b".to_string(),
+            answer: "XYZ".to_string(),
+        };
+        assert_eq!(
+            fill_tests(source, &[odd]).unwrap().lines().nth(1),
+            Some("        r##\"a\"#b\"##,")
+        );
+    }
+
+    #[test]
+    fn adds_part_two_once_part_one_is_solved() {
+        let root = std::env::temp_dir().join(format!("runner-puzzles-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let (base, requests) = serve(&[(200, &fixture("part1")), (200, &fixture("part2"))]);
+        let now = DateTime::parse_from_rfc3339("2024-12-06T00:00:00Z").unwrap();
+        let puzzles = Puzzles::with_root(2024, &root)
+            .with_clock(FixedClock(now.to_utc()))
+            .with_client(Client::with_base(&base).with_spacing(Duration::ZERO));
+
+        let puzzle = puzzles.fetch(5, "abc").unwrap();
+        assert_eq!(puzzle.path, root.join("puzzles/day05.md"));
+        assert_eq!(puzzle.parts, 1);
+        assert_eq!(puzzle.examples.len(), 1);
+        assert!(!fs::read_to_string(&puzzle.path).unwrap().contains(PART_TWO));
+        let example = root.join("examples/day05/1");
+        assert!(fs::read_to_string(&example).unwrap().starts_with("47|53\n"));
+
+        let puzzle = puzzles.fetch(5, "abc").unwrap();
+        assert_eq!((puzzle.parts, puzzle.examples.len()), (2, 2));
+        assert!(fs::read_to_string(&puzzle.path).unwrap().contains(PART_TWO));
+        // Both parts are there, there is nothing more to fetch, but the examples of the
+        // stored page still come back for a day whose tests weren't filled in then.
+        assert_eq!(puzzles.fetch(5, "abc").unwrap(), puzzle);
+        assert_eq!(requests.iter().count(), 2);
+        assert!(!root.join("examples/day05/2").exists());
+        assert!(matches!(
+            puzzles.fetch(6, "abc"),
+            Err(InputError::NotYetReleased(_))
+        ));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
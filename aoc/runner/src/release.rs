@@ -0,0 +1,133 @@
+use chrono::{DateTime, TimeZone, Utc};
+use chrono_tz::US::Eastern;
+
+/// Where the current time comes from when deciding whether a puzzle is out.
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock stopped at one moment, for tests and for replaying an event.
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+/// The clock to go by: stopped at `AOC_NOW` when that is set to an RFC 3339 time,
+/// e.g. `AOC_NOW=2025-12-03T05:00:00Z`, the system clock otherwise.
+pub fn clock() -> Result<Box<dyn Clock>, String> {
+    match std::env::var("AOC_NOW") {
+        Ok(now) => Ok(Box::new(stopped_at(&now)?)),
+        Err(_) => Ok(Box::new(SystemClock)),
+    }
+}
+
+fn stopped_at(now: &str) -> Result<FixedClock, String> {
+    DateTime::parse_from_rfc3339(now)
+        .map(|now| FixedClock(now.to_utc()))
+        .map_err(|e| {
+            format!(
+                "AOC_NOW is not an RFC 3339 time like 2025-12-03T05:00:00Z: {}",
+                e
+            )
+        })
+}
+
+/// The number of puzzles in a year's event. 2025 went down to 12, and the years after
+/// are taken to stay there until an event says otherwise.
+pub fn days(year: i32) -> u32 {
+    match year {
+        ..2015 => 0,
+        2015..2025 => 25,
+        _ => 12,
+    }
+}
+
+/// When a puzzle unlocks, at midnight US/Eastern, or `None` if the event has no such day.
+pub fn unlocks(year: i32, day: u32) -> Option<DateTime<Utc>> {
+    if !(1..=days(year)).contains(&day) {
+        return None;
+    }
+    Eastern
+        .with_ymd_and_hms(year, 12, day, 0, 0, 0)
+        .single()
+        .map(|at| at.to_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(time: &str) -> FixedClock {
+        FixedClock(DateTime::parse_from_rfc3339(time).unwrap().to_utc())
+    }
+
+    fn released(clock: &FixedClock, year: i32, day: u32) -> bool {
+        unlocks(year, day).is_some_and(|at| at <= clock.now())
+    }
+
+    #[test]
+    fn stops_the_clock() {
+        let clock = stopped_at("2025-12-03T00:00:00-05:00").unwrap();
+        assert_eq!(clock.now().to_rfc3339(), "2025-12-03T05:00:00+00:00");
+        assert!(stopped_at("2025-12-03").is_err());
+    }
+
+    #[test]
+    fn calendar() {
+        assert_eq!(days(2014), 0);
+        assert_eq!(days(2015), 25);
+        assert_eq!(days(2024), 25);
+        assert_eq!(days(2025), 12);
+        assert_eq!(days(2026), 12);
+        assert_eq!(unlocks(2024, 0), None);
+        assert_eq!(unlocks(2024, 26), None);
+        assert_eq!(unlocks(2025, 13), None);
+        assert!(unlocks(2025, 12).is_some());
+    }
+
+    #[test]
+    fn unlocks_at_midnight_eastern_standard_time() {
+        // December is always after the switch back from daylight saving time, so every
+        // puzzle unlocks at 05:00 UTC.
+        for year in 2015..=2030 {
+            for day in 1..=days(year) {
+                let at = unlocks(year, day).unwrap();
+                assert_eq!(
+                    at.to_rfc3339(),
+                    format!("{}-12-{:0>2}T05:00:00+00:00", year, day)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn released_from_the_second_it_unlocks() {
+        assert!(!released(&at("2025-12-05T04:59:59Z"), 2025, 5));
+        assert!(released(&at("2025-12-05T05:00:00Z"), 2025, 5));
+        assert!(!released(&at("2025-12-05T04:59:59Z"), 2025, 6));
+        // Midnight daylight saving time would be an hour early.
+        assert!(!released(&at("2025-12-05T00:00:00-04:00"), 2025, 5));
+        assert!(released(&at("2025-12-05T00:00:00-05:00"), 2025, 5));
+    }
+
+    #[test]
+    fn daylight_saving_time_boundaries() {
+        // The clocks go back at 02:00 EDT on 2025-11-02, both readings of 01:30 are
+        // still November.
+        assert!(!released(&at("2025-11-02T01:30:00-04:00"), 2025, 1));
+        assert!(!released(&at("2025-11-02T01:30:00-05:00"), 2025, 1));
+        assert!(released(&at("2026-03-08T03:00:00-04:00"), 2025, 12));
+        assert!(!released(&at("2030-01-01T00:00:00Z"), 2025, 13));
+    }
+}
@@ -0,0 +1,740 @@
+use crate::{Account, Allocs, Answers, Args, Check, Outcome, Stats, Status};
+use num_format::{Locale, ToFormattedString};
+use serde::{Deserialize, Serialize};
+use std::io::{Stdout, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Unknown format: {}", s)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub year: i32,
+    pub day: u32,
+    pub part: u32,
+    /// Whose input this ran on, `None` for the default account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
+    pub answer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub check: Option<Check>,
+    /// The accepted answer, when this one differs from it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    #[serde(rename = "read_ns", with = "nanos_opt", default)]
+    pub read: Option<Duration>,
+    #[serde(rename = "parse_ns", with = "nanos_opt", default)]
+    pub parse: Option<Duration>,
+    #[serde(rename = "solve_ns", with = "nanos")]
+    pub solve: Duration,
+    pub reparsed: bool,
+    /// Answered from the cache, so there are no timings.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub cached: bool,
+    /// Ran alongside other days with `--jobs`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub contended: bool,
+    pub status: Status,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stats: Option<Stats>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parse_allocs: Option<Allocs>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocs: Option<Allocs>,
+}
+
+impl Record {
+    /// The answer marked against the accepted one, or what went wrong instead.
+    pub fn result(&self) -> String {
+        if let Some(error) = &self.error {
+            return format!("{}: {}", self.status, error);
+        }
+        match (self.check, &self.expected) {
+            (Some(Check::Correct), _) => format!("✓ {}", self.answer),
+            (Some(Check::Wrong), Some(expected)) => {
+                format!("✗ {} (expected {})", self.answer, expected)
+            }
+            (Some(_), _) => format!("? {}", self.answer),
+            (None, _) => self.answer.clone(),
+        }
+    }
+
+    /// Unreleased days are skipped, not failed.
+    pub fn failed(&self) -> bool {
+        !matches!(self.status, Status::Ok | Status::Unreleased) || self.check == Some(Check::Wrong)
+    }
+}
+
+pub(crate) mod nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_u128(d.as_nanos())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Duration, D::Error> {
+        u64::deserialize(d).map(Duration::from_nanos)
+    }
+}
+
+mod nanos_opt {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(d: &Option<Duration>, s: S) -> Result<S::Ok, S::Error> {
+        match d {
+            Some(d) => super::nanos::serialize(d, s),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Duration>, D::Error> {
+        Ok(Option::<u64>::deserialize(d)?.map(Duration::from_nanos))
+    }
+}
+
+pub struct Report<W: Write = Stdout> {
+    year: i32,
+    format: Format,
+    bench: bool,
+    alloc: bool,
+    contended: bool,
+    /// `None` when running on another input than the one the answers are for.
+    answers: Option<Answers>,
+    account: Option<String>,
+    out: W,
+    records: Vec<Record>,
+}
+
+impl Report {
+    pub fn new(year: i32, args: &Args, answers: Answers) -> Self {
+        Self::with_writer(year, args, answers, std::io::stdout())
+    }
+}
+
+impl<W: Write> Report<W> {
+    pub fn with_writer(year: i32, args: &Args, answers: Answers, mut out: W) -> Self {
+        let format = args.format;
+        let bench = args.bench.is_some();
+        let alloc = args.alloc;
+        let contended = args.jobs.is_some();
+        let column = if contended { ",contended" } else { "" };
+
+        match (format, bench) {
+            (Format::Table, _) if alloc => writeln!(
+                out,
+                "Part     Parse allocs        Bytes         Peak Solve allocs        Bytes         Peak\tAnswer"
+            ),
+            (Format::Table, false) => writeln!(
+                out,
+                "Part             Read        Parse        Solve\tAnswer"
+            ),
+            (Format::Table, true) => writeln!(
+                out,
+                "Part      Runs           Min        Median          Mean           p95        StdDev\tAnswer"
+            ),
+            (Format::Csv, _) if alloc => writeln!(
+                out,
+                "year,day,part,account,answer,check,read_ns,parse_ns,solve_ns,reparsed,cached,status,error,parse_allocs,parse_bytes,parse_peak,allocs,bytes,peak{}",
+                column
+            ),
+            (Format::Csv, false) => writeln!(
+                out,
+                "year,day,part,account,answer,check,read_ns,parse_ns,solve_ns,reparsed,cached,status,error{}",
+                column
+            ),
+            (Format::Csv, true) => writeln!(
+                out,
+                "year,day,part,account,answer,check,read_ns,parse_ns,solve_ns,reparsed,cached,status,error,runs,min_ns,median_ns,mean_ns,p95_ns,stddev_ns"
+            ),
+            (Format::Json, _) => Ok(()),
+        }
+        .unwrap();
+
+        Self {
+            year,
+            format,
+            bench,
+            alloc,
+            contended,
+            answers: args.input.is_none().then_some(answers),
+            account: None,
+            out,
+            records: Vec::new(),
+        }
+    }
+
+    /// Records from here on are for `account`, checked against its answers.
+    pub fn account(&mut self, account: &Account, answers: Answers) {
+        if self.format == Format::Table {
+            writeln!(self.out, "{}", account).unwrap();
+        }
+        self.account = account.name.clone();
+        if self.answers.is_some() {
+            self.answers = Some(answers);
+        }
+    }
+
+    pub fn record(&mut self, day: u32, outcome: Outcome) {
+        let answers = self
+            .answers
+            .as_ref()
+            .filter(|_| outcome.status == Status::Ok);
+        let check = answers.map(|answers| answers.check(day, outcome.part, &outcome.answer));
+        let expected = match (answers, check) {
+            (Some(answers), Some(Check::Wrong)) => {
+                answers.get(day, outcome.part).map(str::to_string)
+            }
+            _ => None,
+        };
+        let record = Record {
+            year: self.year,
+            day,
+            part: outcome.part,
+            account: self.account.clone(),
+            answer: outcome.answer,
+            check,
+            expected,
+            read: outcome.read,
+            parse: outcome.parse,
+            solve: outcome.solve,
+            reparsed: outcome.reparsed,
+            cached: outcome.cached,
+            contended: self.contended,
+            status: outcome.status,
+            error: outcome.error,
+            stats: outcome.stats,
+            parse_allocs: outcome.parse_allocs,
+            allocs: outcome.allocs,
+        };
+        self.push(record);
+    }
+
+    /// Writes out a record that is already complete, e.g. one read back from JSON.
+    pub fn push(&mut self, record: Record) {
+        match (self.format, &record.stats) {
+            (Format::Table, Some(stats)) if self.bench => writeln!(
+                self.out,
+                "Day{:0>2}-{:0>2} {: >6} {} {} {} {} {}:\t{}",
+                record.day,
+                record.part,
+                stats.runs,
+                precise(&stats.min),
+                precise(&stats.median),
+                precise(&stats.mean),
+                precise(&stats.p95),
+                precise(&stats.stddev),
+                record.result()
+            ),
+            (Format::Table, _) if self.alloc => writeln!(
+                self.out,
+                "Day{:0>2}-{:0>2} {} {}:\t{}",
+                record.day,
+                record.part,
+                allocs(record.parse_allocs.as_ref()),
+                allocs(record.allocs.as_ref()),
+                record.result()
+            ),
+            (Format::Table, _) => writeln!(
+                self.out,
+                "Day{:0>2}-{:0>2} {} {} {}:\t{}",
+                record.day,
+                record.part,
+                micros(record.read.as_ref(), None),
+                micros(record.parse.as_ref(), record.reparsed.then_some('*')),
+                solve(&record),
+                record.result()
+            ),
+            (Format::Json, _) => writeln!(self.out, "{}", serde_json::to_string(&record).unwrap()),
+            (Format::Csv, stats) => {
+                let stats = match stats {
+                    Some(stats) if self.bench => format!(
+                        ",{},{},{},{},{},{}",
+                        stats.runs,
+                        stats.min.as_nanos(),
+                        stats.median.as_nanos(),
+                        stats.mean.as_nanos(),
+                        stats.p95.as_nanos(),
+                        stats.stddev.as_nanos()
+                    ),
+                    _ if self.bench => ",,,,,,".to_string(),
+                    _ if self.alloc => [&record.parse_allocs, &record.allocs]
+                        .map(|allocs| match allocs {
+                            Some(a) => format!(",{},{},{}", a.count, a.bytes, a.peak),
+                            None => ",,,".to_string(),
+                        })
+                        .concat(),
+                    _ => String::new(),
+                };
+                let contended = if self.contended {
+                    format!(",{}", record.contended)
+                } else {
+                    String::new()
+                };
+                writeln!(
+                    self.out,
+                    "{},{},{},{},{},{},{},{},{},{},{},{},{}{}{}",
+                    record.year,
+                    record.day,
+                    record.part,
+                    csv_escape(record.account.as_deref().unwrap_or_default()),
+                    csv_escape(&record.answer),
+                    record.check.map_or(String::new(), |c| c.to_string()),
+                    nanos_cell(record.read.as_ref()),
+                    nanos_cell(record.parse.as_ref()),
+                    record.solve.as_nanos(),
+                    record.reparsed,
+                    record.cached,
+                    record.status.as_str(),
+                    csv_escape(record.error.as_deref().unwrap_or_default()),
+                    stats,
+                    contended
+                )
+            }
+        }
+        .unwrap();
+
+        self.records.push(record);
+    }
+
+    pub fn finish(mut self) -> Vec<Record> {
+        if self.format == Format::Table && self.records.iter().any(|r| r.reparsed) {
+            writeln!(
+                self.out,
+                "* parsed again, the parsed input is not Clone and both parts consume it"
+            )
+            .unwrap();
+        }
+        if self.format == Format::Table && self.records.iter().any(|r| r.cached) {
+            writeln!(
+                self.out,
+                "cached: solved before for the same input and code, --fresh solves it again"
+            )
+            .unwrap();
+        }
+        if self.format == Format::Table && !self.alloc && self.records.iter().any(|r| r.contended) {
+            writeln!(
+                self.out,
+                "~ ran alongside other days with --jobs, the timings are contended"
+            )
+            .unwrap();
+        }
+        if self.format == Format::Table
+            && self.records.iter().any(|r| r.check == Some(Check::Unknown))
+        {
+            writeln!(
+                self.out,
+                "? no accepted answer in answers.toml yet, --record saves these"
+            )
+            .unwrap();
+        }
+        self.out.flush().unwrap();
+        self.records
+    }
+}
+
+/// The solve time, or that there was none.
+fn solve(record: &Record) -> String {
+    if record.cached {
+        format!("{: >12}", "cached")
+    } else {
+        micros(Some(&record.solve), record.contended.then_some('~'))
+    }
+}
+
+/// A duration with an optional mark for a footnote.
+fn micros(d: Option<&Duration>, mark: Option<char>) -> String {
+    match (d, mark) {
+        (Some(d), Some(mark)) => {
+            format!(
+                "{: >9}μs{}",
+                d.as_micros().to_formatted_string(&Locale::en),
+                mark
+            )
+        }
+        (Some(d), None) => format!("{: >10}μs", d.as_micros().to_formatted_string(&Locale::en)),
+        (None, _) => format!("{: >12}", "-"),
+    }
+}
+
+/// Allocations, bytes and peak bytes.
+fn allocs(allocs: Option<&Allocs>) -> String {
+    let cell = |n: Option<u64>| match n {
+        Some(n) => format!("{: >12}", n.to_formatted_string(&Locale::en)),
+        None => format!("{: >12}", "-"),
+    };
+    [
+        allocs.map(|a| a.count),
+        allocs.map(|a| a.bytes),
+        allocs.map(|a| a.peak),
+    ]
+    .map(cell)
+    .join(" ")
+}
+
+fn precise(d: &Duration) -> String {
+    format!("{: >11.3}μs", d.as_nanos() as f64 / 1000.0)
+}
+
+fn nanos_cell(d: Option<&Duration>) -> String {
+    d.map_or(String::new(), |d| d.as_nanos().to_string())
+}
+
+fn csv_escape(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Bench;
+
+    fn render(format: Format) -> String {
+        let args = Args {
+            format,
+            ..Default::default()
+        };
+        let answers = "[day10]\npart2 = \"#..#\\n.##.\"\n[day11]\npart2 = 41"
+            .parse()
+            .unwrap();
+        let mut out = Vec::new();
+        let mut report = Report::with_writer(2022, &args, answers, &mut out);
+        report.record(
+            10,
+            Outcome {
+                part: 2,
+                answer: "#..#\n.##.".to_string(),
+                read: Some(Duration::from_micros(12)),
+                parse: Some(Duration::from_micros(1500)),
+                solve: Duration::from_nanos(2_000_123),
+                reparsed: false,
+                stats: None,
+                ..Default::default()
+            },
+        );
+        report.record(
+            11,
+            Outcome {
+                part: 2,
+                answer: "42".to_string(),
+                read: None,
+                parse: Some(Duration::from_micros(8)),
+                solve: Duration::from_micros(3),
+                reparsed: true,
+                stats: None,
+                ..Default::default()
+            },
+        );
+        report.record(
+            12,
+            Outcome {
+                part: 1,
+                read: Some(Duration::from_micros(5)),
+                solve: Duration::from_micros(40),
+                status: Status::Panic,
+                error: Some("not implemented".to_string()),
+                ..Default::default()
+            },
+        );
+        report.finish();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn table() {
+        assert_eq!(
+            render(Format::Table),
+            "Part             Read        Parse        Solve\tAnswer\n\
+             Day10-02         12μs      1,500μs      2,000μs:\t✓ #..#\n.##.\n\
+             Day11-02            -         8μs*          3μs:\t✗ 42 (expected 41)\n\
+             Day12-01          5μs            -         40μs:\tPANIC: not implemented\n\
+             * parsed again, the parsed input is not Clone and both parts consume it\n"
+        );
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(
+            render(Format::Json),
+            "{\"year\":2022,\"day\":10,\"part\":2,\"answer\":\"#..#\\n.##.\",\"check\":\"correct\",\"read_ns\":12000,\"parse_ns\":1500000,\"solve_ns\":2000123,\"reparsed\":false,\"status\":\"ok\"}\n\
+             {\"year\":2022,\"day\":11,\"part\":2,\"answer\":\"42\",\"check\":\"wrong\",\"expected\":\"41\",\"read_ns\":null,\"parse_ns\":8000,\"solve_ns\":3000,\"reparsed\":true,\"status\":\"ok\"}\n\
+             {\"year\":2022,\"day\":12,\"part\":1,\"answer\":\"\",\"read_ns\":5000,\"parse_ns\":null,\"solve_ns\":40000,\"reparsed\":false,\"status\":\"panic\",\"error\":\"not implemented\"}\n"
+        );
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            render(Format::Csv),
+            "year,day,part,account,answer,check,read_ns,parse_ns,solve_ns,reparsed,cached,status,error\n\
+             2022,10,2,,\"#..#\n.##.\",correct,12000,1500000,2000123,false,false,ok,\n\
+             2022,11,2,,42,wrong,,8000,3000,true,false,ok,\n\
+             2022,12,1,,,,5000,,40000,false,false,panic,not implemented\n"
+        );
+    }
+
+    fn render_bench(format: Format) -> String {
+        let args = Args {
+            format,
+            bench: Some(Bench {
+                runs: 3,
+                budget: Duration::from_secs(1),
+            }),
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        let mut report = Report::with_writer(2024, &args, Answers::default(), &mut out);
+        let mut samples = [1500, 1000, 2000].map(Duration::from_nanos);
+        let stats = Stats::from_samples(&mut samples).unwrap();
+        report.record(
+            6,
+            Outcome {
+                part: 1,
+                answer: "41".to_string(),
+                read: Some(Duration::from_micros(12)),
+                parse: Some(Duration::from_micros(20)),
+                solve: stats.median,
+                reparsed: false,
+                stats: Some(stats),
+                ..Default::default()
+            },
+        );
+        report.finish();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn bench_table() {
+        assert_eq!(
+            render_bench(Format::Table),
+            "Part      Runs           Min        Median          Mean           p95        StdDev\tAnswer\n\
+             Day06-01      3       1.000μs       1.500μs       1.500μs       2.000μs       0.500μs:\t? 41\n\
+             ? no accepted answer in answers.toml yet, --record saves these\n"
+        );
+    }
+
+    #[test]
+    fn bench_csv_and_json() {
+        assert_eq!(
+            render_bench(Format::Csv),
+            "year,day,part,account,answer,check,read_ns,parse_ns,solve_ns,reparsed,cached,status,error,runs,min_ns,median_ns,mean_ns,p95_ns,stddev_ns\n\
+             2024,6,1,,41,unknown,12000,20000,1500,false,false,ok,,3,1000,1500,1500,2000,500\n"
+        );
+        assert_eq!(
+            render_bench(Format::Json),
+            "{\"year\":2024,\"day\":6,\"part\":1,\"answer\":\"41\",\"check\":\"unknown\",\"read_ns\":12000,\"parse_ns\":20000,\"solve_ns\":1500,\"reparsed\":false,\"status\":\"ok\",\
+             \"stats\":{\"runs\":3,\"min_ns\":1000,\"median_ns\":1500,\"mean_ns\":1500,\"p95_ns\":2000,\"stddev_ns\":500}}\n"
+        );
+    }
+
+    fn render_alloc(format: Format) -> String {
+        let args = Args {
+            format,
+            alloc: true,
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        let mut report = Report::with_writer(2024, &args, Answers::default(), &mut out);
+        let allocs = |count, bytes, peak| Some(Allocs { count, bytes, peak });
+        report.record(
+            6,
+            Outcome {
+                part: 1,
+                answer: "41".to_string(),
+                parse: Some(Duration::from_micros(20)),
+                solve: Duration::from_micros(3),
+                parse_allocs: allocs(12, 4096, 2048),
+                allocs: allocs(1500, 1_200_000, 64),
+                ..Default::default()
+            },
+        );
+        report.record(
+            6,
+            Outcome {
+                part: 2,
+                answer: "7".to_string(),
+                solve: Duration::from_micros(5),
+                allocs: allocs(0, 0, 0),
+                ..Default::default()
+            },
+        );
+        report.finish();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn alloc_table() {
+        assert_eq!(
+            render_alloc(Format::Table),
+            "Part     Parse allocs        Bytes         Peak Solve allocs        Bytes         Peak\tAnswer\n\
+             Day06-01           12        4,096        2,048        1,500    1,200,000           64:\t? 41\n\
+             Day06-02            -            -            -            0            0            0:\t? 7\n\
+             ? no accepted answer in answers.toml yet, --record saves these\n"
+        );
+    }
+
+    #[test]
+    fn alloc_csv_and_json() {
+        assert_eq!(
+            render_alloc(Format::Csv),
+            "year,day,part,account,answer,check,read_ns,parse_ns,solve_ns,reparsed,cached,status,error,parse_allocs,parse_bytes,parse_peak,allocs,bytes,peak\n\
+             2024,6,1,,41,unknown,,20000,3000,false,false,ok,,12,4096,2048,1500,1200000,64\n\
+             2024,6,2,,7,unknown,,,5000,false,false,ok,,,,,0,0,0\n"
+        );
+        let json = render_alloc(Format::Json);
+        let record = serde_json::from_str::<Record>(json.lines().next().unwrap()).unwrap();
+        assert!(json.contains(",\"parse_allocs\":{\"count\":12,\"bytes\":4096,\"peak\":2048},"));
+        assert_eq!(record.allocs.unwrap().bytes, 1_200_000);
+    }
+
+    fn render_contended(format: Format) -> String {
+        let args = Args {
+            format,
+            jobs: Some(4),
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        let mut report = Report::with_writer(2024, &args, Answers::default(), &mut out);
+        report.record(
+            22,
+            Outcome {
+                part: 1,
+                answer: "37".to_string(),
+                parse: Some(Duration::from_micros(20)),
+                solve: Duration::from_micros(1500),
+                ..Default::default()
+            },
+        );
+        report.finish();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn contended() {
+        assert_eq!(
+            render_contended(Format::Table),
+            "Part             Read        Parse        Solve\tAnswer\n\
+             Day22-01            -         20μs     1,500μs~:\t? 37\n\
+             ~ ran alongside other days with --jobs, the timings are contended\n\
+             ? no accepted answer in answers.toml yet, --record saves these\n"
+        );
+        assert_eq!(
+            render_contended(Format::Csv),
+            "year,day,part,account,answer,check,read_ns,parse_ns,solve_ns,reparsed,cached,status,error,contended\n\
+             2024,22,1,,37,unknown,,20000,1500000,false,false,ok,,true\n"
+        );
+        assert!(render_contended(Format::Json).contains(",\"contended\":true,"));
+        // Only written when set.
+        assert!(!render(Format::Json).contains("contended"));
+    }
+
+    #[test]
+    fn cached() {
+        let render = |format| {
+            let args = Args {
+                format,
+                ..Default::default()
+            };
+            let answers = "[day09]\npart1 = 13".parse().unwrap();
+            let mut out = Vec::new();
+            let mut report = Report::with_writer(2022, &args, answers, &mut out);
+            report.record(
+                9,
+                Outcome {
+                    part: 1,
+                    answer: "13".to_string(),
+                    read: Some(Duration::from_micros(40)),
+                    cached: true,
+                    ..Default::default()
+                },
+            );
+            report.finish();
+            String::from_utf8(out).unwrap()
+        };
+        assert_eq!(
+            render(Format::Table),
+            "Part             Read        Parse        Solve\tAnswer\n\
+             Day09-01         40μs            -       cached:\t✓ 13\n\
+             cached: solved before for the same input and code, --fresh solves it again\n"
+        );
+        assert_eq!(
+            render(Format::Csv).lines().nth(1),
+            Some("2022,9,1,,13,correct,40000,,0,false,true,ok,")
+        );
+        assert!(render(Format::Json).contains(",\"cached\":true,"));
+    }
+
+    #[test]
+    fn accounts() {
+        let args = Args::default();
+        let alice = Account::named(std::path::Path::new("2022"), "alice");
+        let mut out = Vec::new();
+        let mut report = Report::with_writer(2022, &args, Answers::default(), &mut out);
+        report.account(&alice, "[day01]\npart1 = 7".parse().unwrap());
+        report.record(
+            1,
+            Outcome {
+                part: 1,
+                answer: "7".to_string(),
+                ..Default::default()
+            },
+        );
+        let records = report.finish();
+
+        assert_eq!(records[0].account.as_deref(), Some("alice"));
+        assert_eq!(records[0].check, Some(Check::Correct));
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Part             Read        Parse        Solve\tAnswer\n\
+             alice\n\
+             Day01-01            -            -          0μs:\t✓ 7\n"
+        );
+    }
+
+    #[test]
+    fn json_reads_back() {
+        let out = render_bench(Format::Json) + &render(Format::Json);
+        let records = out
+            .lines()
+            .map(|line| serde_json::from_str::<Record>(line).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(records.len(), 4);
+        assert_eq!(
+            records[0].stats.as_ref().unwrap().p95,
+            Duration::from_micros(2)
+        );
+        assert_eq!(records[2].read, None);
+        assert_eq!(records[2].expected.as_deref(), Some("41"));
+        assert_eq!(records[3].status, Status::Panic);
+        for (line, record) in out.lines().zip(&records) {
+            assert_eq!(serde_json::to_string(record).unwrap(), line);
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::ops::RangeInclusive;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Target {
+    days: RangeInclusive<u32>,
+    part: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Selection {
+    targets: Vec<Target>,
+}
+
+impl Selection {
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    pub fn latest(available: &[u32]) -> Self {
+        let mut selection = Self::default();
+        selection.push_latest(available);
+        selection
+    }
+
+    pub fn push_latest(&mut self, available: &[u32]) {
+        if let Some(&day) = available.iter().max() {
+            self.targets.push(Target {
+                days: day..=day,
+                part: None,
+            });
+        }
+    }
+
+    /// Accepts `7`, `7:2`, `3-9` and `3-9:1`.
+    pub fn push_spec(&mut self, spec: &str, available: &[u32]) -> Result<(), String> {
+        let (days, part) = match spec.split_once(':') {
+            Some((days, part)) => (days, Some(parse_part(part)?)),
+            None => (spec, None),
+        };
+
+        let days = match days.split_once('-') {
+            Some((first, last)) => {
+                let first = parse_day(first)?;
+                let last = parse_day(last)?;
+                if first > last {
+                    return Err(format!("Invalid day range: {}", spec));
+                }
+                first..=last
+            }
+            None => {
+                let day = parse_day(days)?;
+                if !available.contains(&day) {
+                    return Err(format!("Day {} is not available", day));
+                }
+                day..=day
+            }
+        };
+
+        self.targets.push(Target { days, part });
+        Ok(())
+    }
+
+    pub fn contains(&self, day: u32, part: u32) -> bool {
+        self.targets.is_empty()
+            || self
+                .targets
+                .iter()
+                .any(|t| t.days.contains(&day) && t.part.is_none_or(|p| p == part))
+    }
+
+    pub fn contains_day(&self, day: u32) -> bool {
+        self.contains(day, 1) || self.contains(day, 2)
+    }
+}
+
+fn parse_day(s: &str) -> Result<u32, String> {
+    match s.trim_start_matches("day").parse::<u32>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("Invalid day: {}", s)),
+    }
+}
+
+fn parse_part(s: &str) -> Result<u32, String> {
+    match s.parse::<u32>() {
+        Ok(part @ 1..=2) => Ok(part),
+        _ => Err(format!("Invalid part: {}", s)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS: [u32; 11] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+
+    fn parse(specs: &[&str]) -> Result<Selection, String> {
+        let mut selection = Selection::all();
+        for spec in specs {
+            selection.push_spec(spec, &DAYS)?;
+        }
+        Ok(selection)
+    }
+
+    #[test]
+    fn empty_selects_everything() {
+        let selection = parse(&[]).unwrap();
+        assert!(selection.contains(1, 1));
+        assert!(selection.contains(25, 2));
+    }
+
+    #[test]
+    fn single_day_and_part() {
+        let selection = parse(&["7:2"]).unwrap();
+        assert!(selection.contains(7, 2));
+        assert!(!selection.contains(7, 1));
+        assert!(!selection.contains(6, 2));
+    }
+
+    #[test]
+    fn ranges_and_unions() {
+        let selection = parse(&["3-5", "9:1"]).unwrap();
+        assert!(selection.contains(3, 1));
+        assert!(selection.contains(5, 2));
+        assert!(!selection.contains(6, 1));
+        assert!(selection.contains(9, 1));
+        assert!(!selection.contains(9, 2));
+    }
+
+    #[test]
+    fn latest() {
+        let selection = Selection::latest(&DAYS);
+        assert!(selection.contains_day(11));
+        assert!(!selection.contains_day(10));
+    }
+
+    #[test]
+    fn invalid_specs() {
+        assert!(parse(&["0"]).is_err());
+        assert!(parse(&["26"]).is_err());
+        assert!(parse(&["12"]).is_err());
+        assert!(parse(&["7:3"]).is_err());
+        assert!(parse(&["9-3"]).is_err());
+        assert!(parse(&["x"]).is_err());
+    }
+}
@@ -0,0 +1,48 @@
+use std::fmt::Display;
+
+/// A day's solution, implemented by a unit struct in every day crate so that the runner,
+/// the tests and the benchmarks can drive each day the same way.
+///
+/// The input is parsed once and then given to each part; it may borrow from the raw
+/// input, e.g. `type Input<'a> = Vec<&'a str>`.
+pub trait Solution {
+    type Input<'a>;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(data: &str) -> Self::Input<'_>;
+    fn part1(input: Self::Input<'_>) -> Self::Output1;
+    fn part2(input: Self::Input<'_>) -> Self::Output2;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Words;
+
+    impl Solution for Words {
+        type Input<'a> = Vec<&'a str>;
+        type Output1 = usize;
+        type Output2 = String;
+
+        fn parse(data: &str) -> Self::Input<'_> {
+            data.split_whitespace().collect()
+        }
+
+        fn part1(input: Self::Input<'_>) -> Self::Output1 {
+            input.len()
+        }
+
+        fn part2(input: Self::Input<'_>) -> Self::Output2 {
+            input.concat()
+        }
+    }
+
+    #[test]
+    fn borrows_from_the_input() {
+        let input = "a b c".to_string();
+        assert_eq!(Words::part1(Words::parse(&input)), 3);
+        assert_eq!(Words::part2(Words::parse(&input)), "abc");
+    }
+}
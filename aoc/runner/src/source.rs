@@ -0,0 +1,109 @@
+use crate::InputError;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+
+/// Where a day's input comes from. Plain functions like `|day| util::read_input(2024, day)`
+/// are sources too.
+pub trait InputSource {
+    fn read(&self, day: u32) -> Result<String, InputError>;
+}
+
+impl<F: Fn(u32) -> Result<String, InputError>> InputSource for F {
+    fn read(&self, day: u32) -> Result<String, InputError> {
+        self(day)
+    }
+}
+
+/// A source shared by the workers of every day.
+pub type Source = Arc<dyn InputSource + Send + Sync>;
+
+/// An input from the command line, replacing the cached one for every selected day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Input {
+    /// A file, or a directory with `dayNN` files in it like `{year}/inputs`.
+    Path(PathBuf),
+    Stdin,
+    /// The Nth example, from `{year}/examples/dayNN/N`.
+    Example(u32),
+}
+
+impl Input {
+    pub fn source(&self, root: &Path) -> Source {
+        match self {
+            Input::Path(path) => Arc::new(PathSource(path.clone())),
+            Input::Stdin => Arc::new(StdinSource::default()),
+            Input::Example(n) => {
+                let examples = root.join("examples");
+                let n = *n;
+                Arc::new(move |day| read_file(&examples.join(day_file(day)).join(n.to_string())))
+            }
+        }
+    }
+}
+
+struct PathSource(PathBuf);
+
+impl InputSource for PathSource {
+    fn read(&self, day: u32) -> Result<String, InputError> {
+        if self.0.is_dir() {
+            read_file(&self.0.join(day_file(day)))
+        } else {
+            read_file(&self.0)
+        }
+    }
+}
+
+/// Reads stdin once and hands the same input to every day.
+#[derive(Default)]
+struct StdinSource(OnceLock<Result<String, String>>);
+
+impl InputSource for StdinSource {
+    fn read(&self, _day: u32) -> Result<String, InputError> {
+        let input = self.0.get_or_init(|| {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map(|_| input)
+                .map_err(|e| format!("Failed to read stdin: {}", e))
+        });
+        input.clone().map_err(|e| io::Error::other(e).into())
+    }
+}
+
+fn day_file(day: u32) -> String {
+    format!("day{:0>2}", day)
+}
+
+/// Like `fs::read_to_string`, with the path in the error.
+fn read_file(path: &Path) -> Result<String, InputError> {
+    std::fs::read_to_string(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn reads_files_directories_and_examples() {
+        let root = std::env::temp_dir().join(format!("runner-source-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("mine")).unwrap();
+        fs::create_dir_all(root.join("examples/day03")).unwrap();
+        fs::write(root.join("mine/day03"), "mine").unwrap();
+        fs::write(root.join("edge"), "edge").unwrap();
+        fs::write(root.join("examples/day03/2"), "example").unwrap();
+
+        let read = |input: Input, day| input.source(&root).read(day).map_err(|e| e.to_string());
+        assert_eq!(read(Input::Path(root.join("mine")), 3).unwrap(), "mine");
+        assert_eq!(read(Input::Path(root.join("edge")), 3).unwrap(), "edge");
+        assert_eq!(read(Input::Example(2), 3).unwrap(), "example");
+        let missing = read(Input::Example(1), 3).unwrap_err();
+        assert!(missing.contains("examples/day03/1"), "{}", missing);
+        assert!(read(Input::Path(root.join("mine")), 4).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,302 @@
+use crate::{Answers, Client, InputError};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// What adventofcode.com made of an answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Right,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The previous answer was too recent, try again after this long.
+    #[serde(skip)]
+    RateLimited(Duration),
+    /// The part is already solved.
+    #[serde(skip)]
+    Solved,
+}
+
+impl Verdict {
+    /// Reads the page that comes back after posting an answer.
+    pub fn parse(html: &str) -> Option<Self> {
+        let text = article(html);
+        if text.contains("That's the right answer") {
+            Some(Verdict::Right)
+        } else if text.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if text.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else if text.contains("That's not the right answer") {
+            Some(Verdict::Wrong)
+        } else if text.contains("You gave an answer too recently") {
+            Some(Verdict::RateLimited(wait(&text).unwrap_or_default()))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Some(Verdict::Solved)
+        } else {
+            None
+        }
+    }
+
+    fn rejected(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Right => write!(f, "right"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited(wait) => write!(f, "too soon, wait {}s", wait.as_secs()),
+            Verdict::Solved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// The text of the page's `<article>`, without markup.
+pub fn article(html: &str) -> String {
+    let start = html.find("<article").unwrap_or(0);
+    let end = html[start..]
+        .find("</article>")
+        .map_or(html.len(), |end| start + end);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html[start..end].chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.trim().to_string()
+}
+
+/// "You have 4m 44s left to wait."
+fn wait(text: &str) -> Option<Duration> {
+    let left = text.split("You have ").nth(1)?.split(" left").next()?;
+    left.split_whitespace()
+        .try_fold(Duration::ZERO, |wait, part| {
+            let (n, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let n = n.parse::<u64>().ok()?;
+            match unit {
+                "h" => Some(wait + Duration::from_secs(n * 3600)),
+                "m" => Some(wait + Duration::from_secs(n * 60)),
+                "s" => Some(wait + Duration::from_secs(n)),
+                _ => None,
+            }
+        })
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub at: DateTime<Utc>,
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer sent for a year, kept next to its inputs in `submissions.jsonl`.
+pub struct Submissions {
+    path: PathBuf,
+}
+
+impl Submissions {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    pub fn load(&self) -> Vec<Submission> {
+        fs::read_to_string(&self.path)
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect()
+    }
+
+    pub fn append(&self, submission: &Submission) -> std::io::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(submission)?)
+    }
+}
+
+/// Why sending `answer` would be pointless, judging by the accepted answers and what
+/// was sent before.
+pub fn refusal(
+    answers: &Answers,
+    history: &[Submission],
+    day: u32,
+    part: u32,
+    answer: &str,
+) -> Option<String> {
+    if let Some(accepted) = answers.get(day, part) {
+        return Some(format!(
+            "Already solved, the accepted answer is {}",
+            accepted
+        ));
+    }
+
+    let earlier = history
+        .iter()
+        .filter(|s| s.day == day && s.part == part && s.verdict.rejected());
+    let number = answer.parse::<i128>().ok();
+    for submission in earlier {
+        let bound = submission.answer.parse::<i128>().ok();
+        let known = match (submission.verdict, number, bound) {
+            _ if submission.answer == answer => true,
+            (Verdict::TooHigh, Some(n), Some(bound)) => n >= bound,
+            (Verdict::TooLow, Some(n), Some(bound)) => n <= bound,
+            _ => false,
+        };
+        if known {
+            return Some(format!(
+                "{} was {} on {}",
+                submission.answer,
+                submission.verdict,
+                submission.at.format("%Y-%m-%d %H:%M")
+            ));
+        }
+    }
+    None
+}
+
+impl Client {
+    /// Sends an answer and reads what came of it, with the text of the page for people.
+    pub fn submit(
+        &self,
+        year: i32,
+        day: u32,
+        part: u32,
+        answer: &str,
+        session: &str,
+    ) -> Result<(Verdict, String), InputError> {
+        let page = self.post(
+            &format!("/{}/day/{}/answer", year, day),
+            session,
+            &[("level", &part.to_string()), ("answer", answer)],
+        )?;
+        let text = article(&page);
+        match Verdict::parse(&page) {
+            Some(verdict) => Ok((verdict, text)),
+            None => {
+                let e = format!("Unexpected answer page: {}", text);
+                Err(std::io::Error::new(std::io::ErrorKind::InvalidData, e).into())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::tests::serve;
+
+    fn fixture(name: &str) -> String {
+        let path = format!(
+            "{}/fixtures/submit/{}.html",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        );
+        fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    fn parses_answer_pages() {
+        let verdict = |name| Verdict::parse(&fixture(name));
+        assert_eq!(verdict("right"), Some(Verdict::Right));
+        assert_eq!(verdict("too_high"), Some(Verdict::TooHigh));
+        assert_eq!(verdict("too_low"), Some(Verdict::TooLow));
+        assert_eq!(verdict("wrong"), Some(Verdict::Wrong));
+        assert_eq!(
+            verdict("rate_limited"),
+            Some(Verdict::RateLimited(Duration::from_secs(284)))
+        );
+        assert_eq!(verdict("solved"), Some(Verdict::Solved));
+        assert_eq!(Verdict::parse("<html></html>"), None);
+        assert!(
+            article(&fixture("right"))
+                .starts_with("That's the right answer!  You are one gold star")
+        );
+    }
+
+    fn sent(day: u32, part: u32, answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            at: DateTime::from_timestamp(1_733_378_400, 0).unwrap(),
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let answers = "[day05]\npart1 = 143".parse::<Answers>().unwrap();
+        let history = [
+            sent(5, 2, "900", Verdict::TooHigh),
+            sent(5, 2, "100", Verdict::TooLow),
+            sent(5, 2, "ABC", Verdict::Wrong),
+            sent(6, 2, "500", Verdict::Wrong),
+        ];
+        let refusal = |day, part, answer| refusal(&answers, &history, day, part, answer);
+
+        assert!(refusal(5, 1, "144").unwrap().contains("143"));
+        assert_eq!(
+            refusal(5, 2, "950").as_deref(),
+            Some("900 was too high on 2024-12-05 06:00")
+        );
+        assert!(refusal(5, 2, "100").is_some());
+        assert!(refusal(5, 2, "ABC").is_some());
+        assert_eq!(refusal(5, 2, "500"), None);
+        assert_eq!(refusal(6, 1, "500"), None);
+    }
+
+    #[test]
+    fn posts_answers() {
+        let (base, requests) = serve(&[(200, &fixture("right"))]);
+        let client = Client::with_base(&base).with_spacing(Duration::ZERO);
+
+        let verdict = client.submit(2024, 5, 2, "6034", "abc");
+        assert!(matches!(verdict, Ok((Verdict::Right, _))), "{:?}", verdict);
+        let request = requests.recv().unwrap();
+        assert!(
+            request.starts_with("POST /2024/day/5/answer"),
+            "{}",
+            request
+        );
+    }
+
+    #[test]
+    fn keeps_history() {
+        let path = std::env::temp_dir().join(format!("runner-submissions-{}", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let submissions = Submissions::new(path.clone());
+        submissions
+            .append(&sent(5, 1, "143", Verdict::Right))
+            .unwrap();
+        submissions
+            .append(&sent(5, 2, "900", Verdict::TooHigh))
+            .unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap().lines().next(),
+            Some(
+                "{\"at\":\"2024-12-05T06:00:00Z\",\"day\":5,\"part\":1,\"answer\":\"143\",\"verdict\":\"right\"}"
+            )
+        );
+        assert_eq!(submissions.load()[1].verdict, Verdict::TooHigh);
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,3 @@
+use {{crate_name}}::*;
+
+util::generate_bench!({{year}}, {{day}});
@@ -1,19 +1,29 @@
+use util::Solution;
+
 type InputType = usize;
 type OutType = usize;
 
-#[allow(unused_variables)]
-pub fn parse(data: &str) -> InputType {
-    1
-}
+pub struct Day;
 
-#[allow(unused_variables)]
-pub fn part1(input: InputType) -> OutType {
-    input
-}
+impl Solution for Day {
+    type Input<'a> = InputType;
+    type Output1 = OutType;
+    type Output2 = OutType;
+
+    #[allow(unused_variables)]
+    fn parse(data: &str) -> Self::Input<'_> {
+        1
+    }
+
+    #[allow(unused_variables)]
+    fn part1(input: Self::Input<'_>) -> Self::Output1 {
+        input
+    }
 
-#[allow(unused_variables)]
-pub fn part2(input: InputType) -> OutType {
-    input
+    #[allow(unused_variables)]
+    fn part2(input: Self::Input<'_>) -> Self::Output2 {
+        input
+    }
 }
 
 #[cfg(test)]